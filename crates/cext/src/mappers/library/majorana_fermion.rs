@@ -84,13 +84,17 @@ pub unsafe extern "C" fn qf_fermion_to_majorana(
 ///
 /// .. math::
 ///
-///    \gamma_j \rightarrow a^\dagger_j + a_j ~~\text{and}~~
-///    \gamma'_j \rightarrow i (a^\dagger_j - a_j)
+///    \gamma_j \rightarrow a^\dagger_j + i a_j ~~\text{and}~~
+///    \gamma'_j \rightarrow a^\dagger_j - i a_j
 ///
 /// where :math:`\gamma_j`/:math:`\gamma'_j` are the two Majorana fermion operators (stored on the
 /// even and odd modes, respectively), and :math:`a^\dagger_j` (:math:`a_j`) is the fermionic
 /// creation (annihilation) operator acting on the :math:`j`-th spin-less fermionic mode.
 ///
+/// .. note::
+///    This transformation is **not** the inverse of ``qf_fermion_to_majorana``, which relies on
+///    :math:`\gamma_j = a^\dagger_j + a_j` and :math:`\gamma'_j = i (a^\dagger_j - a_j)`.
+///
 /// Example
 /// -------
 ///
@@ -11,11 +11,12 @@
 // that they have been altered from the originals.
 
 use crate::exit_codes::ExitCode;
-use crate::pointers::{const_ptr_as_ref, mut_ptr_as_ref, slice_from_ptr};
+use crate::pointers::{const_ptr_as_ref, mut_ptr_as_ref, mut_slice_from_ptr, slice_from_ptr};
+use std::ffi::{CString, c_char};
 
 use num_complex::Complex64;
 use qiskit_fermions_core::operators::fermion_operator::FermionOperator;
-use qiskit_fermions_core::operators::{OperatorMacro, OperatorTrait};
+use qiskit_fermions_core::operators::{MAX_POW_TERMS, OperatorMacro, OperatorTrait};
 
 /// @ingroup qf_ferm_op
 ///
@@ -228,6 +229,50 @@ pub unsafe extern "C" fn qf_ferm_op_add_term(
     ExitCode::Success
 }
 
+/// @ingroup qf_ferm_op
+///
+/// @brief Adds a constant (or identity) term to an existing operator.
+///
+/// @param op A pointer to the fermionic operator to be modified.
+/// @param coeff A pointer to the complex coefficient of the identity term.
+///
+/// @return An exit code.
+///
+/// @rst
+///
+/// This is equivalent to calling :c:func:`qf_ferm_op_add_term` with zero actions. The new term is
+/// not merged with any existing identity terms.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *one = qf_ferm_op_one();
+///
+///     QfFermionOperator *op = qf_ferm_op_zero();
+///     QkComplex64 coeff = {1.0, 0.0};
+///
+///     qf_ferm_op_add_constant(op, &coeff);
+///
+///     assert(qf_ferm_op_equal(op, one));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_add_constant(
+    op: *mut FermionOperator,
+    coeff: *const Complex64,
+) -> ExitCode {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { mut_ptr_as_ref(op) };
+    let coeff = unsafe { const_ptr_as_ref(coeff) };
+
+    op.add_constant_mut(*coeff);
+
+    ExitCode::Success
+}
+
 /// @ingroup qf_ferm_op
 ///
 /// @brief Adds two operators together.
@@ -346,6 +391,56 @@ pub unsafe extern "C" fn qf_ferm_op_compose(
     Box::into_raw(Box::new(result))
 }
 
+/// @ingroup qf_ferm_op
+///
+/// @brief Raises an operator to an integer power.
+///
+/// This composes the operator ``exponent`` times with itself. An ``exponent`` of ``0`` results in
+/// the multiplicative identity operator.
+///
+/// Since terms are not simplified during the composition, raising an operator with ``n`` terms to
+/// the power ``k`` results in ``n^k`` terms. To avoid running out of memory, no result is computed
+/// when this exceeds ``2^24`` terms.
+///
+/// @param op A pointer to the operator.
+/// @param exponent The exponent.
+///
+/// @return A pointer to the resulting operator, or ``NULL`` if it would exceed ``2^24`` terms.
+///
+/// @rst
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *one = qf_ferm_op_one();
+///     QkComplex64 coeff = {2.0, 0.0};
+///     QfFermionOperator *op = qf_ferm_op_mul(one, &coeff);
+///
+///     QfFermionOperator *result = qf_ferm_op_pow(op, 2);
+///
+///     QkComplex64 coeff_exp = {4.0, 0.0};
+///     QfFermionOperator *expected = qf_ferm_op_mul(one, &coeff_exp);
+///
+///     assert(qf_ferm_op_equal(result, expected));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_pow(
+    op: *const FermionOperator,
+    exponent: u32,
+) -> *mut FermionOperator {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    match op.checked_pow(exponent as usize, MAX_POW_TERMS) {
+        Some(result) => Box::into_raw(Box::new(result)),
+        None => std::ptr::null_mut(),
+    }
+}
+
 /// @ingroup qf_ferm_op
 ///
 /// @brief Returns the Hermitian conjugate (or adjoint) of an operator.
@@ -391,6 +486,49 @@ pub unsafe extern "C" fn qf_ferm_op_adjoint(op: *const FermionOperator) -> *mut
     Box::into_raw(Box::new(result))
 }
 
+/// @ingroup qf_ferm_op
+///
+/// @brief Returns the complex conjugate of an operator.
+///
+/// Unlike qf_ferm_op_adjoint(), this only complex conjugates the coefficients and leaves the
+/// actions of each term untouched.
+///
+/// @param op A pointer to the operator.
+///
+/// @return A pointer to the created operator.
+///
+/// @rst
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *op = qf_ferm_op_zero();
+///     bool actions[2] = {true, false};
+///     uint32_t indices[2] = {0, 1};
+///     QkComplex64 coeff = {0.0, 1.0};
+///     qf_ferm_op_add_term(op, 2, actions, indices, &coeff);
+///
+///     QfFermionOperator *conjugate = qf_ferm_op_conjugate(op);
+///
+///     QfFermionOperator *expected = qf_ferm_op_zero();
+///     QkComplex64 coeff_conj = {0.0, -1.0};
+///     qf_ferm_op_add_term(expected, 2, actions, indices, &coeff_conj);
+///
+///     assert(qf_ferm_op_equal(conjugate, expected));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_conjugate(op: *const FermionOperator) -> *mut FermionOperator {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    let result = op.conjugate();
+    Box::into_raw(Box::new(result))
+}
+
 /// @ingroup qf_ferm_op
 ///
 /// @brief Removes terms whose coefficient magnitude lies below the provided threshold.
@@ -597,6 +735,116 @@ pub unsafe extern "C" fn qf_ferm_op_is_hermitian(op: *const FermionOperator, ato
     op.is_hermitian(atol)
 }
 
+/// @ingroup qf_ferm_op
+///
+/// @brief Checks whether all coefficients of an operator are real.
+///
+/// @param op A pointer to the fermionic operator to be checked.
+/// @param atol The absolute tolerance upto which imaginary parts are considered zero.
+///
+/// @return Whether the imaginary parts of all coefficients lie within ``atol`` of zero.
+///
+/// @rst
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *op = qf_ferm_op_zero();
+///     bool actions[2] = {true, false};
+///     uint32_t indices[2] = {0, 1};
+///     QkComplex64 coeff = {1.0, 1e-6};
+///     qf_ferm_op_add_term(op, 2, actions, indices, &coeff);
+///
+///     assert(qf_ferm_op_is_real(op, 1e-4));
+///     assert(!qf_ferm_op_is_real(op, 1e-8));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_is_real(op: *const FermionOperator, atol: f64) -> bool {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.is_real(atol)
+}
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Checks whether an operator is a scalar multiple of the identity.
+///
+/// @param op A pointer to the fermionic operator to be checked.
+/// @param atol The absolute tolerance below which coefficients are considered zero.
+///
+/// @return Whether all normal-ordered and simplified terms of the operator are identity terms.
+///
+/// @rst
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *one = qf_ferm_op_one();
+///     QkComplex64 coeff = {2.0, 0.0};
+///     QfFermionOperator *op = qf_ferm_op_mul(one, &coeff);
+///
+///     assert(qf_ferm_op_is_constant(op, 1e-8));
+///
+///     bool actions[2] = {true, false};
+///     uint32_t indices[2] = {0, 1};
+///     qf_ferm_op_add_term(op, 2, actions, indices, &coeff);
+///
+///     assert(!qf_ferm_op_is_constant(op, 1e-8));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_is_constant(op: *const FermionOperator, atol: f64) -> bool {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.is_constant(atol)
+}
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Checks whether an operator is the identity.
+///
+/// @param op A pointer to the fermionic operator to be checked.
+/// @param atol The absolute tolerance upto which coefficients are considered equal.
+///
+/// @return Whether the provided operator equals the identity.
+///
+/// @rst
+///
+/// .. note::
+///    This check is implemented using :c:func:`qf_ferm_op_equiv` on the
+///    :c:func:`qf_ferm_op_normal_ordered` operator and :c:func:`qf_ferm_op_one`.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *one = qf_ferm_op_one();
+///     QkComplex64 coeff = {2.0, 0.0};
+///     QfFermionOperator *two = qf_ferm_op_mul(one, &coeff);
+///
+///     assert(qf_ferm_op_is_identity(one, 1e-8));
+///     assert(!qf_ferm_op_is_identity(two, 1e-8));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_is_identity(op: *const FermionOperator, atol: f64) -> bool {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.is_identity(atol)
+}
+
 /// @ingroup qf_ferm_op
 ///
 /// @brief Checks the many-body order of an operator.
@@ -754,6 +1002,57 @@ pub unsafe extern "C" fn qf_ferm_op_equiv(
     left.equiv(right, atol)
 }
 
+/// @ingroup qf_ferm_op
+///
+/// @brief Checks whether two operators are equivalent up to a given many-body order.
+///
+/// Both operators are truncated to the terms whose many-body order (i.e. number of actions)
+/// does not exceed ``max_order`` before being compared with :c:func:`qf_ferm_op_equiv`. This is
+/// useful for comparing a full Hamiltonian to a low-order approximation of it, ignoring the
+/// higher-order terms which the approximation deliberately omits.
+///
+/// @param left A pointer to the left fermionic operator.
+/// @param right A pointer to the right fermionic operator.
+/// @param max_order The maximum many-body order of the terms to compare.
+/// @param atol The absolute tolerance for the coefficient comparison.
+///
+/// @return Whether the two truncated operators are equivalent.
+///
+/// @rst
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *op = qf_ferm_op_one();
+///
+///     QfFermionOperator *perturbed = qf_ferm_op_one();
+///     bool actions[4] = {true, true, false, false};
+///     uint32_t indices[4] = {0, 1, 1, 0};
+///     QkComplex64 coeff = {0.1, 0.0};
+///     qf_ferm_op_add_term(perturbed, 4, actions, indices, &coeff);
+///
+///     assert(qf_ferm_op_equiv_up_to_order(op, perturbed, 2, 1e-8));
+///     assert(!qf_ferm_op_equiv_up_to_order(op, perturbed, 4, 1e-8));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_equiv_up_to_order(
+    left: *const FermionOperator,
+    right: *const FermionOperator,
+    max_order: u32,
+    atol: f64,
+) -> bool {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let left = unsafe { const_ptr_as_ref(left) };
+    let right = unsafe { const_ptr_as_ref(right) };
+
+    left.truncate_order(max_order)
+        .equiv(&right.truncate_order(max_order), atol)
+}
+
 /// @ingroup qf_ferm_op
 ///
 /// @brief Returns the length (or number of terms) of the provided operator.
@@ -786,3 +1085,205 @@ pub unsafe extern "C" fn qf_ferm_op_len(op: *const FermionOperator) -> usize {
 
     op.boundaries.len() - 1
 }
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Returns the number of terms of the provided operator.
+///
+/// @param op A pointer to the fermionic operator.
+///
+/// @return The number of terms of the operator.
+///
+/// @rst
+///
+/// This is the required length of the ``coeffs`` array passed to :c:func:`qf_ferm_op_data`.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *op = qf_ferm_op_one();
+///
+///     assert(qf_ferm_op_num_terms(op) == 1);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_num_terms(op: *const FermionOperator) -> u64 {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.coeffs.len() as u64
+}
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Returns the number of actions summed over all terms of the provided operator.
+///
+/// @param op A pointer to the fermionic operator.
+///
+/// @return The number of actions summed over all terms of the operator.
+///
+/// @rst
+///
+/// This is the required length of the ``actions`` and ``indices`` arrays passed to
+/// :c:func:`qf_ferm_op_data`.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *op = qf_ferm_op_zero();
+///     bool actions[4] = {true, false, true, false};
+///     uint32_t indices[4] = {0, 1, 2, 3};
+///     QkComplex64 coeff = {1.0, 0.0};
+///     qf_ferm_op_add_term(op, 4, actions, indices, &coeff);
+///
+///     assert(qf_ferm_op_num_actions(op) == 4);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_num_actions(op: *const FermionOperator) -> u64 {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.num_actions() as u64
+}
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Copies the internal data of an operator into the provided arrays.
+///
+/// @param op A pointer to the fermionic operator.
+/// @param coeffs A pointer to an array of term coefficients to be filled. The length of this array
+///     should be ``qf_ferm_op_num_terms(op)``.
+/// @param actions A pointer to an array of actions over all terms to be filled. The length of this
+///     array should be ``qf_ferm_op_num_actions(op)``.
+/// @param indices A pointer to an array of action indices over all terms to be filled. The length
+///     of this array should be ``qf_ferm_op_num_actions(op)``.
+/// @param boundaries A pointer to an array of the boundaries between terms to be filled. The length
+///     of this array should be ``qf_ferm_op_num_terms(op) + 1``.
+///
+/// @return An exit code.
+///
+/// @rst
+///
+/// The arrays are laid out exactly as the ones consumed by :c:func:`qf_ferm_op_new`. Since their
+/// memory must be allocated by the caller, this function is meant to be used in two steps: first,
+/// query the required array lengths via :c:func:`qf_ferm_op_num_terms` and
+/// :c:func:`qf_ferm_op_num_actions` and then fetch the data.
+///
+/// Any of the pointer arguments may be ``NULL`` if and only if their corresponding length is zero.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     uint64_t num_terms = qf_ferm_op_num_terms(op);
+///     uint64_t num_actions = qf_ferm_op_num_actions(op);
+///
+///     QkComplex64 *coeffs = malloc(num_terms * sizeof(QkComplex64));
+///     bool *actions = malloc(num_actions * sizeof(bool));
+///     uint32_t *indices = malloc(num_actions * sizeof(uint32_t));
+///     uint32_t *boundaries = malloc((num_terms + 1) * sizeof(uint32_t));
+///
+///     QfExitCode exit = qf_ferm_op_data(op, coeffs, actions, indices, boundaries);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_data(
+    op: *const FermionOperator,
+    coeffs: *mut Complex64,
+    actions: *mut bool,
+    indices: *mut u32,
+    boundaries: *mut u32,
+) -> ExitCode {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    let num_terms = op.coeffs.len();
+    let num_actions = op.indices.len();
+
+    unsafe { mut_slice_from_ptr(coeffs, num_terms) }.copy_from_slice(&op.coeffs);
+    unsafe { mut_slice_from_ptr(actions, num_actions) }.copy_from_slice(&op.actions);
+    unsafe { mut_slice_from_ptr(indices, num_actions) }.copy_from_slice(&op.indices);
+    unsafe { mut_slice_from_ptr(boundaries, num_terms + 1) }
+        .iter_mut()
+        .zip(&op.boundaries)
+        .for_each(|(out, b)| *out = *b as u32);
+
+    ExitCode::Success
+}
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Returns a human-readable string representation of an operator.
+///
+/// @param op A pointer to the fermionic operator.
+///
+/// @return A pointer to a newly allocated, null-terminated string.
+///
+/// @rst
+///
+/// The string has the same format as the one produced by ``print`` in Python, i.e. one line per
+/// term consisting of the coefficient followed by the actions of the term. The returned string is owned by
+/// the caller and must be released with :c:func:`qf_ferm_op_str_free`. It must **not** be released
+/// with ``free``.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfFermionOperator *op = qf_ferm_op_one();
+///     char *string = qf_ferm_op_str(op);
+///     printf("%s\n", string);
+///     qf_ferm_op_str_free(string);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_str(op: *const FermionOperator) -> *mut c_char {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    CString::new(op.to_string())
+        .expect("the string representation should not contain null bytes")
+        .into_raw()
+}
+
+/// @ingroup qf_ferm_op
+///
+/// @brief Frees a string returned by :c:func:`qf_ferm_op_str`.
+///
+/// @param string A pointer to the string to be freed.
+///
+/// @rst
+///
+/// Passing ``NULL`` is a no-op.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     char *string = qf_ferm_op_str(op);
+///     qf_ferm_op_str_free(string);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_ferm_op_str_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: Per documentation, the pointer was returned by qf_ferm_op_str and thus was
+        // allocated by CString.
+        unsafe {
+            let _ = CString::from_raw(string);
+        }
+    }
+}
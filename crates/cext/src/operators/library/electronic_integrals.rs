@@ -190,7 +190,8 @@ pub unsafe extern "C" fn qf_ferm_op_from_2body_tril_spin_sym(
     let carray = unsafe { ::std::slice::from_raw_parts(two_body_aa, len_arr).to_vec() };
     let two_body_aa_arr = Array1::from_vec(carray);
 
-    let op = FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa_arr), norb);
+    let op =
+        FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa_arr), norb, false);
 
     Box::into_raw(Box::new(op))
 }
@@ -289,6 +290,7 @@ pub unsafe extern "C" fn qf_ferm_op_from_2body_tril_spin(
         ArrayView1::from(&two_body_ab_arr),
         ArrayView1::from(&two_body_bb_arr),
         norb,
+        false,
     );
 
     Box::into_raw(Box::new(op))
@@ -11,11 +11,12 @@
 // that they have been altered from the originals.
 
 use crate::exit_codes::ExitCode;
-use crate::pointers::{const_ptr_as_ref, mut_ptr_as_ref, slice_from_ptr};
+use crate::pointers::{const_ptr_as_ref, mut_ptr_as_ref, mut_slice_from_ptr, slice_from_ptr};
+use std::ffi::{CString, c_char};
 
 use num_complex::Complex64;
 use qiskit_fermions_core::operators::majorana_operator::MajoranaOperator;
-use qiskit_fermions_core::operators::{OperatorMacro, OperatorTrait};
+use qiskit_fermions_core::operators::{MAX_POW_TERMS, OperatorMacro, OperatorTrait};
 
 /// @ingroup qf_maj_op
 ///
@@ -334,6 +335,56 @@ pub unsafe extern "C" fn qf_maj_op_compose(
     Box::into_raw(Box::new(result))
 }
 
+/// @ingroup qf_maj_op
+///
+/// @brief Raises an operator to an integer power.
+///
+/// This composes the operator ``exponent`` times with itself. An ``exponent`` of ``0`` results in
+/// the multiplicative identity operator.
+///
+/// Since terms are not simplified during the composition, raising an operator with ``n`` terms to
+/// the power ``k`` results in ``n^k`` terms. To avoid running out of memory, no result is computed
+/// when this exceeds ``2^24`` terms.
+///
+/// @param op A pointer to the operator.
+/// @param exponent The exponent.
+///
+/// @return A pointer to the resulting operator, or ``NULL`` if it would exceed ``2^24`` terms.
+///
+/// @rst
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfMajoranaOperator *one = qf_maj_op_one();
+///     QkComplex64 coeff = {2.0, 0.0};
+///     QfMajoranaOperator *op = qf_maj_op_mul(one, &coeff);
+///
+///     QfMajoranaOperator *result = qf_maj_op_pow(op, 2);
+///
+///     QkComplex64 coeff_exp = {4.0, 0.0};
+///     QfMajoranaOperator *expected = qf_maj_op_mul(one, &coeff_exp);
+///
+///     assert(qf_maj_op_equal(result, expected));
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_maj_op_pow(
+    op: *const MajoranaOperator,
+    exponent: u32,
+) -> *mut MajoranaOperator {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    match op.checked_pow(exponent as usize, MAX_POW_TERMS) {
+        Some(result) => Box::into_raw(Box::new(result)),
+        None => std::ptr::null_mut(),
+    }
+}
+
 /// @ingroup qf_maj_op
 ///
 /// @brief Returns the Hermitian conjugate (or adjoint) of an operator.
@@ -485,9 +536,12 @@ pub unsafe extern "C" fn qf_maj_op_simplify(
 /// @brief Returns an equivalent operator with normal ordered terms.
 ///
 /// The normal order of an operator term is defined such that all actions are ordered by
-/// lexicographically descending indices.
+/// lexicographically descending indices. Each coefficient is multiplied by the sign of the
+/// permutation which sorts its term. Optionally, pairs of equal modes are removed from each sorted
+/// term, since every Majorana operator squares to the identity.
 ///
 /// @param op A pointer to the operator.
+/// @param reduce Whether to remove pairs of equal modes from the normal ordered terms.
 ///
 /// @return A pointer to the created operator.
 ///
@@ -504,7 +558,7 @@ pub unsafe extern "C" fn qf_maj_op_simplify(
 ///     QkComplex64 coeff = {1.0, 0.0};
 ///     qf_maj_op_add_term(op, 4, modes, &coeff);
 ///
-///     QfMajoranaOperator *normal_ordered = qf_maj_op_normal_ordered(op);
+///     QfMajoranaOperator *normal_ordered = qf_maj_op_normal_ordered(op, false);
 ///
 ///     QkComplex64 coeff_minus = {-1.0, 0.0};
 ///     QfMajoranaOperator *expected = qf_maj_op_zero();
@@ -513,6 +567,26 @@ pub unsafe extern "C" fn qf_maj_op_simplify(
 ///
 ///     assert(qf_maj_op_equal(normal_ordered, expected));
 ///
+/// With ``reduce`` set, the term :math:`\gamma_0 \gamma_1 \gamma_0` is sorted into
+/// :math:`-\gamma_1 \gamma_0 \gamma_0` and then reduced to :math:`-\gamma_1`:
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfMajoranaOperator *op = qf_maj_op_zero();
+///     uint32_t modes[3] = {0, 1, 0};
+///     QkComplex64 coeff = {1.0, 0.0};
+///     qf_maj_op_add_term(op, 3, modes, &coeff);
+///
+///     QfMajoranaOperator *reduced = qf_maj_op_normal_ordered(op, true);
+///
+///     QkComplex64 coeff_minus = {-1.0, 0.0};
+///     QfMajoranaOperator *expected = qf_maj_op_zero();
+///     uint32_t modes_exp[1] = {1};
+///     qf_maj_op_add_term(expected, 1, modes_exp, &coeff_minus);
+///
+///     assert(qf_maj_op_equal(reduced, expected));
+///
 /// @endrst
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn qf_maj_op_normal_ordered(
@@ -538,9 +612,10 @@ pub unsafe extern "C" fn qf_maj_op_normal_ordered(
 /// @rst
 ///
 /// .. note::
-///    This check is implemented using :c:func:`qf_maj_op_equiv` on the
-///    :c:func:`qf_maj_op_normal_ordered` difference of ``op`` and its
-///    :c:func:`qf_maj_op_adjoint` and :c:func:`qf_maj_op_zero`.
+///    Rather than constructing the :c:func:`qf_maj_op_adjoint`, this check reduces and merges
+///    the terms of ``op`` (see :c:func:`qf_maj_op_normal_ordered`). Reversing a term of
+///    :math:`k` distinct modes yields the sign :math:`(-1)^{k(k-1)/2}`, which each coefficient
+///    must absorb under complex conjugation.
 ///
 /// Example
 /// -------
@@ -756,3 +831,198 @@ pub unsafe extern "C" fn qf_maj_op_len(op: *const MajoranaOperator) -> usize {
 
     op.boundaries.len() - 1
 }
+
+/// @ingroup qf_maj_op
+///
+/// @brief Returns the number of terms of the provided operator.
+///
+/// @param op A pointer to the Majorana operator.
+///
+/// @return The number of terms of the operator.
+///
+/// @rst
+///
+/// This is the required length of the ``coeffs`` array passed to :c:func:`qf_maj_op_data`.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfMajoranaOperator *op = qf_maj_op_one();
+///
+///     assert(qf_maj_op_num_terms(op) == 1);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_maj_op_num_terms(op: *const MajoranaOperator) -> u64 {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.coeffs.len() as u64
+}
+
+/// @ingroup qf_maj_op
+///
+/// @brief Returns the number of modes summed over all terms of the provided operator.
+///
+/// @param op A pointer to the Majorana operator.
+///
+/// @return The number of modes summed over all terms of the operator.
+///
+/// @rst
+///
+/// This is the required length of the ``modes`` array passed to :c:func:`qf_maj_op_data`.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfMajoranaOperator *op = qf_maj_op_zero();
+///     uint32_t modes[4] = {0, 1, 2, 3};
+///     QkComplex64 coeff = {1.0, 0.0};
+///     qf_maj_op_add_term(op, 4, modes, &coeff);
+///
+///     assert(qf_maj_op_num_modes(op) == 4);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_maj_op_num_modes(op: *const MajoranaOperator) -> u64 {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    op.modes.len() as u64
+}
+
+/// @ingroup qf_maj_op
+///
+/// @brief Copies the internal data of an operator into the provided arrays.
+///
+/// @param op A pointer to the Majorana operator.
+/// @param coeffs A pointer to an array of term coefficients to be filled. The length of this array
+///     should be ``qf_maj_op_num_terms(op)``.
+/// @param modes A pointer to an array of modes over all terms to be filled. The length of this
+///     array should be ``qf_maj_op_num_modes(op)``.
+/// @param boundaries A pointer to an array of the boundaries between terms to be filled. The length
+///     of this array should be ``qf_maj_op_num_terms(op) + 1``.
+///
+/// @return An exit code.
+///
+/// @rst
+///
+/// The arrays are laid out exactly as the ones consumed by :c:func:`qf_maj_op_new`. Since their
+/// memory must be allocated by the caller, this function is meant to be used in two steps: first,
+/// query the required array lengths via :c:func:`qf_maj_op_num_terms` and
+/// :c:func:`qf_maj_op_num_modes` and then fetch the data.
+///
+/// Any of the pointer arguments may be ``NULL`` if and only if their corresponding length is zero.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     uint64_t num_terms = qf_maj_op_num_terms(op);
+///     uint64_t num_modes = qf_maj_op_num_modes(op);
+///
+///     QkComplex64 *coeffs = malloc(num_terms * sizeof(QkComplex64));
+///     uint32_t *modes = malloc(num_modes * sizeof(uint32_t));
+///     uint32_t *boundaries = malloc((num_terms + 1) * sizeof(uint32_t));
+///
+///     QfExitCode exit = qf_maj_op_data(op, coeffs, modes, boundaries);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_maj_op_data(
+    op: *const MajoranaOperator,
+    coeffs: *mut Complex64,
+    modes: *mut u32,
+    boundaries: *mut u32,
+) -> ExitCode {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    let num_terms = op.coeffs.len();
+    let num_modes = op.modes.len();
+
+    unsafe { mut_slice_from_ptr(coeffs, num_terms) }.copy_from_slice(&op.coeffs);
+    unsafe { mut_slice_from_ptr(modes, num_modes) }.copy_from_slice(&op.modes);
+    unsafe { mut_slice_from_ptr(boundaries, num_terms + 1) }
+        .iter_mut()
+        .zip(&op.boundaries)
+        .for_each(|(out, b)| *out = *b as u32);
+
+    ExitCode::Success
+}
+
+/// @ingroup qf_maj_op
+///
+/// @brief Returns a human-readable string representation of an operator.
+///
+/// @param op A pointer to the Majorana operator.
+///
+/// @return A pointer to a newly allocated, null-terminated string.
+///
+/// @rst
+///
+/// The string has the same format as the one produced by ``print`` in Python, i.e. one line per
+/// term consisting of the coefficient followed by the modes of the term. The returned string is owned by
+/// the caller and must be released with :c:func:`qf_maj_op_str_free`. It must **not** be released
+/// with ``free``.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     QfMajoranaOperator *op = qf_maj_op_one();
+///     char *string = qf_maj_op_str(op);
+///     printf("%s\n", string);
+///     qf_maj_op_str_free(string);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_maj_op_str(op: *const MajoranaOperator) -> *mut c_char {
+    // SAFETY: Per documentation, the pointers are non-null and aligned.
+    let op = unsafe { const_ptr_as_ref(op) };
+
+    CString::new(op.to_string())
+        .expect("the string representation should not contain null bytes")
+        .into_raw()
+}
+
+/// @ingroup qf_maj_op
+///
+/// @brief Frees a string returned by :c:func:`qf_maj_op_str`.
+///
+/// @param string A pointer to the string to be freed.
+///
+/// @rst
+///
+/// Passing ``NULL`` is a no-op.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: c
+///     :linenos:
+///
+///     char *string = qf_maj_op_str(op);
+///     qf_maj_op_str_free(string);
+///
+/// @endrst
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qf_maj_op_str_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: Per documentation, the pointer was returned by qf_maj_op_str and thus was
+        // allocated by CString.
+        unsafe {
+            let _ = CString::from_raw(string);
+        }
+    }
+}
@@ -56,6 +56,25 @@ pub(crate) unsafe fn slice_from_ptr<'a, T>(ptr: *const T, len: usize) -> &'a [T]
     unsafe { try_slice_from_ptr(ptr, len) }.expect("caller should ensure a valid pointer")
 }
 
+/// Create a mutable slice of length `len` from a given pointer.
+///
+/// Panicking variant of [try_slice_from_ptr] for mutable pointers.
+///
+/// # Safety
+///
+/// If `len` is non-zero, `ptr` must be valid for `len` writes for lifetime `'a` and the memory
+/// pointed to must not be accessed otherwise while the slice exists.
+pub(crate) unsafe fn mut_slice_from_ptr<'a, T>(ptr: *mut T, len: usize) -> &'a mut [T] {
+    if len == 0 {
+        &mut []
+    } else {
+        check_ptr(ptr).expect("caller should ensure a valid pointer");
+        // SAFETY: per documentation, pointer is valid for `len` writes at the lifetime of the
+        // function.
+        unsafe { ::std::slice::from_raw_parts_mut(ptr, len) }
+    }
+}
+
 /// Casts a const pointer to a reference. Panics is the pointer is null or not aligned.
 ///
 /// # Safety
@@ -0,0 +1,92 @@
+// This code is a Qiskit project.
+//
+// (C) Copyright IBM 2026.
+//
+// This code is licensed under the Apache License, Version 2.0. You may
+// obtain a copy of this license in the LICENSE.txt file in the root directory
+// of this source tree or at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Any modifications or derivative works of this code must retain this
+// copyright notice, and modified files need to carry a notice indicating
+// that they have been altered from the originals.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use num_complex::Complex64;
+use qiskit_fermions_core::mappers::library::jordan_wigner::jordan_wigner;
+use qiskit_fermions_core::operators::fermion_operator::FermionOperator;
+use qiskit_fermions_core::operators::library::fcidump::FCIDump;
+use qiskit_fermions_core::operators::{ModeIndex, OperatorMacro};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+use std::hint::black_box;
+
+fn two_body_terms(num_terms: usize, num_modes: ModeIndex, seed: u64) -> FermionOperator {
+    let mut rng = Pcg64Mcg::seed_from_u64(seed);
+    let mut op = FermionOperator {
+        coeffs: vec![],
+        actions: vec![],
+        indices: vec![],
+        boundaries: vec![0],
+    };
+    for _ in 0..num_terms {
+        op.coeffs
+            .push(Complex64::new(rng.random_range(-1.0..1.0), 0.0));
+        // a random permutation of the actions appearing in a two-body term
+        let mut actions = [true, true, false, false];
+        for i in (1..actions.len()).rev() {
+            actions.swap(i, rng.random_range(0..=i));
+        }
+        for action in actions {
+            op.actions.push(action);
+            op.indices.push(rng.random_range(0..num_modes));
+        }
+        op.boundaries.push(op.indices.len());
+    }
+    op
+}
+
+fn bench_simplify(c: &mut Criterion) {
+    let op = FermionOperator::random(1_000_000, 8, 4, Some(0));
+    let mut group = c.benchmark_group("simplify");
+    group.sample_size(10);
+    group.bench_function("random_1M_terms", |b| {
+        b.iter(|| black_box(&op).simplify(1e-8))
+    });
+    group.finish();
+}
+
+fn bench_normal_ordered(c: &mut Criterion) {
+    let op = two_body_terms(10_000, 8, 1);
+    c.bench_function("normal_ordered/two_body_10k_terms", |b| {
+        b.iter(|| black_box(&op).normal_ordered())
+    });
+}
+
+fn bench_compose(c: &mut Criterion) {
+    let op1 = FermionOperator::random(1_000, 8, 2, Some(2));
+    let op2 = FermionOperator::random(1_000, 8, 2, Some(3));
+    c.bench_function("compose/1k_by_1k_terms", |b| {
+        b.iter(|| black_box(&op1).__and__(black_box(&op2)))
+    });
+}
+
+fn bench_jordan_wigner(c: &mut Criterion) {
+    let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+    let op = FermionOperator::from(&fcidump);
+    let num_qubits = 2 * fcidump.norb;
+    c.bench_function("jordan_wigner/h2", |b| {
+        b.iter(|| {
+            let qubit_op = jordan_wigner(black_box(&op), num_qubits);
+            unsafe { qiskit_sys::qk_obs_free(qubit_op) };
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_simplify,
+    bench_normal_ordered,
+    bench_compose,
+    bench_jordan_wigner
+);
+criterion_main!(benches);
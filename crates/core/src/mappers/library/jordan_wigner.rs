@@ -11,12 +11,51 @@
 // that they have been altered from the originals.
 
 use crate::operators::fermion_operator::{FermionAction, FermionOperator};
+use crate::operators::{ModeIndex, OperatorMacro, OperatorTrait};
+use num_complex::Complex64;
 use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
 use std::mem::MaybeUninit;
-use std::sync::{Arc, Mutex};
+use std::panic;
+use std::sync::{Arc, Mutex, OnceLock, mpsc};
+use std::thread;
+
+/// The absolute tolerance up to which [`inverse_jordan_wigner_checked`] requires the re-mapped
+/// observable to match its input.
+const ROUND_TRIP_ATOL: f64 = 1e-10;
+
+/// The error returned by [`inverse_jordan_wigner_checked`] when an observable is not the
+/// Jordan-Wigner image of an operator acting on `num_modes` fermionic modes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MappingError {
+    pub num_modes: ModeIndex,
+    /// The number of terms by which the re-mapped observable differs from the input.
+    pub num_terms: usize,
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The observable is not the Jordan-Wigner image of an operator on {} modes, {} terms \
+             differ after mapping it back.",
+            self.num_modes, self.num_terms
+        )
+    }
+}
+
+impl std::error::Error for MappingError {}
 
 fn map_action(action: FermionAction, num_qubits: u32) -> *mut qiskit_sys::QkObs {
+    assert!(
+        *action.1 < num_qubits as ModeIndex,
+        "the fermionic mode {} exceeds the number of qubits ({num_qubits})",
+        action.1
+    );
     let fer_idx = *action.1 as usize;
+    // the assertion above guarantees that this does not truncate, even with 64-bit mode indices
+    let qubit = fer_idx as u32;
     let im = if *action.0 { -0.5 } else { 0.5 };
     let mut coeffs: Vec<qiskit_sys::QkComplex64> = vec![
         qiskit_sys::QkComplex64 { re: 0.5, im: 0.0 },
@@ -25,18 +64,18 @@ fn map_action(action: FermionAction, num_qubits: u32) -> *mut qiskit_sys::QkObs
 
     let mut bit_terms = Vec::<qiskit_sys::QkBitTerm>::new();
     let mut indices = Vec::<u32>::new();
-    for qb_idx in 0..fer_idx {
+    for qb_idx in 0..qubit {
         bit_terms.push(qiskit_sys::QkBitTerm_QkBitTerm_Z);
-        indices.push(qb_idx as u32);
+        indices.push(qb_idx);
     }
     bit_terms.push(qiskit_sys::QkBitTerm_QkBitTerm_X);
-    indices.push(fer_idx as u32);
-    for qb_idx in 0..fer_idx {
+    indices.push(qubit);
+    for qb_idx in 0..qubit {
         bit_terms.push(qiskit_sys::QkBitTerm_QkBitTerm_Z);
-        indices.push(qb_idx as u32);
+        indices.push(qb_idx);
     }
     bit_terms.push(qiskit_sys::QkBitTerm_QkBitTerm_Y);
-    indices.push(fer_idx as u32);
+    indices.push(qubit);
 
     let mut boundaries: Vec<usize> = vec![0, fer_idx + 1, 2 * fer_idx + 2];
 
@@ -53,19 +92,135 @@ fn map_action(action: FermionAction, num_qubits: u32) -> *mut qiskit_sys::QkObs
     }
 }
 
+/// A cache of the observables produced by [`map_action`], keyed by `(action, index)`.
+///
+/// The same single actions recur across many terms of an operator (for example, the 144 actions of
+/// the H2 Hamiltonian only comprise 8 distinct ones). Caching them avoids re-building the vectors
+/// and the `QkObs` of an action every time it occurs. The cache owns its observables and frees them
+/// when dropped; callers may only borrow them (e.g. as the `const` inputs of `qk_obs_compose`).
+struct ActionCache {
+    num_qubits: u32,
+    mapped_actions: HashMap<(bool, ModeIndex), *mut qiskit_sys::QkObs>,
+}
+
+impl ActionCache {
+    fn new(num_qubits: u32) -> Self {
+        Self {
+            num_qubits,
+            mapped_actions: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, action: FermionAction) -> *const qiskit_sys::QkObs {
+        let num_qubits = self.num_qubits;
+        *self
+            .mapped_actions
+            .entry((*action.0, *action.1))
+            .or_insert_with(|| map_action(action, num_qubits))
+    }
+}
+
+impl Drop for ActionCache {
+    fn drop(&mut self) {
+        for mapped_action in self.mapped_actions.values() {
+            unsafe { qiskit_sys::qk_obs_free(*mapped_action) };
+        }
+    }
+}
+
+static THREAD_POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
+fn build_thread_pool(num_threads: usize) -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .unwrap()
+}
+
+/// Returns the thread pool shared by all calls of [`jordan_wigner`], building it on first use.
+fn thread_pool() -> &'static rayon::ThreadPool {
+    THREAD_POOL.get_or_init(|| build_thread_pool(0))
+}
+
+/// Configures the number of threads of the pool shared by all calls of [`jordan_wigner`] (and
+/// [`jordan_wigner_with_progress`]). A value of `0` lets rayon choose the number of threads.
+///
+/// The pool gets built only once, so this has to be called before the first mapping. Returns
+/// whether the configuration took effect, i.e. `false` if the pool had already been built.
+pub fn configure_thread_pool(num_threads: usize) -> bool {
+    let mut configured = false;
+    THREAD_POOL.get_or_init(|| {
+        configured = true;
+        build_thread_pool(num_threads)
+    });
+    configured
+}
+
 // NOTE: https://stackoverflow.com/a/50341075
 struct Wrapper {
     ptr: *mut qiskit_sys::QkObs,
 }
 unsafe impl Send for Wrapper {}
 
+pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_sys::QkObs {
+    map_terms(fer_op, num_qubits, || {})
+}
+
+/// Like [`jordan_wigner`], but reports its progress by calling `progress(terms_done, terms_total)`
+/// after every `interval` mapped terms, as well as once all terms have been mapped.
+///
+/// The mapping itself runs on a separate thread, while `progress` is always invoked on the calling
+/// thread. Thus, the callback may block or acquire locks (like Python's GIL) held by the caller
+/// without stalling the rayon worker threads. The reported number of mapped terms is
+/// monotonically increasing.
+pub fn jordan_wigner_with_progress(
+    fer_op: &FermionOperator,
+    num_qubits: u32,
+    interval: usize,
+    mut progress: impl FnMut(usize, usize),
+) -> *mut qiskit_sys::QkObs {
+    let total = fer_op.coeffs.len();
+    let interval = interval.max(1);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        let worker = scope.spawn(move || {
+            // the sender is guarded together with the counter to ensure in-order messages
+            let counter = Mutex::new((0, sender));
+            let ptr = map_terms(fer_op, num_qubits, || {
+                let mut guard = counter.lock().unwrap();
+                guard.0 += 1;
+                let done = guard.0;
+                if done % interval == 0 || done == total {
+                    // the receiver only hangs up once this thread has finished
+                    let _ = guard.1.send(done);
+                }
+            });
+            Wrapper { ptr }
+        });
+
+        // this loop terminates once the worker thread has dropped its sender
+        for done in receiver {
+            progress(done, total);
+        }
+
+        match worker.join() {
+            Ok(mapped_operator) => mapped_operator.ptr,
+            Err(err) => panic::resume_unwind(err),
+        }
+    })
+}
+
 // TODO: can we clean up the coding pattern of overwriting a data structure in-place to avoid the
 // repetitive re-allocations?
-pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_sys::QkObs {
-    let pool = rayon::ThreadPoolBuilder::new()
-        .num_threads(0)
-        .build()
-        .unwrap();
+fn map_terms(
+    fer_op: &FermionOperator,
+    num_qubits: u32,
+    on_term_mapped: impl Fn() + Sync,
+) -> *mut qiskit_sys::QkObs {
+    // PERF: the pool is shared across calls because building one spawns new OS threads, which
+    // dominates the runtime when mapping many small operators
+    let pool = thread_pool();
 
     let mut qubit_ops = vec![];
     for _ in 0..pool.current_num_threads() {
@@ -75,7 +230,10 @@ pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_s
     }
 
     pool.install(|| {
-        fer_op.iter().par_bridge().for_each(|term| {
+        // each rayon worker operates on its own cache to avoid any synchronization overhead
+        let init = || ActionCache::new(num_qubits);
+        let terms = fer_op.iter().par_bridge();
+        terms.for_each_init(init, |cache, term| {
             let qk_coeff = qiskit_sys::QkComplex64 {
                 re: term.coeff.re,
                 im: term.coeff.im,
@@ -84,9 +242,8 @@ pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_s
             let mut mapped_term = unsafe { qiskit_sys::qk_obs_identity(num_qubits) };
 
             term.iter().for_each(|action| {
-                let mapped_action = map_action(action, num_qubits);
+                let mapped_action = cache.get(action);
                 let new_term = unsafe { qiskit_sys::qk_obs_compose(mapped_action, mapped_term) };
-                unsafe { qiskit_sys::qk_obs_free(mapped_action) };
                 unsafe { qiskit_sys::qk_obs_free(mapped_term) };
                 mapped_term = new_term;
             });
@@ -94,9 +251,6 @@ pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_s
             let scaled_term = unsafe { qiskit_sys::qk_obs_multiply(mapped_term, &qk_coeff) };
             unsafe { qiskit_sys::qk_obs_free(mapped_term) };
 
-            let canon_term = unsafe { qiskit_sys::qk_obs_canonicalize(scaled_term, 1e-18) };
-            unsafe { qiskit_sys::qk_obs_free(scaled_term) };
-
             let qubit_op = qubit_ops[pool.current_thread_index().unwrap()]
                 // this should never lock because we have one item per thread
                 .lock()
@@ -105,14 +259,20 @@ pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_s
             // PERF: we are addinf the terms one-by-one manually since this is significantly more
             // efficient that many repetitive calls to qk_obs_add. In-place addition support within
             // Qiskit would alleviate the need for this.
-            let num_add_terms = unsafe { qiskit_sys::qk_obs_num_terms(canon_term) };
+            // NOTE: the mapped term is not canonicalized here because many terms map onto
+            // overlapping Pauli strings. Instead, each per-thread observable gets canonicalized
+            // once, after all terms have been accumulated.
+            let num_add_terms = unsafe { qiskit_sys::qk_obs_num_terms(scaled_term) };
             let mut term = MaybeUninit::uninit();
             (0..num_add_terms).for_each(|j| unsafe {
-                qiskit_sys::qk_obs_term(canon_term, j as u64, term.as_mut_ptr());
+                qiskit_sys::qk_obs_term(scaled_term, j as u64, term.as_mut_ptr());
                 qiskit_sys::qk_obs_add_term(qubit_op.ptr, term.as_ptr());
             });
 
-            unsafe { qiskit_sys::qk_obs_free(canon_term) };
+            unsafe { qiskit_sys::qk_obs_free(scaled_term) };
+            drop(qubit_op);
+
+            on_term_mapped();
         });
     });
 
@@ -126,14 +286,17 @@ pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_s
                 |op1: Wrapper, op2| {
                     let op_locked = op2.lock().unwrap();
 
-                    let num_add_terms = unsafe { qiskit_sys::qk_obs_num_terms(op_locked.ptr) };
+                    let canon_op = unsafe { qiskit_sys::qk_obs_canonicalize(op_locked.ptr, 1e-18) };
+                    unsafe { qiskit_sys::qk_obs_free(op_locked.ptr) };
+
+                    let num_add_terms = unsafe { qiskit_sys::qk_obs_num_terms(canon_op) };
                     let mut term = MaybeUninit::uninit();
                     (0..num_add_terms).for_each(|j| unsafe {
-                        qiskit_sys::qk_obs_term(op_locked.ptr, j as u64, term.as_mut_ptr());
+                        qiskit_sys::qk_obs_term(canon_op, j as u64, term.as_mut_ptr());
                         qiskit_sys::qk_obs_add_term(op1.ptr, term.as_ptr());
                     });
 
-                    unsafe { qiskit_sys::qk_obs_free(op_locked.ptr) };
+                    unsafe { qiskit_sys::qk_obs_free(canon_op) };
 
                     op1
                 }
@@ -171,10 +334,169 @@ pub fn jordan_wigner(fer_op: &FermionOperator, num_qubits: u32) -> *mut qiskit_s
     mapped_operator.ptr
 }
 
+/// Returns the preimage of a single Pauli string under the Jordan-Wigner transformation.
+///
+/// `paulis` holds one of `b'I'`, `b'X'`, `b'Y'` or `b'Z'` for every qubit.
+fn invert_pauli_string(paulis: &[u8]) -> FermionOperator {
+    // Every X_j (Y_j) gets written as the image of a†_j + a_j, i.e. Z_{<j} X_j, which drags along a
+    // string of Z's on all preceding qubits. Taking these flips in ascending order leaves qubit k
+    // with c_k extra Z's, c_k being the number of flips above k. What remains on each qubit after
+    // dividing those out is at most a single Z_k, which is the image of 1 - 2 n_k.
+    let mut phase = Complex64::new(1.0, 0.0);
+    let mut flips = FermionOperator::one();
+    let mut parities = FermionOperator::one();
+    let mut num_flips_above = paulis.iter().filter(|p| matches!(p, b'X' | b'Y')).count();
+    for (k, &pauli) in paulis.iter().enumerate() {
+        let index = k as ModeIndex;
+        if matches!(pauli, b'X' | b'Y') {
+            num_flips_above -= 1;
+            flips = flips.matmul(&FermionOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0); 2],
+                actions: vec![true, false],
+                indices: vec![index, index],
+                boundaries: vec![0, 1, 2],
+            });
+        }
+        let odd = num_flips_above % 2 == 1;
+        // X Z = -i Y, so a Y only results from an odd number of dragged-along Z's up to a phase
+        let parity = match pauli {
+            b'I' | b'X' => odd,
+            b'Y' => {
+                phase *= Complex64::new(0.0, 1.0);
+                !odd
+            }
+            b'Z' => !odd,
+            _ => unreachable!("the Pauli strings only consist of I, X, Y and Z"),
+        };
+        if parity {
+            parities = parities.matmul(&FermionOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-2.0, 0.0)],
+                actions: vec![true, false],
+                indices: vec![index, index],
+                boundaries: vec![0, 0, 2],
+            });
+        }
+    }
+    flips.matmul(&parities).__mul__(phase)
+}
+
+/// Expands the terms of `obs` into Pauli strings, holding one of `b'I'`, `b'X'`, `b'Y'` or `b'Z'`
+/// for every qubit, with their coefficients. Projector terms (like `+` or `1`) get expanded into
+/// the sum of their Pauli components.
+///
+/// # Safety
+///
+/// `obs` must point to a valid observable.
+unsafe fn pauli_strings(obs: *mut qiskit_sys::QkObs) -> Vec<(Complex64, Vec<u8>)> {
+    let num_qubits = unsafe { qiskit_sys::qk_obs_num_qubits(obs) } as usize;
+    let num_terms = unsafe { qiskit_sys::qk_obs_num_terms(obs) };
+
+    let mut out = vec![];
+    let mut term = MaybeUninit::<qiskit_sys::QkObsTerm>::uninit();
+    for j in 0..num_terms {
+        let term = unsafe {
+            qiskit_sys::qk_obs_term(obs, j as u64, term.as_mut_ptr());
+            term.assume_init_ref()
+        };
+        let mut expanded = vec![(
+            Complex64::new(term.coeff.re, term.coeff.im),
+            vec![b'I'; num_qubits],
+        )];
+        for k in 0..term.len {
+            let (bit_term, index) = unsafe { (*term.bit_terms.add(k), *term.indices.add(k)) };
+            let expansion: &[(f64, u8)] = match bit_term {
+                qiskit_sys::QkBitTerm_QkBitTerm_X => &[(1.0, b'X')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Y => &[(1.0, b'Y')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Z => &[(1.0, b'Z')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Plus => &[(0.5, b'I'), (0.5, b'X')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Minus => &[(0.5, b'I'), (-0.5, b'X')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Right => &[(0.5, b'I'), (0.5, b'Y')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Left => &[(0.5, b'I'), (-0.5, b'Y')],
+                qiskit_sys::QkBitTerm_QkBitTerm_Zero => &[(0.5, b'I'), (0.5, b'Z')],
+                qiskit_sys::QkBitTerm_QkBitTerm_One => &[(0.5, b'I'), (-0.5, b'Z')],
+                _ => unreachable!("the observable contains an invalid bit term"),
+            };
+            expanded = expanded
+                .into_iter()
+                .flat_map(|(coeff, paulis)| {
+                    expansion.iter().map(move |&(factor, pauli)| {
+                        let mut paulis = paulis.clone();
+                        paulis[index as usize] = pauli;
+                        (coeff * factor, paulis)
+                    })
+                })
+                .collect();
+        }
+        out.extend(expanded);
+    }
+    out
+}
+
+/// Maps a qubit observable back onto a [`FermionOperator`] by inverting the Jordan-Wigner
+/// transformation, acting on as many fermionic modes as `obs` has qubits.
+///
+/// Projector terms (like `+` or `1`) get expanded into Pauli strings first. The result is
+/// normal-ordered and simplified.
+///
+/// # Safety
+///
+/// `obs` must point to a valid observable, which remains owned by the caller.
+pub unsafe fn inverse_jordan_wigner(obs: *mut qiskit_sys::QkObs) -> FermionOperator {
+    let mut fer_op = FermionOperator::zero();
+    for (coeff, paulis) in unsafe { pauli_strings(obs) } {
+        fer_op.scaled_iadd(&invert_pauli_string(&paulis), coeff);
+    }
+    fer_op.normal_ordered().simplify(0.0)
+}
+
+/// Like [`inverse_jordan_wigner`], but only keeps the terms acting on the first `num_modes`
+/// fermionic modes and verifies that mapping the result with [`jordan_wigner`] reproduces `obs`.
+///
+/// This detects observables which are not the image of an operator on `num_modes` modes, for
+/// which the inverse is ill-defined.
+///
+/// # Safety
+///
+/// `obs` must point to a valid observable, which remains owned by the caller.
+pub unsafe fn inverse_jordan_wigner_checked(
+    obs: *mut qiskit_sys::QkObs,
+    num_modes: ModeIndex,
+) -> Result<FermionOperator, MappingError> {
+    let fer_op = unsafe { inverse_jordan_wigner(obs) }.restrict_to_modes(num_modes);
+
+    let num_qubits = unsafe { qiskit_sys::qk_obs_num_qubits(obs) };
+    let remapped = jordan_wigner(&fer_op, num_qubits);
+    let factor = qiskit_sys::QkComplex64 { re: -1.0, im: 0.0 };
+    let neg_remapped = unsafe { qiskit_sys::qk_obs_multiply(remapped, &factor) };
+    let sum = unsafe { qiskit_sys::qk_obs_add(obs, neg_remapped) };
+    // NOTE: the difference gets compared in the Pauli basis, since canonicalizing an observable
+    // does not merge projector terms with the Pauli terms produced by the re-mapping
+    let mut diff: HashMap<Vec<u8>, Complex64> = HashMap::new();
+    for (coeff, paulis) in unsafe { pauli_strings(sum) } {
+        *diff.entry(paulis).or_default() += coeff;
+    }
+    for obs in [remapped, neg_remapped, sum] {
+        unsafe { qiskit_sys::qk_obs_free(obs) };
+    }
+    let num_terms = diff
+        .values()
+        .filter(|coeff| coeff.norm() > ROUND_TRIP_ATOL)
+        .count();
+
+    if num_terms > 0 {
+        return Err(MappingError {
+            num_modes,
+            num_terms,
+        });
+    }
+    Ok(fer_op)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::operators::library::fcidump::FCIDump;
     use num_complex::Complex64;
 
     #[test]
@@ -386,4 +708,305 @@ mod tests {
 
         assert!(equal)
     }
+
+    #[test]
+    fn test_jordan_wigner_single_canonicalization() {
+        // The result of mapping the whole operator at once, which canonicalizes only the
+        // accumulated per-thread observables, must match mapping (and thereby canonicalizing)
+        // every term individually. For the runtime impact of the former, compare the
+        // `jordan_wigner/h2` benchmark (see `make benchrust`).
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let fer_op = FermionOperator::from(&fcidump);
+        let num_qubits = 2 * fcidump.norb;
+
+        let qb_op = jordan_wigner(&fer_op, num_qubits);
+
+        let mut expected = unsafe { qiskit_sys::qk_obs_zero(num_qubits) };
+        for term in fer_op.iter() {
+            let term_op = FermionOperator {
+                coeffs: vec![term.coeff],
+                actions: term.actions.to_vec(),
+                indices: term.indices.to_vec(),
+                boundaries: vec![0, term.indices.len()],
+            };
+            let mapped_term = jordan_wigner(&term_op, num_qubits);
+            let canon_term = unsafe { qiskit_sys::qk_obs_canonicalize(mapped_term, 1e-18) };
+            let sum = unsafe { qiskit_sys::qk_obs_add(expected, canon_term) };
+            unsafe { qiskit_sys::qk_obs_free(mapped_term) };
+            unsafe { qiskit_sys::qk_obs_free(canon_term) };
+            unsafe { qiskit_sys::qk_obs_free(expected) };
+            expected = sum;
+        }
+
+        let factor = qiskit_sys::QkComplex64 { re: -1.0, im: 0.0 };
+        let neg_expected = unsafe { qiskit_sys::qk_obs_multiply(expected, &factor) };
+        let sum = unsafe { qiskit_sys::qk_obs_add(qb_op, neg_expected) };
+        let diff = unsafe { qiskit_sys::qk_obs_canonicalize(sum, 1e-10) };
+        let zero = unsafe { qiskit_sys::qk_obs_zero(num_qubits) };
+
+        let equal = unsafe { qiskit_sys::qk_obs_equal(diff, zero) };
+
+        for obs in [qb_op, expected, neg_expected, sum, diff, zero] {
+            unsafe { qiskit_sys::qk_obs_free(obs) };
+        }
+
+        assert!(equal)
+    }
+
+    #[test]
+    fn test_jordan_wigner_action_cache() {
+        // The cached single-action observables must yield exactly the same result as re-mapping
+        // every action from scratch.
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let fer_op = FermionOperator::from(&fcidump);
+        let num_qubits = 2 * fcidump.norb;
+
+        let mut cache = ActionCache::new(num_qubits);
+        for term in fer_op.iter() {
+            for action in term.iter() {
+                let cached = cache.get(action);
+                let uncached = map_action(action, num_qubits);
+                let equal = unsafe { qiskit_sys::qk_obs_equal(cached, uncached) };
+                unsafe { qiskit_sys::qk_obs_free(uncached) };
+                assert!(equal);
+            }
+        }
+        assert_eq!(cache.mapped_actions.len(), 8);
+
+        let qb_op = jordan_wigner(&fer_op, num_qubits);
+
+        let mut expected = unsafe { qiskit_sys::qk_obs_zero(num_qubits) };
+        for term in fer_op.iter() {
+            let mut mapped_term = unsafe { qiskit_sys::qk_obs_identity(num_qubits) };
+            for action in term.iter() {
+                let mapped_action = map_action(action, num_qubits);
+                let new_term = unsafe { qiskit_sys::qk_obs_compose(mapped_action, mapped_term) };
+                unsafe { qiskit_sys::qk_obs_free(mapped_action) };
+                unsafe { qiskit_sys::qk_obs_free(mapped_term) };
+                mapped_term = new_term;
+            }
+            let qk_coeff = qiskit_sys::QkComplex64 {
+                re: term.coeff.re,
+                im: term.coeff.im,
+            };
+            let scaled_term = unsafe { qiskit_sys::qk_obs_multiply(mapped_term, &qk_coeff) };
+            let sum = unsafe { qiskit_sys::qk_obs_add(expected, scaled_term) };
+            unsafe { qiskit_sys::qk_obs_free(mapped_term) };
+            unsafe { qiskit_sys::qk_obs_free(scaled_term) };
+            unsafe { qiskit_sys::qk_obs_free(expected) };
+            expected = sum;
+        }
+
+        let factor = qiskit_sys::QkComplex64 { re: -1.0, im: 0.0 };
+        let neg_expected = unsafe { qiskit_sys::qk_obs_multiply(expected, &factor) };
+        let sum = unsafe { qiskit_sys::qk_obs_add(qb_op, neg_expected) };
+        let diff = unsafe { qiskit_sys::qk_obs_canonicalize(sum, 1e-10) };
+        let zero = unsafe { qiskit_sys::qk_obs_zero(num_qubits) };
+
+        let equal = unsafe { qiskit_sys::qk_obs_equal(diff, zero) };
+
+        for obs in [qb_op, expected, neg_expected, sum, diff, zero] {
+            unsafe { qiskit_sys::qk_obs_free(obs) };
+        }
+
+        assert!(equal)
+    }
+
+    #[test]
+    fn test_jordan_wigner_shared_thread_pool() {
+        let num_qubits = 4;
+        let pool = thread_pool();
+        for i in 0..100 {
+            let mode = i % num_qubits;
+            let number_op = FermionOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                actions: vec![true, false],
+                indices: vec![mode as ModeIndex, mode as ModeIndex],
+                boundaries: vec![0, 2],
+            };
+            let qb_op = jordan_wigner(&number_op, num_qubits);
+
+            // n_i = (I - Z_i) / 2
+            let mut coeffs = vec![
+                qiskit_sys::QkComplex64 { re: 0.5, im: 0.0 },
+                qiskit_sys::QkComplex64 { re: -0.5, im: 0.0 },
+            ];
+            let mut bit_terms = vec![qiskit_sys::QkBitTerm_QkBitTerm_Z];
+            let mut indices = vec![mode];
+            let mut boundaries: Vec<usize> = vec![0, 0, 1];
+            let expected = unsafe {
+                qiskit_sys::qk_obs_new(
+                    num_qubits,
+                    coeffs.len().try_into().unwrap(),
+                    bit_terms.len().try_into().unwrap(),
+                    coeffs.as_mut_ptr(),
+                    bit_terms.as_mut_ptr(),
+                    indices.as_mut_ptr(),
+                    boundaries.as_mut_ptr(),
+                )
+            };
+
+            let factor = qiskit_sys::QkComplex64 { re: -1.0, im: 0.0 };
+            let neg_expected = unsafe { qiskit_sys::qk_obs_multiply(expected, &factor) };
+            let sum = unsafe { qiskit_sys::qk_obs_add(qb_op, neg_expected) };
+            let diff = unsafe { qiskit_sys::qk_obs_canonicalize(sum, 1e-10) };
+            let zero = unsafe { qiskit_sys::qk_obs_zero(num_qubits) };
+
+            let equal = unsafe { qiskit_sys::qk_obs_equal(diff, zero) };
+
+            for obs in [qb_op, expected, neg_expected, sum, diff, zero] {
+                unsafe { qiskit_sys::qk_obs_free(obs) };
+            }
+
+            assert!(equal);
+        }
+
+        // all calls have reused the same pool, which can no longer be reconfigured
+        assert!(std::ptr::eq(pool, thread_pool()));
+        assert!(!configure_thread_pool(1));
+    }
+
+    #[test]
+    fn test_jordan_wigner_with_progress() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let fer_op = FermionOperator::from(&fcidump);
+        let num_qubits = 2 * fcidump.norb;
+        let total = fer_op.coeffs.len();
+        assert_eq!(total, 41);
+
+        let mut calls = vec![];
+        let qb_op = jordan_wigner_with_progress(&fer_op, num_qubits, 10, |done, total| {
+            calls.push((done, total))
+        });
+        assert_eq!(calls, [(10, 41), (20, 41), (30, 41), (40, 41), (41, 41)]);
+
+        let expected = jordan_wigner(&fer_op, num_qubits);
+        let factor = qiskit_sys::QkComplex64 { re: -1.0, im: 0.0 };
+        let neg_expected = unsafe { qiskit_sys::qk_obs_multiply(expected, &factor) };
+        let sum = unsafe { qiskit_sys::qk_obs_add(qb_op, neg_expected) };
+        let diff = unsafe { qiskit_sys::qk_obs_canonicalize(sum, 1e-10) };
+        let zero = unsafe { qiskit_sys::qk_obs_zero(num_qubits) };
+
+        let equal = unsafe { qiskit_sys::qk_obs_equal(diff, zero) };
+
+        for obs in [qb_op, expected, neg_expected, sum, diff, zero] {
+            unsafe { qiskit_sys::qk_obs_free(obs) };
+        }
+
+        assert!(equal)
+    }
+
+    #[test]
+    #[should_panic(expected = "the fermionic mode 4 exceeds the number of qubits (4)")]
+    fn test_map_action_out_of_range() {
+        map_action((&true, &4), 4);
+    }
+
+    #[test]
+    fn test_inverse_jordan_wigner_round_trip() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let fer_op = FermionOperator::from(&fcidump);
+        let num_qubits = 2 * fcidump.norb;
+
+        let qb_op = jordan_wigner(&fer_op, num_qubits);
+        let inverse = unsafe { inverse_jordan_wigner_checked(qb_op, num_qubits as ModeIndex) };
+        unsafe { qiskit_sys::qk_obs_free(qb_op) };
+
+        assert!(inverse.unwrap().equiv(&fer_op.normal_ordered(), 1e-10));
+    }
+
+    #[test]
+    fn test_inverse_jordan_wigner_projectors() {
+        // |1><1|_0 maps back onto n_0 and Y_1 onto i (a†_1 - a_1) (1 - 2 n_0), while
+        // n_0 (1 - 2 n_0) = -n_0
+        let mut coeffs = vec![qiskit_sys::QkComplex64 { re: 2.0, im: 0.0 }];
+        let mut bit_terms = vec![
+            qiskit_sys::QkBitTerm_QkBitTerm_One,
+            qiskit_sys::QkBitTerm_QkBitTerm_Y,
+        ];
+        let mut indices = vec![0, 1];
+        let mut boundaries = vec![0, 2];
+        let obs = unsafe {
+            qiskit_sys::qk_obs_new(
+                2,
+                1,
+                2,
+                coeffs.as_mut_ptr(),
+                bit_terms.as_mut_ptr(),
+                indices.as_mut_ptr(),
+                boundaries.as_mut_ptr(),
+            )
+        };
+        let inverse = unsafe { inverse_jordan_wigner(obs) };
+        unsafe { qiskit_sys::qk_obs_free(obs) };
+
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(0.0, -2.0), Complex64::new(0.0, 2.0)],
+            actions: vec![true, true, false, true, false, false],
+            indices: vec![1, 0, 0, 1, 0, 0],
+            boundaries: vec![0, 3, 6],
+        };
+        assert!(inverse.equiv(&expected.normal_ordered(), 1e-12));
+    }
+
+    #[test]
+    fn test_inverse_jordan_wigner_checked_projector() {
+        // |1><1|_0 is the image of n_0, although its re-mapped image is (I - Z_0) / 2
+        let mut coeffs = vec![qiskit_sys::QkComplex64 { re: 1.0, im: 0.0 }];
+        let mut bit_terms = vec![qiskit_sys::QkBitTerm_QkBitTerm_One];
+        let mut indices = vec![0];
+        let mut boundaries = vec![0, 1];
+        let obs = unsafe {
+            qiskit_sys::qk_obs_new(
+                1,
+                1,
+                1,
+                coeffs.as_mut_ptr(),
+                bit_terms.as_mut_ptr(),
+                indices.as_mut_ptr(),
+                boundaries.as_mut_ptr(),
+            )
+        };
+        let inverse = unsafe { inverse_jordan_wigner_checked(obs, 1) };
+        unsafe { qiskit_sys::qk_obs_free(obs) };
+
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(inverse, Ok(expected));
+    }
+
+    #[test]
+    fn test_inverse_jordan_wigner_checked_not_in_image() {
+        // Z_2 acts on a qubit without a corresponding mode among the first two, so only the
+        // identity part of its preimage 1 - 2 n_2 survives and the difference is Z_2 - I
+        let mut coeffs = vec![qiskit_sys::QkComplex64 { re: 1.0, im: 0.0 }];
+        let mut bit_terms = vec![qiskit_sys::QkBitTerm_QkBitTerm_Z];
+        let mut indices = vec![2];
+        let mut boundaries = vec![0, 1];
+        let obs = unsafe {
+            qiskit_sys::qk_obs_new(
+                3,
+                1,
+                1,
+                coeffs.as_mut_ptr(),
+                bit_terms.as_mut_ptr(),
+                indices.as_mut_ptr(),
+                boundaries.as_mut_ptr(),
+            )
+        };
+        let inverse = unsafe { inverse_jordan_wigner_checked(obs, 2) };
+        unsafe { qiskit_sys::qk_obs_free(obs) };
+
+        assert_eq!(
+            inverse,
+            Err(MappingError {
+                num_modes: 2,
+                num_terms: 2
+            })
+        );
+    }
 }
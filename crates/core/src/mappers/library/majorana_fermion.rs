@@ -11,38 +11,148 @@
 // that they have been altered from the originals.
 
 use num_complex::Complex64;
+use rayon::prelude::*;
+use std::f64::consts::FRAC_1_SQRT_2;
 
-use crate::operators::OperatorTrait;
-use crate::operators::fermion_operator::{FermionAction, FermionOperator};
+use crate::operators::fermion_operator::{FermionAction, FermionOperator, FermionOperatorTermView};
+use crate::operators::library::electronic_integrals::{From1Body, From2Body, SpinOrdering};
 use crate::operators::majorana_operator::{MajoranaAction, MajoranaOperator};
+use crate::operators::{ModeIndex, OperatorTrait};
+use ndarray::ArrayView1;
+
+/// The convention relating the fermionic creation and annihilation operators of mode `j` to the
+/// two Majorana operators `γ_j` and `γ'_j`, which are stored on the Majorana modes `2j` and
+/// `2j + 1`, respectively.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MajoranaConvention {
+    /// `γ_j = a†_j + a_j` and `γ'_j = i (a†_j - a_j)`, such that `a†_j = (γ_j - i γ'_j) / 2`
+    /// and `a_j = (γ_j + i γ'_j) / 2`.
+    #[default]
+    Default,
+    /// `γ_j = a_j + a†_j` and `γ'_j = i (a_j - a†_j)`, such that `a†_j = (γ_j + i γ'_j) / 2`
+    /// and `a_j = (γ_j - i γ'_j) / 2`.
+    FlippedSign,
+    /// `γ_j = (a†_j + a_j) / √2` and `γ'_j = i (a†_j - a_j) / √2`, such that
+    /// `a†_j = (γ_j - i γ'_j) / √2` and `a_j = (γ_j + i γ'_j) / √2`.
+    ///
+    /// Note that these Majorana operators square to `1/2`, whereas the simplifications of
+    /// [`MajoranaOperator`] (like [`MajoranaOperator::normal_ordered`]) assume them to square to
+    /// `1`.
+    Normalized,
+}
 
-fn map_fermion_action(action: FermionAction) -> MajoranaOperator {
-    let im = if *action.0 { -0.5 } else { 0.5 };
+impl MajoranaConvention {
+    /// Returns the coefficients of `γ_j` and `γ'_j` in the expansion of `a†_j`.
+    fn creation_coeffs(&self) -> (Complex64, Complex64) {
+        match self {
+            Self::Default => (Complex64::new(0.5, 0.0), Complex64::new(0.0, -0.5)),
+            Self::FlippedSign => (Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.5)),
+            Self::Normalized => (
+                Complex64::new(FRAC_1_SQRT_2, 0.0),
+                Complex64::new(0.0, -FRAC_1_SQRT_2),
+            ),
+        }
+    }
+
+    /// Returns the factors `c` and `c'` for which `γ_j = c (a†_j + a_j)` and
+    /// `γ'_j = c' (a†_j - a_j)`.
+    fn majorana_coeffs(&self) -> (Complex64, Complex64) {
+        let (re, im) = self.creation_coeffs();
+        (0.5 * re.inv(), 0.5 * im.inv())
+    }
+}
+
+fn map_fermion_action(action: FermionAction, convention: MajoranaConvention) -> MajoranaOperator {
+    let (re, im) = convention.creation_coeffs();
+    // the annihilation operator is the adjoint of the creation operator
+    let im = if *action.0 { im } else { -im };
 
     MajoranaOperator {
-        coeffs: vec![Complex64::new(0.5, 0.0), Complex64::new(0.0, im)],
+        coeffs: vec![re, im],
         modes: vec![*action.1 * 2, *action.1 * 2 + 1],
         boundaries: vec![0, 1, 2],
     }
 }
 
-pub fn fermion_to_majorana(fer_op: &FermionOperator) -> MajoranaOperator {
-    let mut mapped_operator = MajoranaOperator::zero();
+fn map_fermion_term(
+    term: FermionOperatorTermView,
+    convention: MajoranaConvention,
+) -> MajoranaOperator {
+    let mut mapped_term = MajoranaOperator::one();
 
-    fer_op.iter().for_each(|term| {
-        let mut mapped_term = MajoranaOperator::one();
+    term.iter()
+        .for_each(|action| mapped_term.__iand__(&map_fermion_action(action, convention)));
 
-        term.iter()
-            .for_each(|action| mapped_term.__iand__(&map_fermion_action(action)));
+    mapped_term.__imul__(term.coeff);
 
-        mapped_term.__imul__(term.coeff);
+    mapped_term
+}
 
-        mapped_operator.__iadd__(&mapped_term);
-    });
+pub fn fermion_to_majorana(fer_op: &FermionOperator) -> MajoranaOperator {
+    fermion_to_majorana_with_convention(fer_op, MajoranaConvention::Default)
+}
+
+/// Variant of [fermion_to_majorana] using the provided [`MajoranaConvention`].
+pub fn fermion_to_majorana_with_convention(
+    fer_op: &FermionOperator,
+    convention: MajoranaConvention,
+) -> MajoranaOperator {
+    let mut mapped_operator = MajoranaOperator::zero();
+
+    fer_op
+        .iter()
+        .for_each(|term| mapped_operator.__iadd__(&map_fermion_term(term, convention)));
 
     mapped_operator
 }
 
+/// Parallel variant of [fermion_to_majorana] running on at most `num_threads` threads of the global
+/// rayon pool (or on all of them if `None`). The order of the mapped terms does not depend on the
+/// scheduling.
+pub fn fermion_to_majorana_with(
+    fer_op: &FermionOperator,
+    num_threads: Option<usize>,
+) -> MajoranaOperator {
+    fer_op.to_majorana(MajoranaConvention::Default, num_threads)
+}
+
+impl FermionOperator {
+    /// Maps this operator using the provided [`MajoranaConvention`] on at most `num_threads`
+    /// threads of the global rayon pool (or on all of them if `None`). The order of the mapped
+    /// terms does not depend on the scheduling.
+    pub fn to_majorana(
+        &self,
+        convention: MajoranaConvention,
+        num_threads: Option<usize>,
+    ) -> MajoranaOperator {
+        // PERF: splitting the terms into `num_threads` chunks bounds the parallelism without
+        // building a dedicated pool, which would spawn new OS threads on every call
+        let num_chunks = num_threads
+            .filter(|num_threads| *num_threads > 0)
+            .unwrap_or_else(rayon::current_num_threads);
+        let terms: Vec<FermionOperatorTermView> = self.iter().collect();
+        let chunk_size = terms.len().div_ceil(num_chunks).max(1);
+
+        let mapped_chunks: Vec<MajoranaOperator> = terms
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut mapped_chunk = MajoranaOperator::zero();
+                chunk
+                    .iter()
+                    .for_each(|&term| mapped_chunk.__iadd__(&map_fermion_term(term, convention)));
+                mapped_chunk
+            })
+            .collect();
+
+        let mut mapped_operator = MajoranaOperator::zero();
+        mapped_chunks
+            .iter()
+            .for_each(|chunk| mapped_operator.__iadd__(chunk));
+
+        mapped_operator
+    }
+}
+
 fn map_majorana_action(mode: MajoranaAction) -> FermionOperator {
     let idx = mode / 2;
     let im = if mode.is_multiple_of(2) { 1.0 } else { -1.0 };
@@ -55,6 +165,11 @@ fn map_majorana_action(mode: MajoranaAction) -> FermionOperator {
     }
 }
 
+/// Maps `γ_j` to `a†_j + i a_j` and `γ'_j` to `a†_j - i a_j`.
+///
+/// This is **not** the inverse of [fermion_to_majorana] for any [`MajoranaConvention`]. Use
+/// [majorana_to_fermion_with_convention] to undo the mapping of [fermion_to_majorana] (or of
+/// [fermion_to_majorana_with_convention]).
 pub fn majorana_to_fermion(maj_op: &MajoranaOperator) -> FermionOperator {
     let mut mapped_operator = FermionOperator::zero();
 
@@ -72,9 +187,173 @@ pub fn majorana_to_fermion(maj_op: &MajoranaOperator) -> FermionOperator {
     mapped_operator
 }
 
+fn map_majorana_action_with_convention(
+    mode: MajoranaAction,
+    convention: MajoranaConvention,
+) -> FermionOperator {
+    let idx = mode / 2;
+    let (coeff, coeff_prime) = convention.majorana_coeffs();
+    let coeffs = if mode.is_multiple_of(2) {
+        vec![coeff, coeff]
+    } else {
+        vec![coeff_prime, -coeff_prime]
+    };
+
+    FermionOperator {
+        coeffs,
+        actions: vec![true, false],
+        indices: vec![idx, idx],
+        boundaries: vec![0, 1, 2],
+    }
+}
+
+/// Maps `γ_j` and `γ'_j` back to fermionic operators according to the provided
+/// [`MajoranaConvention`]. This inverts [fermion_to_majorana_with_convention] for the same
+/// convention.
+pub fn majorana_to_fermion_with_convention(
+    maj_op: &MajoranaOperator,
+    convention: MajoranaConvention,
+) -> FermionOperator {
+    let mut mapped_operator = FermionOperator::zero();
+
+    maj_op.iter().for_each(|term| {
+        let mut mapped_term = FermionOperator::one();
+
+        term.iter().for_each(|action| {
+            mapped_term.__iand__(&map_majorana_action_with_convention(action, convention))
+        });
+
+        mapped_term.__imul__(term.coeff);
+
+        mapped_operator.__iadd__(&mapped_term);
+    });
+
+    mapped_operator
+}
+
+impl MajoranaOperator {
+    /// Maps this operator using [`majorana_to_fermion`] and returns the normal-ordered and
+    /// simplified result.
+    pub fn to_fermion_normal_ordered(&self, atol: f64) -> FermionOperator {
+        majorana_to_fermion(self).normal_ordered().simplify(atol)
+    }
+}
+
+/// Maps an operator built from electronic integrals, normal-ordering and simplifying the result
+/// to merge the many terms which [`fermion_to_majorana`] produces for every fermionic term.
+fn _map_integrals(fer_op: &FermionOperator) -> MajoranaOperator {
+    fermion_to_majorana(fer_op)
+        .normal_ordered(true)
+        .simplify(0.0)
+}
+
+/// The Majorana constructors build the corresponding [`FermionOperator`], map it via
+/// [`fermion_to_majorana`] and normal-order and simplify the result.
+impl From1Body for MajoranaOperator {
+    fn add_1body_tril_spin_sym(&mut self, one_body_a: ArrayView1<f64>, norb: ModeIndex) {
+        self.__iadd__(&_map_integrals(&FermionOperator::from_1body_tril_spin_sym(
+            one_body_a, norb,
+        )));
+    }
+
+    fn from_1body_tril_spin_sym(one_body_a: ArrayView1<f64>, norb: ModeIndex) -> Self {
+        let mut op = Self::zero();
+        op.add_1body_tril_spin_sym(one_body_a, norb);
+        op
+    }
+
+    fn from_1body_tril_spin_sym_ordered(
+        one_body_a: ArrayView1<f64>,
+        norb: ModeIndex,
+        ordering: SpinOrdering,
+    ) -> Self {
+        _map_integrals(&FermionOperator::from_1body_tril_spin_sym_ordered(
+            one_body_a, norb, ordering,
+        ))
+    }
+
+    fn add_1body_tril_spin(
+        &mut self,
+        one_body_a: ArrayView1<f64>,
+        one_body_b: ArrayView1<f64>,
+        norb: ModeIndex,
+    ) {
+        self.__iadd__(&_map_integrals(&FermionOperator::from_1body_tril_spin(
+            one_body_a, one_body_b, norb,
+        )));
+    }
+
+    fn from_1body_tril_spin(
+        one_body_a: ArrayView1<f64>,
+        one_body_b: ArrayView1<f64>,
+        norb: ModeIndex,
+    ) -> Self {
+        let mut op = Self::zero();
+        op.add_1body_tril_spin(one_body_a, one_body_b, norb);
+        op
+    }
+}
+
+/// See the [`From1Body`] implementation of [`MajoranaOperator`].
+impl From2Body for MajoranaOperator {
+    fn add_2body_tril_spin_sym(
+        &mut self,
+        two_body_aa: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) {
+        self.__iadd__(&_map_integrals(&FermionOperator::from_2body_tril_spin_sym(
+            two_body_aa,
+            norb,
+            drop_vanishing,
+        )));
+    }
+
+    fn from_2body_tril_spin_sym(
+        two_body_aa: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) -> Self {
+        let mut op = Self::zero();
+        op.add_2body_tril_spin_sym(two_body_aa, norb, drop_vanishing);
+        op
+    }
+
+    fn add_2body_tril_spin(
+        &mut self,
+        two_body_aa: ArrayView1<f64>,
+        two_body_ab: ArrayView1<f64>,
+        two_body_bb: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) {
+        self.__iadd__(&_map_integrals(&FermionOperator::from_2body_tril_spin(
+            two_body_aa,
+            two_body_ab,
+            two_body_bb,
+            norb,
+            drop_vanishing,
+        )));
+    }
+
+    fn from_2body_tril_spin(
+        two_body_aa: ArrayView1<f64>,
+        two_body_ab: ArrayView1<f64>,
+        two_body_bb: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) -> Self {
+        let mut op = Self::zero();
+        op.add_2body_tril_spin(two_body_aa, two_body_ab, two_body_bb, norb, drop_vanishing);
+        op
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ndarray::Array1;
+    use std::iter::zip;
 
     #[test]
     fn test_fermion_to_majorana_1() {
@@ -96,6 +375,72 @@ mod tests {
         assert_eq!(maj_op, expected);
     }
 
+    #[test]
+    fn test_fermion_to_majorana_conventions() {
+        let fer_op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+
+        for (convention, re, im) in [
+            (MajoranaConvention::Default, 0.5, -0.5),
+            (MajoranaConvention::FlippedSign, 0.5, 0.5),
+            (
+                MajoranaConvention::Normalized,
+                FRAC_1_SQRT_2,
+                -FRAC_1_SQRT_2,
+            ),
+        ] {
+            let expected = MajoranaOperator {
+                coeffs: vec![Complex64::new(re, 0.0), Complex64::new(0.0, im)],
+                modes: vec![0, 1],
+                boundaries: vec![0, 1, 2],
+            };
+            assert_eq!(
+                fermion_to_majorana_with_convention(&fer_op, convention),
+                expected
+            );
+            assert_eq!(fer_op.to_majorana(convention, Some(2)), expected);
+        }
+
+        assert_eq!(
+            fermion_to_majorana_with_convention(&fer_op, MajoranaConvention::default()),
+            fermion_to_majorana(&fer_op)
+        );
+    }
+
+    #[test]
+    fn test_majorana_convention_round_trip() {
+        let fer_op = FermionOperator::random(20, 4, 4, Some(0));
+        let expected = fer_op.normal_ordered().simplify(1e-10);
+
+        for convention in [
+            MajoranaConvention::Default,
+            MajoranaConvention::FlippedSign,
+            MajoranaConvention::Normalized,
+        ] {
+            let maj_op = fermion_to_majorana_with_convention(&fer_op, convention);
+            let round_trip = majorana_to_fermion_with_convention(&maj_op, convention);
+            assert!(
+                round_trip
+                    .normal_ordered()
+                    .simplify(1e-10)
+                    .equiv(&expected, 1e-10)
+            );
+        }
+
+        // the plain mapping back does not undo the default convention
+        let round_trip = majorana_to_fermion(&fermion_to_majorana(&fer_op));
+        assert!(
+            !round_trip
+                .normal_ordered()
+                .simplify(1e-10)
+                .equiv(&expected, 1e-10)
+        );
+    }
+
     #[test]
     fn test_fermion_to_majorana_2() {
         let fer_op = FermionOperator {
@@ -160,6 +505,20 @@ mod tests {
         assert!(canon.equiv(&expected, 1e-10));
     }
 
+    #[test]
+    fn test_fermion_to_majorana_with() {
+        let fer_op = FermionOperator::random(200, 6, 4, Some(0));
+
+        let serial = fermion_to_majorana(&fer_op);
+        let single = fermion_to_majorana_with(&fer_op, Some(1));
+        let multi = fermion_to_majorana_with(&fer_op, Some(4));
+        let all = fermion_to_majorana_with(&fer_op, None);
+
+        assert_eq!(single, serial);
+        assert_eq!(multi, single);
+        assert_eq!(all, single);
+    }
+
     #[test]
     fn test_majorana_to_fermion_1() {
         let maj_op = MajoranaOperator {
@@ -243,4 +602,78 @@ mod tests {
 
         assert!(canon.equiv(&expected, 1e-10));
     }
+
+    #[test]
+    fn test_majorana_tril_spin_sym() {
+        let norb = 2;
+        let one_body_a = Array1::from_iter((1..4).map(f64::from));
+        let two_body_aa = Array1::from_iter((1..7).map(f64::from));
+
+        let op = MajoranaOperator::from_1body_tril_spin_sym(ArrayView1::from(&one_body_a), norb);
+        let mapped = fermion_to_majorana(&FermionOperator::from_1body_tril_spin_sym(
+            ArrayView1::from(&one_body_a),
+            norb,
+        ));
+        assert!(op.equiv(&mapped.normal_ordered(true), 1e-12));
+        assert_eq!(op, op.normal_ordered(true).simplify(0.0));
+
+        let op =
+            MajoranaOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa), norb, false);
+        let mapped = fermion_to_majorana(&FermionOperator::from_2body_tril_spin_sym(
+            ArrayView1::from(&two_body_aa),
+            norb,
+            false,
+        ));
+        assert!(op.equiv(&mapped.normal_ordered(true), 1e-12));
+        assert_eq!(op, op.normal_ordered(true).simplify(0.0));
+        assert!(op.coeffs.len() < mapped.coeffs.len());
+    }
+
+    #[test]
+    fn test_to_fermion_normal_ordered() {
+        let maj_ops = [
+            MajoranaOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                modes: vec![0],
+                boundaries: vec![0, 1],
+            },
+            MajoranaOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                modes: vec![1],
+                boundaries: vec![0, 1],
+            },
+            MajoranaOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                modes: vec![0, 1],
+                boundaries: vec![0, 2],
+            },
+            MajoranaOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+                modes: vec![0, 3, 2, 1],
+                boundaries: vec![0, 2, 4],
+            },
+        ];
+
+        for maj_op in maj_ops {
+            let manual = majorana_to_fermion(&maj_op).normal_ordered().simplify(1e-8);
+            assert!(maj_op.to_fermion_normal_ordered(1e-8).equiv(&manual, 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_to_sparse_matrix_round_trip() {
+        let dense = |(rows, cols, values): (Vec<usize>, Vec<usize>, Vec<Complex64>)| {
+            let mut out = vec![Complex64::new(0.0, 0.0); 16 * 16];
+            for ((row, col), value) in zip(zip(rows, cols), values) {
+                out[16 * row + col] += value;
+            }
+            out
+        };
+        for seed in 0..5 {
+            let fer_op = FermionOperator::random(20, 4, 4, Some(seed));
+            let mapped = dense(fermion_to_majorana(&fer_op).to_sparse_matrix(4));
+            let expected = dense(fer_op.to_sparse_matrix(4));
+            assert!(zip(mapped, expected).all(|(a, b)| (a - b).norm() < 1e-12));
+        }
+    }
 }
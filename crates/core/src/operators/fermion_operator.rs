@@ -10,22 +10,136 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
-use crate::operators::{OperatorMacro, OperatorTrait};
-use num_complex::{Complex64, ComplexFloat};
-use std::cmp::Ordering;
-use std::collections::HashMap;
+use crate::operators::{ModeIndex, OperatorMacro, OperatorTrait, fock_dim, round_coeff};
+use ndarray::{Array1, Array2, ArrayView2, Axis};
+use num_complex::{Complex32, Complex64, ComplexFloat};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::iter::zip;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-pub type FermionAction<'a> = (&'a bool, &'a u32);
+pub type FermionAction<'a> = (&'a bool, &'a ModeIndex);
+
+/// The error returned by [`FermionOperator::normal_ordered_bounded`] when normal ordering would
+/// produce more than `max_terms` terms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NormalOrderError {
+    pub max_terms: usize,
+}
+
+impl fmt::Display for NormalOrderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Normal ordering exceeded the maximum of {} terms.",
+            self.max_terms
+        )
+    }
+}
+
+impl std::error::Error for NormalOrderError {}
+
+/// The error returned by [`FermionOperator::check_coeffs`] when the coefficient of the term at
+/// position `term` is NaN or infinite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidCoeffError {
+    pub term: usize,
+}
+
+impl fmt::Display for InvalidCoeffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Term {} has a NaN or infinite coefficient.", self.term)
+    }
+}
+
+impl std::error::Error for InvalidCoeffError {}
+
+/// The error returned by [`FermionOperator::to_1body_matrix`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OneBodyMatrixError {
+    /// The term at position `term` is not of the form `a†_i a_j`.
+    NotOneBody { term: usize },
+    /// The mode `index` lies outside of the `norb` rows and columns of the matrix.
+    IndexOutOfRange { index: ModeIndex, norb: ModeIndex },
+}
+
+impl fmt::Display for OneBodyMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotOneBody { term } => {
+                write!(f, "Term {term} is not of the one-body form a†_i a_j.")
+            }
+            Self::IndexOutOfRange { index, norb } => {
+                write!(f, "Index {index} is out of range for {norb} orbitals.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OneBodyMatrixError {}
+
+/// The error returned by [`FermionOperator::ground_energy`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GroundEnergyError {
+    /// The operator is not Hermitian.
+    NotHermitian,
+    /// The Lanczos iteration did not converge within the maximum number of iterations. The last
+    /// estimate of the lowest eigenvalue is provided.
+    NotConverged { estimate: f64 },
+}
+
+impl fmt::Display for GroundEnergyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotHermitian => write!(f, "The operator is not Hermitian."),
+            Self::NotConverged { estimate } => write!(
+                f,
+                "The Lanczos iteration did not converge, the last estimate is {estimate}."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GroundEnergyError {}
+
+/// The notation in which [`FermionOperator::to_str`] renders coefficients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoeffStyle {
+    /// Scientific notation, like `1.500000e0`. This is used by the [`fmt::Display`] implementation.
+    #[default]
+    Scientific,
+    /// Fixed-point notation, like `1.500000`.
+    Fixed,
+}
+
+/// Summary statistics of the coefficient magnitudes of a [`FermionOperator`], as returned by
+/// [`FermionOperator::coefficient_stats`].
+///
+/// The magnitudes only take finite coefficients into account and are `0.0` if there are none.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CoeffStats {
+    /// The smallest finite coefficient magnitude.
+    pub min_abs: f64,
+    /// The largest finite coefficient magnitude.
+    pub max_abs: f64,
+    /// The mean of all finite coefficient magnitudes.
+    pub mean_abs: f64,
+    /// Whether any coefficient has a NaN real or imaginary part.
+    pub has_nan: bool,
+    /// Whether any coefficient has an infinite real or imaginary part.
+    pub has_inf: bool,
+}
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct FermionOperatorTermView<'a> {
     pub coeff: Complex64,
     pub actions: &'a [bool],
-    pub indices: &'a [u32],
+    pub indices: &'a [ModeIndex],
 }
 
 impl FermionOperatorTermView<'_> {
@@ -39,22 +153,139 @@ impl FermionOperatorTermView<'_> {
         zip(self.actions, self.indices).collect()
     }
 
-    pub fn into_vec(&'_ self) -> Vec<(bool, u32)> {
+    pub fn into_vec(&'_ self) -> Vec<(bool, ModeIndex)> {
         zip(self.actions.to_vec(), self.indices.to_vec()).collect()
     }
+
+    /// Returns the sign picked up by reordering the actions of this term into normal order, i.e.
+    /// all creation actions before all annihilation actions and each group sorted by descending
+    /// index.
+    ///
+    /// This only accounts for the parity of the permutation and ignores the additional terms
+    /// arising from the anti-commutation relations, which [`FermionOperator::normal_ordered`]
+    /// would produce.
+    pub fn permutation_parity_to_normal(&self) -> i32 {
+        // the position of an action in normal order: creators first, then by descending index
+        let key = |i: usize| (!self.actions[i], Reverse(self.indices[i]));
+        let mut parity = 1;
+        for i in 0..self.actions.len() {
+            for j in i + 1..self.actions.len() {
+                if key(j) < key(i) {
+                    parity = -parity;
+                }
+            }
+        }
+        parity
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FermionOperator {
     pub coeffs: Vec<Complex64>,
     pub actions: Vec<bool>,
-    pub indices: Vec<u32>,
+    pub indices: Vec<ModeIndex>,
     pub boundaries: Vec<usize>,
 }
 
 crate::impl_operator_macro!(FermionOperator);
 
 impl FermionOperator {
+    /// Constructs a random operator with `num_terms` terms, each of which consists of up to
+    /// `max_term_length` actions on `num_modes` modes and a coefficient whose real and imaginary
+    /// parts are drawn uniformly from `[-1, 1)`.
+    pub fn random(
+        num_terms: usize,
+        num_modes: ModeIndex,
+        max_term_length: usize,
+        seed: Option<u64>,
+    ) -> Self {
+        let mut rng = match seed {
+            Some(seed) => Pcg64Mcg::seed_from_u64(seed),
+            None => Pcg64Mcg::from_os_rng(),
+        };
+        let mut out = Self::zero();
+        for _ in 0..num_terms {
+            out.coeffs.push(Complex64::new(
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0),
+            ));
+            let term_length = rng.random_range(0..=max_term_length);
+            for _ in 0..term_length {
+                out.actions.push(rng.random());
+                out.indices.push(rng.random_range(0..num_modes));
+            }
+            out.boundaries.push(out.indices.len());
+        }
+        out
+    }
+
+    /// Constructs the fermionic parity operator `prod_i (1 - 2 n_i)` acting on `num_modes` modes.
+    pub fn parity_operator(num_modes: ModeIndex) -> Self {
+        let mut out = Self::one();
+        for i in 0..num_modes {
+            out.__iand__(&Self {
+                coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-2.0, 0.0)],
+                actions: vec![true, false],
+                indices: vec![i, i],
+                boundaries: vec![0, 0, 2],
+            });
+        }
+        out
+    }
+
+    /// Constructs the normal-ordered projector `prod_{i in occ} a†_i a_i prod_{j not in occ} a_j a†_j`
+    /// onto the Slater determinant with the `occupied` modes out of `num_modes`.
+    pub fn determinant_projector(num_modes: ModeIndex, occupied: &[ModeIndex]) -> Self {
+        assert!(
+            occupied.iter().all(|&i| i < num_modes),
+            "occupied modes must be smaller than the number of modes"
+        );
+        let mut out = Self::one();
+        for i in 0..num_modes {
+            let is_occupied = occupied.contains(&i);
+            out.__iand__(&Self {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                actions: vec![is_occupied, !is_occupied],
+                indices: vec![i, i],
+                boundaries: vec![0, 2],
+            });
+        }
+        out.normal_ordered().simplify(0.0)
+    }
+
+    /// Constructs the lattice Hamiltonian `sum_(i,j,t) (t a†_i a_j + t* a†_j a_i) + sum_(i,U) U
+    /// a†_i a_i` from a list of hopping `edges` and `onsite` energies.
+    ///
+    /// Every edge contributes two terms and every on-site energy one term, in the order given.
+    /// Duplicate entries are not merged.
+    pub fn from_hopping_edges(
+        edges: &[(ModeIndex, ModeIndex, Complex64)],
+        onsite: &[(ModeIndex, Complex64)],
+    ) -> Self {
+        let num_terms = 2 * edges.len() + onsite.len();
+        let mut out = Self {
+            coeffs: Vec::with_capacity(num_terms),
+            actions: Vec::with_capacity(2 * num_terms),
+            indices: Vec::with_capacity(2 * num_terms),
+            boundaries: Vec::with_capacity(num_terms + 1),
+        };
+        out.boundaries.push(0);
+        let mut push = |coeff: Complex64, i: ModeIndex, j: ModeIndex| {
+            out.coeffs.push(coeff);
+            out.actions.extend_from_slice(&[true, false]);
+            out.indices.extend_from_slice(&[i, j]);
+            out.boundaries.push(out.indices.len());
+        };
+        for &(i, j, t) in edges {
+            push(t, i, j);
+            push(t.conj(), j, i);
+        }
+        for &(i, u) in onsite {
+            push(u, i, i);
+        }
+        out
+    }
+
     pub fn simplify(&self, atol: f64) -> Self {
         let mut terms = HashMap::new();
         for term in self.iter() {
@@ -63,19 +294,216 @@ impl FermionOperator {
                 .and_modify(|c| *c += term.coeff)
                 .or_insert(term.coeff);
         }
+        let mut terms: Vec<_> = terms
+            .into_iter()
+            .filter(|(_, coeff)| coeff.abs() > atol)
+            .collect();
+        // sort the terms to make the output independent of the hashing order
+        terms.sort_unstable_by(|((indices_a, actions_a), _), ((indices_b, actions_b), _)| {
+            zip(*actions_a, *indices_a).cmp(zip(*actions_b, *indices_b))
+        });
         let mut out = Self::zero();
-        terms
-            .iter()
-            .filter(|((_, _), coeff)| coeff.abs() > atol)
-            .for_each(|((indices, actions), coeff)| {
-                out.coeffs.push(*coeff);
-                out.actions.extend_from_slice(actions);
-                out.indices.extend_from_slice(indices);
+        terms.iter().for_each(|((indices, actions), coeff)| {
+            out.coeffs.push(*coeff);
+            out.actions.extend_from_slice(actions);
+            out.indices.extend_from_slice(indices);
+            out.boundaries.push(out.indices.len());
+        });
+        out
+    }
+
+    /// Simplifies this operator in place, like [`FermionOperator::simplify`], but without hashing.
+    ///
+    /// The terms are first reordered by [`FermionOperator::sort_terms`], after which equal terms
+    /// are adjacent and can be merged in a single linear pass. This is more cache-friendly for
+    /// large operators whose terms are already (nearly) grouped, like those constructed from
+    /// electronic integrals. The resulting terms are in the same order as those returned by
+    /// [`FermionOperator::simplify`].
+    pub fn simplify_sorted(&mut self, atol: f64) {
+        self.sort_terms();
+        let terms: Vec<FermionOperatorTermView> = self.iter().collect();
+        let mut out = Self::zero();
+        let mut start = 0;
+        while start < terms.len() {
+            let head = terms[start];
+            let mut end = start;
+            let mut coeff = Complex64::new(0.0, 0.0);
+            while end < terms.len()
+                && terms[end].actions == head.actions
+                && terms[end].indices == head.indices
+            {
+                coeff += terms[end].coeff;
+                end += 1;
+            }
+            if coeff.abs() > atol {
+                out.coeffs.push(coeff);
+                out.actions.extend_from_slice(head.actions);
+                out.indices.extend_from_slice(head.indices);
                 out.boundaries.push(out.indices.len());
-            });
+            }
+            start = end;
+        }
+        *self = out;
+    }
+
+    /// Simplifies this operator and then removes terms whose coefficient magnitude lies below
+    /// `rtol` times the largest one. An `rtol` of `0` keeps all terms.
+    pub fn chop_relative(&self, rtol: f64) -> Self {
+        let simplified = self.simplify(f64::NEG_INFINITY);
+        let atol = rtol
+            * simplified
+                .coeffs
+                .iter()
+                .map(|c| c.norm())
+                .fold(0.0, f64::max);
+        simplified.filter_terms(|term| term.coeff.norm() >= atol)
+    }
+
+    /// Returns the number of terms which survive `self.simplify(threshold)` for each of the given
+    /// `thresholds`. The operator is only simplified once, making this a cheap way of choosing a
+    /// truncation tolerance. The counts are returned in the order of `thresholds`.
+    pub fn term_count_vs_threshold(&self, thresholds: &[f64]) -> Vec<usize> {
+        let mut magnitudes: Vec<f64> = self
+            .simplify(f64::NEG_INFINITY)
+            .coeffs
+            .iter()
+            .map(|c| c.abs())
+            .collect();
+        magnitudes.sort_unstable_by(f64::total_cmp);
+        thresholds
+            .iter()
+            .map(|atol| magnitudes.len() - magnitudes.partition_point(|m| m <= atol))
+            .collect()
+    }
+
+    /// Appends many terms at once. The `i`-th new term has the coefficient `coeffs[i]` and
+    /// consists of the next `term_lengths[i]` entries of `actions` and `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coeffs` and `term_lengths` differ in length, or if `actions` and `indices` do
+    /// not both contain exactly `term_lengths.iter().sum()` entries.
+    pub fn extend_terms(
+        &mut self,
+        coeffs: &[Complex64],
+        term_lengths: &[usize],
+        actions: &[bool],
+        indices: &[ModeIndex],
+    ) {
+        assert_eq!(
+            coeffs.len(),
+            term_lengths.len(),
+            "the number of coefficients and term lengths must match"
+        );
+        let num_actions: usize = term_lengths.iter().sum();
+        assert_eq!(
+            actions.len(),
+            num_actions,
+            "the number of actions must equal the sum of the term lengths"
+        );
+        assert_eq!(
+            indices.len(),
+            num_actions,
+            "the number of indices must equal the sum of the term lengths"
+        );
+
+        self.coeffs.extend_from_slice(coeffs);
+        self.actions.extend_from_slice(actions);
+        self.indices.extend_from_slice(indices);
+        self.boundaries.reserve(term_lengths.len());
+        let mut end = *self.boundaries.last().unwrap();
+        for length in term_lengths {
+            end += length;
+            self.boundaries.push(end);
+        }
+    }
+
+    /// Partitions the terms into `num_chunks` contiguous sub-operators whose number of terms
+    /// differs by at most one. Summing all chunks yields the original operator.
+    pub fn chunks(&self, num_chunks: usize) -> Vec<Self> {
+        assert!(num_chunks > 0, "the number of chunks must be positive");
+        let num_terms = self.coeffs.len();
+        let (size, remainder) = (num_terms / num_chunks, num_terms % num_chunks);
+        let mut start = 0;
+        (0..num_chunks)
+            .map(|i| {
+                let end = start + size + usize::from(i < remainder);
+                let offset = self.boundaries[start];
+                let chunk = Self {
+                    coeffs: self.coeffs[start..end].to_vec(),
+                    actions: self.actions[offset..self.boundaries[end]].to_vec(),
+                    indices: self.indices[offset..self.boundaries[end]].to_vec(),
+                    boundaries: self.boundaries[start..=end]
+                        .iter()
+                        .map(|b| b - offset)
+                        .collect(),
+                };
+                start = end;
+                chunk
+            })
+            .collect()
+    }
+
+    /// Returns one single-term operator per stored term, in order. Summing them yields the
+    /// original operator.
+    pub fn term_operators(&self) -> Vec<Self> {
+        self.iter()
+            .map(|term| Self {
+                coeffs: vec![term.coeff],
+                actions: term.actions.to_vec(),
+                indices: term.indices.to_vec(),
+                boundaries: vec![0, term.indices.len()],
+            })
+            .collect()
+    }
+
+    /// Returns the sub-operator consisting of all terms for which `pred` returns `true`.
+    pub fn filter_terms(&self, mut pred: impl FnMut(FermionOperatorTermView) -> bool) -> Self {
+        let mut out = Self::zero();
+        self.iter().filter(|term| pred(*term)).for_each(|term| {
+            out.coeffs.push(term.coeff);
+            out.actions.extend_from_slice(term.actions);
+            out.indices.extend_from_slice(term.indices);
+            out.boundaries.push(out.indices.len());
+        });
         out
     }
 
+    /// Applies `f` to every coefficient of this operator in place. The terms themselves are left
+    /// untouched; in particular, no terms are removed when `f` maps their coefficients to zero.
+    pub fn map_coeffs(&mut self, mut f: impl FnMut(Complex64) -> Complex64) {
+        self.coeffs.iter_mut().for_each(|coeff| *coeff = f(*coeff));
+    }
+
+    /// Reorders the terms into a canonical order, sorting them by their actions (in the order of
+    /// [`FermionOperatorTermView::into_vec`]) and then by their coefficients. Duplicate terms are
+    /// **not** merged.
+    pub fn sort_terms(&mut self) {
+        let mut terms: Vec<FermionOperatorTermView> = self.iter().collect();
+        terms.sort_by(|a, b| {
+            a.iter()
+                .cmp(b.iter())
+                .then_with(|| a.coeff.re.total_cmp(&b.coeff.re))
+                .then_with(|| a.coeff.im.total_cmp(&b.coeff.im))
+        });
+        let mut sorted = Self::zero();
+        for term in terms {
+            sorted.coeffs.push(term.coeff);
+            sorted.actions.extend_from_slice(term.actions);
+            sorted.indices.extend_from_slice(term.indices);
+            sorted.boundaries.push(sorted.indices.len());
+        }
+        *self = sorted;
+    }
+
+    /// Iterates over the terms of this operator.
+    ///
+    /// # Panics
+    ///
+    /// This assumes the operator data to be consistent and panics if `boundaries` does not
+    /// provide a valid range of `actions` and `indices` for every coefficient. Use
+    /// [`FermionOperator::checked_iter`] for operators whose fields may have been constructed
+    /// inconsistently.
     pub fn iter(&'_ self) -> impl ExactSizeIterator<Item = FermionOperatorTermView<'_>> + '_ {
         self.coeffs.iter().enumerate().map(|(i, coeff)| {
             let start = self.boundaries[i];
@@ -88,31 +516,257 @@ impl FermionOperator {
         })
     }
 
+    /// Iterates over the terms of this operator whose coefficients exceed `atol` in magnitude.
+    ///
+    /// This skips numerically negligible terms like [`OperatorTrait::ichop`] would, but without
+    /// allocating a chopped copy of the operator.
+    pub fn iter_nonzero(
+        &'_ self,
+        atol: f64,
+    ) -> impl Iterator<Item = FermionOperatorTermView<'_>> + '_ {
+        self.iter().filter(move |term| term.coeff.abs() > atol)
+    }
+
+    /// Iterates over the terms of this operator without assuming its data to be consistent.
+    ///
+    /// Unlike [`FermionOperator::iter`], this yields an error message for every term whose
+    /// `boundaries` do not describe a valid range of `actions` and `indices`, rather than
+    /// panicking.
+    pub fn checked_iter(
+        &'_ self,
+    ) -> impl ExactSizeIterator<Item = Result<FermionOperatorTermView<'_>, String>> + '_ {
+        self.coeffs.iter().enumerate().map(|(i, coeff)| {
+            let (Some(&start), Some(&end)) = (self.boundaries.get(i), self.boundaries.get(i + 1))
+            else {
+                return Err(format!(
+                    "Term {i} has no boundaries: expected {} boundaries but found {}.",
+                    self.coeffs.len() + 1,
+                    self.boundaries.len()
+                ));
+            };
+            match (self.actions.get(start..end), self.indices.get(start..end)) {
+                (Some(actions), Some(indices)) => Ok(FermionOperatorTermView {
+                    coeff: *coeff,
+                    actions,
+                    indices,
+                }),
+                _ => Err(format!(
+                    "Term {i} spans {start}..{end} which is invalid for {} actions and {} indices.",
+                    self.actions.len(),
+                    self.indices.len()
+                )),
+            }
+        })
+    }
+
+    /// Projects this operator onto the modes with indices below `max_index_exclusive`, by keeping
+    /// only the terms which act exclusively on these modes.
+    ///
+    /// This is **not** a partial trace: terms touching any other mode are discarded rather than
+    /// evaluated on some state of the remaining modes.
+    pub fn restrict_to_modes(&self, max_index_exclusive: ModeIndex) -> Self {
+        self.filter_terms(|term| term.indices.iter().all(|i| *i < max_index_exclusive))
+    }
+
+    /// Returns this operator without the terms whose many-body order (i.e. their number of
+    /// actions, see [`FermionOperator::many_body_order`]) exceeds `max_order`.
+    pub fn truncate_order(&self, max_order: u32) -> Self {
+        self.filter_terms(|term| term.actions.len() <= max_order as usize)
+    }
+
+    /// Returns this operator without the terms which vanish identically by the Pauli exclusion
+    /// principle, i.e. those acting twice with the same action on a mode, without the opposite
+    /// action on that mode in between (like `a†_0 a†_0 a_1 a_2`).
+    pub fn drop_vanishing_terms(&self) -> Self {
+        self.filter_terms(|term| !_is_trivially_zero(&term.to_vec()))
+    }
+
     pub fn normal_ordered(&self) -> Self {
         let mut result = Self::zero();
         self.iter()
-            .for_each(|term| result.__iadd__(&_normal_ordered_term(term)));
+            .for_each(|term| result.__iadd__(&_normal_ordered_term(term, usize::MAX).unwrap()));
         result
     }
 
+    /// Like [`FermionOperator::normal_ordered`], but additionally returns, for each term of the
+    /// result, the index of the term of `self` from which it originated.
+    pub fn normal_ordered_with_provenance(&self) -> (Self, Vec<usize>) {
+        let mut result = Self::zero();
+        let mut provenance = vec![];
+        for (i, term) in self.iter().enumerate() {
+            let ordered = _normal_ordered_term(term, usize::MAX).unwrap();
+            provenance.resize(provenance.len() + ordered.coeffs.len(), i);
+            result.__iadd__(&ordered);
+        }
+        (result, provenance)
+    }
+
+    /// Like [`FermionOperator::normal_ordered`], but aborts once the number of terms, including
+    /// those still pending during the reordering, exceeds `max_terms`.
+    ///
+    /// Normal ordering a term with many interleaved creation and annihilation operators can produce
+    /// exponentially many terms. This method guards against that before memory runs out.
+    pub fn normal_ordered_bounded(&self, max_terms: usize) -> Result<Self, NormalOrderError> {
+        let mut result = Self::zero();
+        for term in self.iter() {
+            let remaining = max_terms.saturating_sub(result.coeffs.len());
+            let ordered = _normal_ordered_term(term, remaining)
+                .map_err(|_| NormalOrderError { max_terms })?;
+            result.__iadd__(&ordered);
+            if result.coeffs.len() > max_terms {
+                return Err(NormalOrderError { max_terms });
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the global phase `p` (with `|p| = 1`) for which `p * self` is equivalent to
+    /// `other` (see [`OperatorTrait::equiv`]), or `None` if no such phase exists.
+    ///
+    /// The phase is determined from the largest coefficient of the simplified `self`.
+    pub fn global_phase(&self, other: &Self, atol: f64) -> Option<Complex64> {
+        let simplified = self.simplify(atol);
+        let Some(largest) = simplified
+            .iter()
+            .max_by(|a, b| a.coeff.norm().total_cmp(&b.coeff.norm()))
+        else {
+            return other
+                .equiv(&Self::zero(), atol)
+                .then_some(Complex64::new(1.0, 0.0));
+        };
+        let coeff = other
+            .simplify(atol)
+            .iter()
+            .find(|term| term.actions == largest.actions && term.indices == largest.indices)?
+            .coeff;
+        let ratio = coeff / largest.coeff;
+        let phase = ratio / ratio.norm();
+        simplified
+            .__mul__(phase)
+            .equiv(other, atol)
+            .then_some(phase)
+    }
+
+    /// Returns whether `self` and `other` are equivalent up to a global phase (see
+    /// [`FermionOperator::global_phase`]).
+    pub fn equiv_up_to_phase(&self, other: &Self, atol: f64) -> bool {
+        self.global_phase(other, atol).is_some()
+    }
+
     pub fn is_hermitian(&self, atol: f64) -> bool {
         let mut diff = (self.__sub__(&self.adjoint())).normal_ordered();
         diff.ichop(atol);
         diff.equiv(&Self::zero(), atol)
     }
 
+    /// Returns `scale * self + shift * 1`, constructed in a single allocation. The identity term is
+    /// appended at the end and not merged with any existing identity terms.
+    pub fn scale_and_shift(&self, scale: Complex64, shift: Complex64) -> Self {
+        let mut coeffs = Vec::with_capacity(self.coeffs.len() + 1);
+        coeffs.extend(self.coeffs.iter().map(|c| c * scale));
+        coeffs.push(shift);
+        let mut boundaries = Vec::with_capacity(self.boundaries.len() + 1);
+        boundaries.extend_from_slice(&self.boundaries);
+        boundaries.push(self.indices.len());
+        Self {
+            coeffs,
+            actions: self.actions.clone(),
+            indices: self.indices.clone(),
+            boundaries,
+        }
+    }
+
+    /// Returns this operator with complex conjugated coefficients. Unlike
+    /// [`OperatorTrait::adjoint`], this leaves the terms themselves unchanged.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            coeffs: self.coeffs.iter().map(|c| c.conj()).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the conjugation `R† self R` of this operator by `rotation` (`R`), as it appears in
+    /// similarity transformations.
+    ///
+    /// Since `a & b` composes to the operator product `b a`, this equals
+    /// `rotation.__and__(self).__and__(&rotation.adjoint())` (including the order of the terms), but
+    /// it builds the result in a single pass without materializing the adjoint or the intermediate
+    /// composition.
+    pub fn conjugate_by(&self, rotation: &Self) -> Self {
+        let num_terms = rotation.coeffs.len() * self.coeffs.len() * rotation.coeffs.len();
+        let mut coeffs = Vec::with_capacity(num_terms);
+        let mut actions = vec![];
+        let mut indices = vec![];
+        let mut boundaries = Vec::with_capacity(num_terms + 1);
+        boundaries.push(0);
+
+        for right in rotation.iter() {
+            for middle in self.iter() {
+                for left in rotation.iter() {
+                    coeffs.push(right.coeff * middle.coeff * left.coeff.conj());
+                    actions.extend(left.actions.iter().rev().map(|a| !a));
+                    actions.extend_from_slice(middle.actions);
+                    actions.extend_from_slice(right.actions);
+                    indices.extend(left.indices.iter().rev());
+                    indices.extend_from_slice(middle.indices);
+                    indices.extend_from_slice(right.indices);
+                    boundaries.push(indices.len());
+                }
+            }
+        }
+
+        Self {
+            coeffs,
+            actions,
+            indices,
+            boundaries,
+        }
+    }
+
+    /// Returns whether the imaginary parts of all coefficients are at most `atol` in magnitude.
+    pub fn is_real(&self, atol: f64) -> bool {
+        self.coeffs.iter().all(|c| c.im.abs() <= atol)
+    }
+
+    /// Returns the number of bytes occupied by the operator's data buffers.
+    pub fn nbytes(&self) -> usize {
+        size_of_val(self.coeffs.as_slice())
+            + size_of_val(self.actions.as_slice())
+            + size_of_val(self.indices.as_slice())
+            + size_of_val(self.boundaries.as_slice())
+    }
+
+    /// Returns the total number of actions stored in this operator, i.e. the sum of all term
+    /// lengths.
+    pub fn num_actions(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Returns the average number of actions per term, or `0.0` if there are no terms.
+    pub fn average_term_length(&self) -> f64 {
+        if self.coeffs.is_empty() {
+            0.0
+        } else {
+            self.num_actions() as f64 / self.coeffs.len() as f64
+        }
+    }
+
+    /// Returns the number of actions in the longest term.
+    pub fn max_term_length(&self) -> usize {
+        self.boundaries
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the many-body order, i.e. the number of actions in the longest term.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the longest term has more than `u32::MAX` actions.
     pub fn many_body_order(&self) -> u32 {
-        let mut max = 0;
-        let mut prev_b = 0;
-        // TODO: refactor this
-        self.boundaries[1..].iter().for_each(|b| {
-            let d = b - prev_b;
-            if d > max {
-                max = d;
-            }
-            prev_b = *b;
-        });
-        max as u32
+        u32::try_from(self.max_term_length()).expect("many-body order exceeds u32::MAX")
     }
 
     pub fn conserves_particle_number(&self) -> bool {
@@ -132,94 +786,865 @@ impl FermionOperator {
         }
         true
     }
-}
-
-fn _normal_ordered_term(term_view: FermionOperatorTermView) -> FermionOperator {
-    let mut coeffs = vec![];
-    let mut actions = vec![];
-    let mut indices = vec![];
-    let mut boundaries = vec![0];
 
-    let mut stack = vec![(term_view.to_vec(), term_view.coeff)];
-    while let Some((mut term, coeff)) = stack.pop() {
-        let mut parity = false;
-        let mut zero = false;
-        for i in 1..term.len() {
-            // shift the operator at index i to the left until it's in the correct location
-            for j in (1..=i).rev() {
-                let (action_right, index_right) = term[j];
-                let (action_left, index_left) = term[j - 1];
-                if *action_right == *action_left {
-                    // both create or both destroy
-                    match (index_right).cmp(index_left) {
-                        Ordering::Equal => {
-                            // operators are the same, so product is zero
-                            zero = true;
-                            break;
-                        }
-                        Ordering::Greater => {
-                            // swap operators and update sign
-                            term.swap(j - 1, j);
-                            parity = !parity;
-                        }
-                        Ordering::Less => {}
-                    }
-                } else if *action_right && !*action_left {
-                    // create on right and destroy on left
-                    if index_right == index_left {
-                        // add new term
-                        let mut new_term: Vec<FermionAction> = Vec::new();
-                        new_term.extend(&term[..j - 1]);
-                        new_term.extend(&term[j + 1..]);
-                        let signed_coeff = if parity { -coeff } else { coeff };
-                        stack.push((new_term, signed_coeff))
+    /// Returns whether every term separately conserves the number of alpha- and beta-spin
+    /// particles. This assumes the spin-orbitals to be blocked by spin, i.e. the modes `0..norb`
+    /// are alpha-spin and the modes `norb..2 * norb` are beta-spin orbitals.
+    pub fn conserves_spin_numbers(&self, norb: ModeIndex) -> bool {
+        self.iter().all(|term| {
+            let (alpha, beta) = term
+                .iter()
+                .fold((0i64, 0i64), |(alpha, beta), (action, index)| {
+                    let change = if *action { 1 } else { -1 };
+                    if *index < norb {
+                        (alpha + change, beta)
+                    } else {
+                        (alpha, beta + change)
                     }
-                    // swap operators and update sign
-                    term.swap(j - 1, j);
-                    parity = !parity;
-                }
-            }
-        }
-        if zero {
-            continue;
-        }
-        let signed_coeff = if parity { -coeff } else { coeff };
-        coeffs.push(signed_coeff);
-        term.iter().for_each(|&(&a, &i)| {
-            actions.push(a);
-            indices.push(i);
-        });
-        boundaries.push(indices.len())
+                });
+            alpha == 0 && beta == 0
+        })
     }
-    FermionOperator {
-        coeffs,
-        actions,
-        indices,
-        boundaries,
+
+    /// Returns whether this operator is quadratic, i.e. whether each of its normal-ordered terms
+    /// is either a constant or consists of exactly one creation and one annihilation operator.
+    pub fn is_quadratic(&self) -> bool {
+        self.normal_ordered()
+            .simplify(0.0)
+            .iter()
+            .all(|term| match term.actions {
+                [] => true,
+                [first, second] => first != second,
+                _ => false,
+            })
     }
-}
 
-impl OperatorTrait for FermionOperator {
-    fn zero() -> Self {
-        Self {
-            coeffs: vec![],
-            actions: vec![],
-            indices: vec![],
-            boundaries: vec![0],
-        }
+    /// Returns whether this operator is a scalar multiple of the identity, i.e. whether all of its
+    /// normal-ordered terms with coefficient magnitudes above `atol` are identity terms.
+    pub fn is_constant(&self, atol: f64) -> bool {
+        self.normal_ordered()
+            .simplify(atol)
+            .iter()
+            .all(|term| term.actions.is_empty())
     }
 
-    fn one() -> Self {
-        Self {
-            coeffs: vec![Complex64::new(1.0, 0.0)],
-            actions: vec![],
-            indices: vec![],
-            boundaries: vec![0, 0],
+    /// Returns whether this operator equals the identity up to the absolute tolerance `atol`,
+    /// after normal ordering.
+    pub fn is_identity(&self, atol: f64) -> bool {
+        self.normal_ordered().equiv(&Self::one(), atol)
+    }
+
+    /// Splits this operator into its components keyed by the number of actions per term. The sum
+    /// over all components equals the original operator.
+    pub fn group_by_order(&self) -> BTreeMap<u32, Self> {
+        let mut groups: BTreeMap<u32, Self> = BTreeMap::new();
+        for term in self.iter() {
+            let group = groups
+                .entry(term.actions.len() as u32)
+                .or_insert_with(Self::zero);
+            group.coeffs.push(term.coeff);
+            group.actions.extend_from_slice(term.actions);
+            group.indices.extend_from_slice(term.indices);
+            group.boundaries.push(group.indices.len());
         }
+        groups
     }
 
-    fn equiv(&self, other: &Self, atol: f64) -> bool {
-        let mut diff = self.__sub__(other);
+    /// Decomposes this operator into generators `h_j`, such that `sum_j (h_j + h_j†)` reconstructs
+    /// it, provided that it is Hermitian.
+    ///
+    /// The operator is normal-ordered and simplified first. Each term and its Hermitian conjugate
+    /// are then represented by the lexicographically smaller of the two (w.r.t.
+    /// [`FermionOperatorTermView::into_vec`]). Terms which are their own Hermitian conjugate (like
+    /// number operators) are represented by half of themselves. Every generator is a single term.
+    pub fn hermitian_generators(&self, atol: f64) -> Vec<Self> {
+        let terms: BTreeMap<Vec<(bool, ModeIndex)>, Complex64> = self
+            .normal_ordered()
+            .simplify(atol)
+            .iter()
+            .map(|term| (term.into_vec(), term.coeff))
+            .collect();
+        let mut seen = HashSet::new();
+        let mut generators = vec![];
+        for (term, coeff) in &terms {
+            if seen.contains(term) {
+                continue;
+            }
+            // the adjoint of a normal-ordered term, brought back into normal order
+            let mut adjoint: Vec<(bool, ModeIndex)> = term
+                .iter()
+                .rev()
+                .map(|(action, index)| (!action, *index))
+                .collect();
+            adjoint.sort_by_key(|(action, index)| (!action, Reverse(*index)));
+            let coeff = if adjoint == *term {
+                coeff / 2.0
+            } else {
+                *coeff
+            };
+            seen.insert(adjoint);
+            generators.push(Self {
+                coeffs: vec![coeff],
+                actions: term.iter().map(|(action, _)| *action).collect(),
+                indices: term.iter().map(|(_, index)| *index).collect(),
+                boundaries: vec![0, term.len()],
+            });
+        }
+        generators
+    }
+
+    /// Counts the terms of this operator by the number of distinct modes they act upon. Terms are
+    /// not simplified beforehand.
+    pub fn support_histogram(&self) -> BTreeMap<u32, usize> {
+        let mut histogram = BTreeMap::new();
+        for term in self.iter() {
+            let mut support = term.indices.to_vec();
+            support.sort_unstable();
+            support.dedup();
+            *histogram.entry(support.len() as u32).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns the product `self * other` in the usual mathematical (left-to-right) sense.
+    ///
+    /// This is the opposite of the composition convention of `&`, i.e.
+    /// `a.matmul(&b) == &b & &a`.
+    pub fn matmul(&self, other: &Self) -> Self {
+        other.__and__(self)
+    }
+
+    /// Returns the tensor product of `self`, acting on the first `num_modes_self` modes, and
+    /// `other`, whose indices get shifted to act on the subsequent modes.
+    ///
+    /// The result is the product `self * other` (see [`FermionOperator::matmul`]) of the two
+    /// operators with disjoint supports.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` acts on a mode outside of `0..num_modes_self`.
+    pub fn tensor(&self, other: &Self, num_modes_self: ModeIndex) -> Self {
+        assert!(
+            self.indices.iter().all(|&i| i < num_modes_self),
+            "the operator acts on a mode outside of its {num_modes_self} modes"
+        );
+        let shifted = Self {
+            coeffs: other.coeffs.clone(),
+            actions: other.actions.clone(),
+            indices: other.indices.iter().map(|i| i + num_modes_self).collect(),
+            boundaries: other.boundaries.clone(),
+        };
+        self.matmul(&shifted)
+    }
+
+    /// Returns the sum of all coefficients.
+    pub fn coefficient_sum(&self) -> Complex64 {
+        self.coeffs.iter().sum()
+    }
+
+    /// Returns the minimum, maximum and mean coefficient magnitude and whether any coefficient is
+    /// NaN or infinite. See [`CoeffStats`] for details.
+    pub fn coefficient_stats(&self) -> CoeffStats {
+        let mut stats = CoeffStats {
+            min_abs: f64::INFINITY,
+            ..CoeffStats::default()
+        };
+        let mut num_finite = 0;
+        let mut sum_abs = 0.0;
+        for coeff in &self.coeffs {
+            if coeff.is_nan() {
+                stats.has_nan = true;
+            } else if coeff.is_infinite() {
+                stats.has_inf = true;
+            } else {
+                let abs = coeff.norm();
+                stats.min_abs = stats.min_abs.min(abs);
+                stats.max_abs = stats.max_abs.max(abs);
+                sum_abs += abs;
+                num_finite += 1;
+            }
+        }
+        if num_finite == 0 {
+            stats.min_abs = 0.0;
+        } else {
+            stats.mean_abs = sum_abs / num_finite as f64;
+        }
+        stats
+    }
+
+    /// Returns whether any coefficient is NaN or infinite.
+    ///
+    /// Such coefficients are easily missed, because [`FermionOperator::simplify`] and
+    /// [`OperatorTrait::ichop`] silently drop NaN coefficients (`NaN > atol` is `false`).
+    pub fn has_invalid_coeffs(&self) -> bool {
+        self.coeffs.iter().any(|coeff| !coeff.is_finite())
+    }
+
+    /// Returns an error for the first term whose coefficient is NaN or infinite.
+    pub fn check_coeffs(&self) -> Result<(), InvalidCoeffError> {
+        match self.coeffs.iter().position(|coeff| !coeff.is_finite()) {
+            Some(term) => Err(InvalidCoeffError { term }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns this operator without the terms whose coefficients are NaN or infinite.
+    pub fn purge_nan_inf(&self) -> Self {
+        self.filter_terms(|term| term.coeff.is_finite())
+    }
+
+    /// Returns this operator with the real and imaginary parts of all coefficients rounded to
+    /// `decimals` decimal places. Terms whose coefficients round to zero are kept; use
+    /// [`FermionOperator::simplify`] to remove them.
+    pub fn round_coeffs(&self, decimals: i32) -> Self {
+        let mut out = self.clone();
+        out.round_coeffs_mut(decimals);
+        out
+    }
+
+    /// In-place variant of [`FermionOperator::round_coeffs`].
+    pub fn round_coeffs_mut(&mut self, decimals: i32) {
+        self.map_coeffs(|coeff| round_coeff(coeff, decimals));
+    }
+
+    /// Returns the sum of the coefficients of all identity terms.
+    pub fn constant(&self) -> Complex64 {
+        self.iter()
+            .filter(|term| term.actions.is_empty())
+            .map(|term| term.coeff)
+            .sum()
+    }
+
+    /// Returns this operator with an identity term of coefficient `c` appended. Existing identity
+    /// terms are not merged with the new one.
+    pub fn add_constant(&self, c: Complex64) -> Self {
+        let mut out = self.clone();
+        out.add_constant_mut(c);
+        out
+    }
+
+    /// In-place variant of [`FermionOperator::add_constant`].
+    pub fn add_constant_mut(&mut self, c: Complex64) {
+        self.coeffs.push(c);
+        self.boundaries.push(self.indices.len());
+    }
+
+    /// Returns this operator with all identity terms removed, together with the removed
+    /// constant (see [`FermionOperator::constant`]).
+    pub fn remove_constant(&self) -> (Self, Complex64) {
+        let mut out = Self::zero();
+        let mut constant = Complex64::new(0.0, 0.0);
+        for term in self.iter() {
+            if term.actions.is_empty() {
+                constant += term.coeff;
+            } else {
+                out.coeffs.push(term.coeff);
+                out.actions.extend_from_slice(term.actions);
+                out.indices.extend_from_slice(term.indices);
+                out.boundaries.push(out.indices.len());
+            }
+        }
+        (out, constant)
+    }
+
+    /// Returns the smallest and largest index acted upon, or `None` if there are no actions.
+    pub fn index_range(&self) -> Option<(ModeIndex, ModeIndex)> {
+        let first = *self.indices.first()?;
+        Some(
+            self.indices[1..]
+                .iter()
+                .fold((first, first), |(min, max), &i| (min.min(i), max.max(i))),
+        )
+    }
+
+    /// Remaps the indices acted upon onto the contiguous range `0..k`, preserving their order.
+    /// Returns the remapped operator together with the original index of each new index.
+    pub fn compactify_indices(&self) -> (Self, Vec<ModeIndex>) {
+        let mut mapping = self.indices.clone();
+        mapping.sort_unstable();
+        mapping.dedup();
+        let out = Self {
+            coeffs: self.coeffs.clone(),
+            actions: self.actions.clone(),
+            indices: self
+                .indices
+                .iter()
+                .map(|i| mapping.binary_search(i).unwrap() as ModeIndex)
+                .collect(),
+            boundaries: self.boundaries.clone(),
+        };
+        (out, mapping)
+    }
+
+    /// Applies the single-particle basis rotation `unitary` to this operator.
+    ///
+    /// Every creation action `a†_p` gets substituted by `sum_k U_{pk} a†_k` and every annihilation
+    /// action `a_p` by `sum_k conj(U_{pk}) a_k`, before expanding the products. Since a term with
+    /// `m` actions expands into up to `n^m` terms for an `n x n` unitary, this is only supported for
+    /// terms of up to 4 actions (i.e. up to two-body operators). Zero entries of `unitary` are
+    /// skipped and the result is not simplified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unitary` is not square, if an index acted upon is out of its bounds, or if a term
+    /// has more than 4 actions.
+    pub fn rotate_orbitals(&self, unitary: ArrayView2<Complex64>) -> Self {
+        assert_eq!(
+            unitary.nrows(),
+            unitary.ncols(),
+            "the unitary must be square"
+        );
+        let num_modes = unitary.nrows();
+        let mut out = Self::zero();
+        for term in self.iter() {
+            assert!(
+                term.actions.len() <= 4,
+                "rotating terms of more than 4 actions is not supported"
+            );
+            let mut expanded: Vec<(Complex64, Vec<(bool, ModeIndex)>)> = vec![(term.coeff, vec![])];
+            for (&action, &index) in zip(term.actions, term.indices) {
+                assert!(
+                    (index as usize) < num_modes,
+                    "index {index} is out of bounds for a unitary of {num_modes} modes"
+                );
+                let row = unitary.row(index as usize);
+                let mut next = Vec::with_capacity(expanded.len() * num_modes);
+                for (coeff, actions) in &expanded {
+                    for (k, &u) in row.iter().enumerate() {
+                        if u == Complex64::new(0.0, 0.0) {
+                            continue;
+                        }
+                        let u = if action { u } else { u.conj() };
+                        let mut actions = actions.clone();
+                        actions.push((action, k as ModeIndex));
+                        next.push((coeff * u, actions));
+                    }
+                }
+                expanded = next;
+            }
+            for (coeff, actions) in expanded {
+                out.coeffs.push(coeff);
+                for (action, index) in actions {
+                    out.actions.push(action);
+                    out.indices.push(index);
+                }
+                out.boundaries.push(out.indices.len());
+            }
+        }
+        out
+    }
+
+    /// Computes the partial trace over `modes`, assuming these to be in the vacuum state.
+    pub fn trace_out_vacuum(&self, modes: &[ModeIndex]) -> Self {
+        let mut out = Self::zero();
+        for term in self.iter() {
+            let mut traced = vec![];
+            let mut parity = false;
+            let mut num_kept_right = 0;
+            for (action, index) in zip(term.actions, term.indices).rev() {
+                if modes.contains(index) {
+                    traced.push((*action, *index));
+                    if num_kept_right % 2 == 1 {
+                        parity = !parity;
+                    }
+                } else {
+                    num_kept_right += 1;
+                }
+            }
+            // NOTE: the traced actions were collected in reverse, i.e. in the order in which they
+            // act on the vacuum state
+            let Some(value) = _vacuum_expectation(&traced) else {
+                continue;
+            };
+            let coeff = if parity { -term.coeff } else { term.coeff };
+            out.coeffs.push(coeff * value);
+            term.iter()
+                .filter(|(_, index)| !modes.contains(index))
+                .for_each(|(action, index)| {
+                    out.actions.push(*action);
+                    out.indices.push(*index);
+                });
+            out.boundaries.push(out.indices.len());
+        }
+        out
+    }
+
+    /// Applies this operator to the Fock state with the provided `occupation` numbers.
+    ///
+    /// The sign of each action is determined according to the Jordan-Wigner convention. Returns the
+    /// (unmerged) non-vanishing results of all terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any action acts on a mode outside of `occupation`.
+    pub fn apply_to_state(&self, occupation: &[bool]) -> Vec<(Complex64, Vec<bool>)> {
+        let mut out = vec![];
+        'terms: for term in self.iter() {
+            let mut state = occupation.to_vec();
+            let mut coeff = term.coeff;
+            for (action, index) in zip(term.actions, term.indices).rev() {
+                let index = *index as usize;
+                if state[index] == *action {
+                    continue 'terms;
+                }
+                if state[..index].iter().filter(|occ| **occ).count() % 2 == 1 {
+                    coeff = -coeff;
+                }
+                state[index] = *action;
+            }
+            out.push((coeff, state));
+        }
+        out
+    }
+
+    /// Returns whether every term acts diagonally on Fock states, i.e. whether each term contains
+    /// as many creation as annihilation actions on every mode.
+    pub fn is_diagonal(&self) -> bool {
+        self.iter().all(|term| {
+            let mut balance: HashMap<ModeIndex, i64> = HashMap::new();
+            for (action, index) in term.iter() {
+                *balance.entry(*index).or_default() += if *action { 1 } else { -1 };
+            }
+            balance.values().all(|b| *b == 0)
+        })
+    }
+
+    /// Evaluates the diagonal of this operator on all `2^num_modes` Fock states, without
+    /// constructing the dense matrix. The `k`-th entry corresponds to the state whose `i`-th mode
+    /// is occupied if the `i`-th bit of `k` is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operator is not diagonal (see [`FermionOperator::is_diagonal`]), acts on a
+    /// mode outside of `num_modes` or if `num_modes` exceeds
+    /// [`MAX_FOCK_MODES`](crate::operators::MAX_FOCK_MODES).
+    pub fn fock_diagonal(&self, num_modes: ModeIndex) -> Array1<Complex64> {
+        assert!(self.is_diagonal(), "the operator must be diagonal");
+        (0..fock_dim(num_modes))
+            .map(|state| {
+                let occupation: Vec<bool> = (0..num_modes).map(|i| state >> i & 1 == 1).collect();
+                self.apply_to_state(&occupation)
+                    .into_iter()
+                    .map(|(coeff, _)| coeff)
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Computes the expectation value of this operator with respect to the Slater determinant in
+    /// which the `occupied` modes out of `num_modes` are filled.
+    ///
+    /// Each term is applied to the determinant (see [`FermionOperator::apply_to_state`]) and only
+    /// those contributions which map it back onto itself are summed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any occupied mode or any action lies outside of `num_modes`.
+    pub fn expectation_determinant(
+        &self,
+        occupied: &[ModeIndex],
+        num_modes: ModeIndex,
+    ) -> Complex64 {
+        assert!(
+            occupied.iter().all(|&i| i < num_modes),
+            "occupied modes must be smaller than the number of modes"
+        );
+        let mut occupation = vec![false; num_modes as usize];
+        for &i in occupied {
+            occupation[i as usize] = true;
+        }
+        self.apply_to_state(&occupation)
+            .into_iter()
+            .filter(|(_, state)| *state == occupation)
+            .map(|(coeff, _)| coeff)
+            .sum()
+    }
+
+    /// Computes the matrix element `<bra|self|ket>` between two Fock states given by their
+    /// occupation numbers.
+    ///
+    /// The operator is applied to `ket` (see [`FermionOperator::apply_to_state`]) and the result is
+    /// projected onto `bra`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bra` and `ket` differ in length or if any action acts on a mode outside of them.
+    pub fn matrix_element(&self, bra: &[bool], ket: &[bool]) -> Complex64 {
+        assert_eq!(
+            bra.len(),
+            ket.len(),
+            "bra and ket must have the same number of modes"
+        );
+        self.apply_to_state(ket)
+            .into_iter()
+            .filter(|(_, state)| state == bra)
+            .map(|(coeff, _)| coeff)
+            .sum()
+    }
+
+    /// Extracts the one-body matrix `h` of this operator, such that it equals
+    /// `sum_ij h[i, j] a†_i a_j`. This inverts the one-body electronic integral constructors.
+    /// Coefficients of duplicate terms are summed.
+    ///
+    /// Every term must consist of exactly one creation followed by one annihilation action
+    /// (normal order the operator first, if necessary), and act on modes below `norb`. Otherwise,
+    /// an error is returned.
+    pub fn to_1body_matrix(
+        &self,
+        norb: ModeIndex,
+    ) -> Result<Array2<Complex64>, OneBodyMatrixError> {
+        let mut matrix = Array2::zeros((norb as usize, norb as usize));
+        for (n, term) in self.iter().enumerate() {
+            let (&[true, false], &[i, j]) = (term.actions, term.indices) else {
+                return Err(OneBodyMatrixError::NotOneBody { term: n });
+            };
+            if let Some(index) = [i, j].into_iter().find(|&index| index >= norb) {
+                return Err(OneBodyMatrixError::IndexOutOfRange { index, norb });
+            }
+            matrix[[i as usize, j as usize]] += term.coeff;
+        }
+        Ok(matrix)
+    }
+
+    /// Computes the single-particle energies of this quadratic, particle-number-conserving
+    /// operator acting on `norb` modes.
+    ///
+    /// These are the eigenvalues of the one-body matrix (see [`FermionOperator::to_1body_matrix`],
+    /// which also determines the errors returned by this method), in ascending order. Only the
+    /// Hermitian part of the matrix is taken into account.
+    pub fn quadratic_spectrum(&self, norb: ModeIndex) -> Result<Array1<f64>, OneBodyMatrixError> {
+        let matrix = self.to_1body_matrix(norb)?;
+        let hermitian = (&matrix + &matrix.t().mapv(|x| x.conj())) / 2.0;
+        // The Hermitian matrix A + iB has the same eigenvalues as the real symmetric matrix
+        // [[A, -B], [B, A]], where each of them appears twice.
+        let n = norb as usize;
+        let mut embedded = Array2::zeros((2 * n, 2 * n));
+        for ((i, j), value) in hermitian.indexed_iter() {
+            embedded[[i, j]] = value.re;
+            embedded[[i + n, j + n]] = value.re;
+            embedded[[i, j + n]] = -value.im;
+            embedded[[i + n, j]] = value.im;
+        }
+        Ok(_symmetric_eigenvalues(embedded)
+            .into_iter()
+            .step_by(2)
+            .collect())
+    }
+
+    /// Approximates the lowest eigenvalue of this Hermitian operator on the Fock space of
+    /// `num_modes` modes, using the Lanczos iteration on its sparse matrix (see
+    /// [`FermionOperator::to_sparse_matrix`]).
+    ///
+    /// The iteration starts from a seeded random vector and fully reorthogonalizes the Krylov
+    /// basis. It stops once the residual norm of the lowest Ritz pair, `β_k |y_k|` with `y_k` being
+    /// the last component of the Ritz vector in the Krylov basis, is at most `tol`, or once the
+    /// Krylov space becomes invariant. Since no particle-number sector is selected, this is the
+    /// ground energy across all sectors.
+    ///
+    /// Note that `tol` also serves as the tolerance of the Hermiticity check (see
+    /// [`FermionOperator::is_hermitian`]). An error is returned if the operator is not Hermitian up
+    /// to `tol`, or if it does not converge within `max_iter` iterations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any action acts on a mode outside of `num_modes` or if `num_modes` exceeds
+    /// [`MAX_FOCK_MODES`](crate::operators::MAX_FOCK_MODES).
+    pub fn ground_energy(
+        &self,
+        num_modes: ModeIndex,
+        max_iter: usize,
+        tol: f64,
+    ) -> Result<f64, GroundEnergyError> {
+        if !self.is_hermitian(tol) {
+            return Err(GroundEnergyError::NotHermitian);
+        }
+        let (rows, cols, values) = self.to_sparse_matrix(num_modes);
+        let dim = fock_dim(num_modes);
+        let inner = |a: &[Complex64], b: &[Complex64]| -> Complex64 {
+            zip(a, b).map(|(x, y)| x.conj() * y).sum()
+        };
+
+        let mut rng = Pcg64Mcg::seed_from_u64(0);
+        let mut v: Vec<Complex64> = (0..dim)
+            .map(|_| Complex64::new(rng.random_range(-1.0..1.0), 0.0))
+            .collect();
+        let norm = inner(&v, &v).re.sqrt();
+        v.iter_mut().for_each(|x| *x /= norm);
+
+        let mut basis: Vec<Vec<Complex64>> = vec![];
+        let mut alphas = vec![];
+        let mut betas = vec![];
+        let mut estimate = f64::INFINITY;
+        for _ in 0..max_iter {
+            let mut w = vec![Complex64::new(0.0, 0.0); dim];
+            for ((&row, &col), &value) in zip(zip(&rows, &cols), &values) {
+                w[row] += value * v[col];
+            }
+            alphas.push(inner(&v, &w).re);
+            basis.push(v);
+            for b in &basis {
+                let overlap = inner(b, &w);
+                zip(&mut w, b).for_each(|(x, y)| *x -= overlap * y);
+            }
+            let beta = inner(&w, &w).re.sqrt();
+
+            let k = alphas.len();
+            let mut tridiagonal = Array2::zeros((k, k));
+            for (i, &alpha) in alphas.iter().enumerate() {
+                tridiagonal[[i, i]] = alpha;
+            }
+            for (i, &b) in betas.iter().enumerate() {
+                tridiagonal[[i, i + 1]] = b;
+                tridiagonal[[i + 1, i]] = b;
+            }
+            let (eigenvalues, eigenvectors) = _symmetric_eigh(tridiagonal);
+            estimate = eigenvalues[0];
+            let residual = beta * eigenvectors[[k - 1, 0]].abs();
+            if residual <= tol || beta <= tol {
+                return Ok(estimate);
+            }
+            betas.push(beta);
+            v = w.into_iter().map(|x| x / beta).collect();
+        }
+        Err(GroundEnergyError::NotConverged { estimate })
+    }
+
+    /// Computes the sparse matrix of this operator on the Fock space of `num_modes` modes, as
+    /// coordinate (COO) triplets of row indices, column indices and values.
+    ///
+    /// The basis state with index `k` is the one whose `i`-th mode is occupied if the `i`-th bit of
+    /// `k` is set (like in [`FermionOperator::fock_diagonal`]). The signs are determined according
+    /// to the Jordan-Wigner convention (see [`FermionOperator::apply_to_state`]). Contributions to
+    /// the same entry are summed and entries which sum to exactly zero are omitted. The triplets
+    /// are sorted by column and then by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any action acts on a mode outside of `num_modes` or if `num_modes` exceeds
+    /// [`MAX_FOCK_MODES`](crate::operators::MAX_FOCK_MODES).
+    pub fn to_sparse_matrix(
+        &self,
+        num_modes: ModeIndex,
+    ) -> (Vec<usize>, Vec<usize>, Vec<Complex64>) {
+        let mut rows = vec![];
+        let mut cols = vec![];
+        let mut values = vec![];
+        for col in 0..fock_dim(num_modes) {
+            let occupation: Vec<bool> = (0..num_modes).map(|i| col >> i & 1 == 1).collect();
+            let mut column: BTreeMap<usize, Complex64> = BTreeMap::new();
+            for (coeff, state) in self.apply_to_state(&occupation) {
+                let row = state
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, occ)| **occ)
+                    .map(|(i, _)| 1 << i)
+                    .sum();
+                *column.entry(row).or_default() += coeff;
+            }
+            for (row, value) in column {
+                if value != Complex64::new(0.0, 0.0) {
+                    rows.push(row);
+                    cols.push(col);
+                    values.push(value);
+                }
+            }
+        }
+        (rows, cols, values)
+    }
+}
+
+/// Computes the vacuum expectation value of `actions`, given in the order in which they act on the
+/// vacuum state. Returns `None` when this value vanishes.
+fn _vacuum_expectation(actions: &[(bool, ModeIndex)]) -> Option<f64> {
+    let mut occupied: Vec<ModeIndex> = vec![];
+    let mut sign = 1.0;
+    for (action, index) in actions {
+        let pos = occupied.partition_point(|i| i < index);
+        let is_occupied = occupied.get(pos) == Some(index);
+        if *action == is_occupied {
+            return None;
+        }
+        if pos % 2 == 1 {
+            sign = -sign;
+        }
+        if *action {
+            occupied.insert(pos, *index);
+        } else {
+            occupied.remove(pos);
+        }
+    }
+    if occupied.is_empty() {
+        Some(sign)
+    } else {
+        None
+    }
+}
+
+/// Returns whether a term contains two equal actions on the same mode without an opposite action on
+/// that mode in between them, which implies that the term vanishes.
+fn _is_trivially_zero(term: &[FermionAction]) -> bool {
+    let mut last_actions = HashMap::new();
+    for (action, index) in term {
+        if last_actions.insert(*index, *action) == Some(*action) {
+            return true;
+        }
+    }
+    false
+}
+
+fn _normal_ordered_term(
+    term_view: FermionOperatorTermView,
+    max_terms: usize,
+) -> Result<FermionOperator, NormalOrderError> {
+    let mut coeffs = vec![];
+    let mut actions = vec![];
+    let mut indices = vec![];
+    let mut boundaries = vec![0];
+
+    let mut stack = vec![(term_view.to_vec(), term_view.coeff)];
+    while let Some((mut term, coeff)) = stack.pop() {
+        if _is_trivially_zero(&term) {
+            continue;
+        }
+        let mut parity = false;
+        let mut zero = false;
+        for i in 1..term.len() {
+            // shift the operator at index i to the left until it's in the correct location
+            for j in (1..=i).rev() {
+                let (action_right, index_right) = term[j];
+                let (action_left, index_left) = term[j - 1];
+                if *action_right == *action_left {
+                    // both create or both destroy
+                    match (index_right).cmp(index_left) {
+                        Ordering::Equal => {
+                            // operators are the same, so product is zero
+                            zero = true;
+                            break;
+                        }
+                        Ordering::Greater => {
+                            // swap operators and update sign
+                            term.swap(j - 1, j);
+                            parity = !parity;
+                        }
+                        Ordering::Less => {}
+                    }
+                } else if *action_right && !*action_left {
+                    // create on right and destroy on left
+                    if index_right == index_left {
+                        // add new term
+                        let mut new_term: Vec<FermionAction> = Vec::new();
+                        new_term.extend(&term[..j - 1]);
+                        new_term.extend(&term[j + 1..]);
+                        let signed_coeff = if parity { -coeff } else { coeff };
+                        stack.push((new_term, signed_coeff));
+                        if coeffs.len() + stack.len() >= max_terms {
+                            return Err(NormalOrderError { max_terms });
+                        }
+                    }
+                    // swap operators and update sign
+                    term.swap(j - 1, j);
+                    parity = !parity;
+                }
+            }
+        }
+        if zero {
+            continue;
+        }
+        let signed_coeff = if parity { -coeff } else { coeff };
+        coeffs.push(signed_coeff);
+        term.iter().for_each(|&(&a, &i)| {
+            actions.push(a);
+            indices.push(i);
+        });
+        boundaries.push(indices.len())
+    }
+    Ok(FermionOperator {
+        coeffs,
+        actions,
+        indices,
+        boundaries,
+    })
+}
+
+/// Computes the eigenvalues of the real symmetric `matrix` in ascending order (see
+/// [`_symmetric_eigh`]).
+fn _symmetric_eigenvalues(matrix: Array2<f64>) -> Vec<f64> {
+    _symmetric_eigh(matrix).0
+}
+
+/// Computes the eigenvalues of the real symmetric `matrix` in ascending order, together with the
+/// matrix whose columns are the corresponding eigenvectors, using cyclic Jacobi rotations until
+/// the off-diagonal entries are negligible.
+fn _symmetric_eigh(mut matrix: Array2<f64>) -> (Vec<f64>, Array2<f64>) {
+    const MAX_SWEEPS: usize = 100;
+    let n = matrix.nrows();
+    let mut eigenvectors = Array2::<f64>::eye(n);
+    let norm: f64 = matrix.iter().map(|x| x * x).sum();
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = matrix
+            .indexed_iter()
+            .filter(|((i, j), _)| i != j)
+            .map(|(_, x)| x * x)
+            .sum();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = matrix[[p, q]];
+                if apq == 0.0 {
+                    continue;
+                }
+                // the rotation angle which annihilates the entry (p, q)
+                let theta = (matrix[[q, q]] - matrix[[p, p]]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (matrix[[k, p]], matrix[[k, q]]);
+                    matrix[[k, p]] = c * akp - s * akq;
+                    matrix[[k, q]] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (matrix[[p, k]], matrix[[q, k]]);
+                    matrix[[p, k]] = c * apk - s * aqk;
+                    matrix[[q, k]] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (eigenvectors[[k, p]], eigenvectors[[k, q]]);
+                    eigenvectors[[k, p]] = c * vkp - s * vkq;
+                    eigenvectors[[k, q]] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| matrix[[i, i]].total_cmp(&matrix[[j, j]]));
+    let eigenvalues = order.iter().map(|&i| matrix[[i, i]]).collect();
+    (eigenvalues, eigenvectors.select(Axis(1), &order))
+}
+
+impl OperatorTrait for FermionOperator {
+    fn zero() -> Self {
+        Self {
+            coeffs: vec![],
+            actions: vec![],
+            indices: vec![],
+            boundaries: vec![0],
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![],
+            indices: vec![],
+            boundaries: vec![0, 0],
+        }
+    }
+
+    fn equiv(&self, other: &Self, atol: f64) -> bool {
+        let mut diff = self.__sub__(other);
         diff = diff.simplify(atol);
         for c in diff.coeffs {
             if c.abs() > atol {
@@ -257,15 +1682,29 @@ impl OperatorTrait for FermionOperator {
             .extend(other.boundaries[1..].iter().map(|b| b + offset));
     }
 
+    fn scaled_iadd(&mut self, other: &Self, scale: Complex64) {
+        self.coeffs.extend(other.coeffs.iter().map(|c| c * scale));
+        self.actions.extend_from_slice(&other.actions);
+        self.indices.extend_from_slice(&other.indices);
+        let offset = self.boundaries[self.boundaries.len() - 1];
+        self.boundaries
+            .extend(other.boundaries[1..].iter().map(|b| b + offset));
+    }
+
     fn __imul__(&mut self, other: Complex64) {
         self.coeffs.iter_mut().for_each(|c| *c *= other);
     }
 
     fn __iand__(&mut self, other: &Self) {
-        let mut coeffs = vec![];
-        let mut actions = vec![];
-        let mut indices = vec![];
-        let mut boundaries = vec![0];
+        // PERF: the sizes of the product are known up front, so every buffer is allocated once
+        let num_terms = self.coeffs.len() * other.coeffs.len();
+        let num_actions =
+            self.actions.len() * other.coeffs.len() + other.actions.len() * self.coeffs.len();
+        let mut coeffs = Vec::with_capacity(num_terms);
+        let mut actions = Vec::with_capacity(num_actions);
+        let mut indices = Vec::with_capacity(num_actions);
+        let mut boundaries = Vec::with_capacity(num_terms + 1);
+        boundaries.push(0);
 
         for left in self.iter() {
             for right in other.iter() {
@@ -306,13 +1745,169 @@ impl OperatorTrait for FermionOperator {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl FermionOperator {
+    /// Renders this operator like its [`fmt::Display`] implementation, but with the real and
+    /// imaginary parts of the coefficients formatted in the given `style` with `precision` digits
+    /// after the decimal point. `to_str(6, CoeffStyle::Scientific)` equals `to_string()`.
+    pub fn to_str(&self, precision: usize, style: CoeffStyle) -> String {
+        let width = precision + 6;
+        let mut sorted: Vec<_> = self.iter().collect();
+        sorted.sort_by_key(|&term| term.into_vec());
+        let mut items_str = Vec::new();
+        for term in sorted {
+            let key_parts: Vec<String> = term
+                .iter()
+                .map(|(action, orb)| format!("{}_{}", if *action { "+" } else { "-" }, orb))
+                .collect();
+            let key_str = format!("({})", key_parts.join(" "));
+            let (re, im) = (term.coeff.re, term.coeff.im);
+            let val_str = match style {
+                CoeffStyle::Scientific => {
+                    format!("{re:width$.precision$e}{im:+width$.precision$e}j")
+                }
+                CoeffStyle::Fixed => format!("{re:width$.precision$}{im:+width$.precision$}j"),
+            };
+            items_str.push(format!("{val_str} * {key_str}"));
+        }
+        items_str.join("\n")
+    }
 
-    #[test]
-    fn test_zero() {
-        let zero = FermionOperator::zero();
+    /// Renders this operator as a LaTeX sum, like `0.5\, a^\dagger_0 a_1 - 2\, a_1`.
+    ///
+    /// The terms are sorted like in [`FermionOperator::to_str`]. Real and imaginary coefficients
+    /// are rendered as such and all others as `(re + im i)`. If `max_terms` is given and exceeded,
+    /// only the first `max_terms` terms are rendered, followed by `\cdots` (which stands alone if
+    /// `max_terms` is `0`). The empty operator is rendered as `0`.
+    pub fn to_latex(&self, max_terms: Option<usize>) -> String {
+        let mut sorted: Vec<_> = self.iter().collect();
+        if sorted.is_empty() {
+            return String::from("0");
+        }
+        sorted.sort_by_key(|&term| term.into_vec());
+        let num_rendered = max_terms.map_or(sorted.len(), |max| max.min(sorted.len()));
+        let mut out = String::new();
+        for (n, term) in sorted[..num_rendered].iter().enumerate() {
+            let (re, im) = (term.coeff.re, term.coeff.im);
+            let coeff_str = if im == 0.0 || re == 0.0 {
+                // the sign of a real or imaginary coefficient gets folded into the separator
+                let (value, unit) = if im == 0.0 { (re, "") } else { (im, "i") };
+                if n > 0 && value.is_sign_negative() {
+                    out.push_str(" - ");
+                    format!("{}{unit}", -value)
+                } else {
+                    if n > 0 {
+                        out.push_str(" + ");
+                    }
+                    format!("{value}{unit}")
+                }
+            } else {
+                if n > 0 {
+                    out.push_str(" + ");
+                }
+                format!("({re} {} {}i)", if im < 0.0 { "-" } else { "+" }, im.abs())
+            };
+            out.push_str(&coeff_str);
+            let actions: Vec<String> = term
+                .iter()
+                .map(|(action, index)| {
+                    let index = if *index < 10 {
+                        index.to_string()
+                    } else {
+                        format!("{{{index}}}")
+                    };
+                    if *action {
+                        format!("a^\\dagger_{index}")
+                    } else {
+                        format!("a_{index}")
+                    }
+                })
+                .collect();
+            if !actions.is_empty() {
+                out.push_str("\\, ");
+                out.push_str(&actions.join(" "));
+            }
+        }
+        if num_rendered < sorted.len() {
+            if num_rendered > 0 {
+                out.push_str(" + ");
+            }
+            out.push_str("\\cdots");
+        }
+        out
+    }
+}
+
+/// Formats the operator with one term per line, sorted by their actions.
+impl fmt::Display for FermionOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_str(6, CoeffStyle::Scientific))
+    }
+}
+
+/// A [`FermionOperator`] whose coefficients are stored in single precision.
+///
+/// This is purely a storage format to reduce the memory footprint of very large operators. It
+/// does not support any arithmetic and must be converted back into a [`FermionOperator`] for
+/// further processing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FermionOperatorF32 {
+    pub coeffs: Vec<Complex32>,
+    pub actions: Vec<bool>,
+    pub indices: Vec<ModeIndex>,
+    pub boundaries: Vec<usize>,
+}
+
+impl FermionOperatorF32 {
+    /// Returns the number of bytes occupied by the operator's data buffers.
+    pub fn nbytes(&self) -> usize {
+        size_of_val(self.coeffs.as_slice())
+            + size_of_val(self.actions.as_slice())
+            + size_of_val(self.indices.as_slice())
+            + size_of_val(self.boundaries.as_slice())
+    }
+}
+
+/// Rounds the coefficients to single precision.
+impl From<&FermionOperator> for FermionOperatorF32 {
+    fn from(op: &FermionOperator) -> Self {
+        Self {
+            coeffs: op
+                .coeffs
+                .iter()
+                .map(|c| Complex32::new(c.re as f32, c.im as f32))
+                .collect(),
+            actions: op.actions.clone(),
+            indices: op.indices.clone(),
+            boundaries: op.boundaries.clone(),
+        }
+    }
+}
+
+impl From<&FermionOperatorF32> for FermionOperator {
+    fn from(op: &FermionOperatorF32) -> Self {
+        Self {
+            coeffs: op
+                .coeffs
+                .iter()
+                .map(|c| Complex64::new(c.re as f64, c.im as f64))
+                .collect(),
+            actions: op.actions.clone(),
+            indices: op.indices.clone(),
+            boundaries: op.boundaries.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operators::library::commutators::commutator;
+    use crate::operators::library::electronic_integrals::From1Body;
+    use crate::operators::library::fcidump::FCIDump;
+
+    #[test]
+    fn test_zero() {
+        let zero = FermionOperator::zero();
         assert_eq!(
             zero,
             FermionOperator {
@@ -380,6 +1975,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_assign_many() {
+        let mut op = FermionOperator::zero();
+        for i in 0..1000 {
+            op += FermionOperator {
+                coeffs: vec![Complex64::new(i as f64, 0.0)],
+                actions: vec![true, false],
+                indices: vec![i, i + 1],
+                boundaries: vec![0, 2],
+            };
+        }
+        assert_eq!(op.coeffs.len(), 1000);
+        assert_eq!(op.boundaries, (0..=2000).step_by(2).collect::<Vec<_>>());
+        for (i, term) in op.iter().enumerate() {
+            assert_eq!(term.coeff, Complex64::new(i as f64, 0.0));
+            assert_eq!(term.indices, [i as ModeIndex, i as ModeIndex + 1]);
+        }
+    }
+
+    #[test]
+    fn test_scaled_add_assign() {
+        let mut op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 0, 2],
+        };
+        let other = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(0.0, 1.0)],
+            actions: vec![true, false, false],
+            indices: vec![1, 2, 3],
+            boundaries: vec![0, 2, 3],
+        };
+        let scale = Complex64::new(3.0, 0.0);
+        let expected = op.__add__(&other.__mul__(scale));
+        op.scaled_iadd(&other, scale);
+        assert_eq!(op, expected);
+    }
+
     #[test]
     fn test_sub() {
         let one = FermionOperator::one();
@@ -519,269 +2153,1889 @@ mod tests {
                 boundaries: vec![0, 0],
             }
         );
-    }
+    }
+
+    #[test]
+    fn test_and() {
+        let op1 = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        let op2 = FermionOperator {
+            coeffs: vec![Complex64::new(1.5, 0.0), Complex64::new(4.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![1, 0],
+            boundaries: vec![0, 0, 2],
+        };
+        let result = op1 & op2;
+        assert_eq!(
+            result,
+            FermionOperator {
+                coeffs: vec![
+                    Complex64::new(3.0, 0.0),
+                    Complex64::new(8.0, 0.0),
+                    Complex64::new(4.5, 0.0),
+                    Complex64::new(12.0, 0.0),
+                ],
+                actions: vec![true, false, true, false, true, false, true, false],
+                indices: vec![1, 0, 0, 1, 1, 0, 0, 1],
+                boundaries: vec![0, 0, 2, 4, 8],
+            }
+        );
+    }
+
+    #[test]
+    fn test_matmul() {
+        let op1 = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 0, 1],
+        };
+        let op2 = FermionOperator {
+            coeffs: vec![Complex64::new(1.5, 0.0), Complex64::new(4.0, 0.0)],
+            actions: vec![false],
+            indices: vec![0],
+            boundaries: vec![0, 0, 1],
+        };
+        let product = op1.matmul(&op2);
+        assert_eq!(product, op2.__and__(&op1));
+        // the operators do not commute, so the two conventions differ
+        assert!(!product.equiv(&op1.__and__(&op2), 1e-8));
+        assert_eq!(
+            product.iter().last().map(|term| term.into_vec()),
+            Some(vec![(true, 0), (false, 0)])
+        );
+    }
+
+    #[test]
+    fn test_and_assign() {
+        let mut op1 = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        let op2 = FermionOperator {
+            coeffs: vec![Complex64::new(1.5, 0.0), Complex64::new(4.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![1, 0],
+            boundaries: vec![0, 0, 2],
+        };
+        op1 &= op2;
+        assert_eq!(
+            op1,
+            FermionOperator {
+                coeffs: vec![
+                    Complex64::new(3.0, 0.0),
+                    Complex64::new(8.0, 0.0),
+                    Complex64::new(4.5, 0.0),
+                    Complex64::new(12.0, 0.0),
+                ],
+                actions: vec![true, false, true, false, true, false, true, false],
+                indices: vec![1, 0, 0, 1, 1, 0, 0, 1],
+                boundaries: vec![0, 0, 2, 4, 8],
+            }
+        );
+    }
+
+    #[test]
+    fn test_pow() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+        // exponent=0
+        let one = FermionOperator::one();
+        assert_eq!(op.__pow__(0), one);
+
+        // exponent=1
+        assert_eq!(op.__pow__(1), op);
+
+        // exponent=2
+        let squared = op.__pow__(2);
+        assert_eq!(
+            squared,
+            FermionOperator {
+                coeffs: vec![Complex64::new(4.0, 0.0)],
+                actions: vec![true, true],
+                indices: vec![0, 0],
+                boundaries: vec![0, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_pow_by_squaring() {
+        let op = FermionOperator::random(3, 3, 2, Some(4));
+        let mut naive = FermionOperator::one();
+        for exponent in 0..6 {
+            let result = op.__pow__(exponent);
+            assert_eq!(result.coeffs.len(), naive.coeffs.len());
+            assert!(
+                result
+                    .simplify(0.0)
+                    .__sub__(&naive.simplify(0.0))
+                    .simplify(1e-10)
+                    .coeffs
+                    .is_empty()
+            );
+            naive.__iand__(&op);
+        }
+    }
+
+    #[test]
+    fn test_checked_pow() {
+        let op = FermionOperator::random(2, 3, 2, Some(5));
+        assert_eq!(op.checked_pow(10, 1 << 10), Some(op.__pow__(10)));
+        assert_eq!(op.checked_pow(11, 1 << 10), None);
+        assert_eq!(op.checked_pow(usize::MAX, 1 << 10), None);
+
+        let one = FermionOperator::one();
+        assert_eq!(one.checked_pow(usize::MAX, 1), Some(one));
+    }
+
+    #[test]
+    fn test_ichop() {
+        let mut op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1e-4, 0.0),
+                Complex64::new(1e-6, 0.0),
+                Complex64::new(1e-8, 0.0),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 0, 1, 2],
+        };
+
+        op.ichop(1e-7);
+
+        let expected1 = FermionOperator {
+            coeffs: vec![Complex64::new(1e-4, 0.0), Complex64::new(1e-6, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 0, 1],
+        };
+
+        assert_eq!(op, expected1);
+
+        op.ichop(1e-5);
+
+        let expected2 = FermionOperator {
+            coeffs: vec![Complex64::new(1e-4, 0.0)],
+            actions: vec![],
+            indices: vec![],
+            boundaries: vec![0, 0],
+        };
+
+        assert_eq!(op, expected2);
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 2.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        let conj = op.conjugate();
+        assert_eq!(conj.coeffs, [Complex64::new(1.0, -2.0)]);
+        assert_eq!(conj.conjugate(), op);
+        assert_ne!(conj, op.adjoint());
+    }
+
+    #[test]
+    fn test_scale_and_shift() {
+        let op = FermionOperator::random(20, 4, 4, Some(0));
+        let scale = Complex64::new(2.0, 0.5);
+        let shift = Complex64::new(3.0, 0.0);
+        let expected = op
+            .__mul__(scale)
+            .__add__(&FermionOperator::one().__mul__(shift));
+        assert!(op.scale_and_shift(scale, shift).equiv(&expected, 1e-12));
+    }
+
+    #[test]
+    fn test_conjugate_by() {
+        let number = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(number.conjugate_by(&FermionOperator::one()), number);
+
+        let op = FermionOperator::random(5, 3, 4, Some(0));
+        let rotation = FermionOperator::random(3, 3, 2, Some(1));
+        let expected = rotation.__and__(&op).__and__(&rotation.adjoint());
+        assert_eq!(op.conjugate_by(&rotation), expected);
+    }
+
+    #[test]
+    fn test_tensor() {
+        let parity =
+            FermionOperator::parity_operator(1).tensor(&FermionOperator::parity_operator(2), 1);
+        let expected = FermionOperator::parity_operator(3);
+        assert!(
+            parity
+                .normal_ordered()
+                .equiv(&expected.normal_ordered(), 1e-12)
+        );
+
+        let number = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2],
+        };
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 4],
+        };
+        assert_eq!(number.tensor(&number, 1), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tensor_overlapping() {
+        FermionOperator::random(5, 4, 2, Some(0)).tensor(&FermionOperator::one(), 2);
+    }
+
+    #[test]
+    fn test_equiv_up_to_phase() {
+        let op = FermionOperator::random(10, 4, 4, Some(0));
+        let rotated = op.__mul__(Complex64::new(0.0, 1.0));
+        assert!(!op.equiv(&rotated, 1e-8));
+        assert!(op.equiv_up_to_phase(&rotated, 1e-8));
+        let phase = op.global_phase(&rotated, 1e-8).unwrap();
+        assert!((phase - Complex64::new(0.0, 1.0)).norm() < 1e-12);
+
+        let phase = Complex64::from_polar(1.0, 0.3);
+        assert!(op.equiv_up_to_phase(&op.__mul__(phase), 1e-8));
+        assert!(!op.equiv_up_to_phase(&op.__mul__(phase * 2.0), 1e-8));
+        assert!(!op.equiv_up_to_phase(&FermionOperator::random(10, 4, 4, Some(1)), 1e-8));
+
+        let zero = FermionOperator::zero();
+        assert!(zero.equiv_up_to_phase(&zero, 1e-8));
+        assert!(!zero.equiv_up_to_phase(&op, 1e-8));
+        assert!(!op.equiv_up_to_phase(&zero, 1e-8));
+    }
+
+    #[test]
+    fn test_support_histogram() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let op = FermionOperator::from(&fcidump);
+        // the constant, the 8 one-body terms of which 4 act on a single mode, and the 32 two-body
+        // terms of which 4 act on a single mode, 20 on a pair of modes and 8 on all four modes
+        assert_eq!(
+            op.support_histogram(),
+            BTreeMap::from([(0, 1), (1, 8), (2, 24), (4, 8)])
+        );
+        assert_eq!(
+            op.support_histogram().values().sum::<usize>(),
+            op.coeffs.len()
+        );
+    }
+
+    #[test]
+    fn test_rotate_orbitals() {
+        let (c, s) = (0.6_f64, 0.8_f64);
+        let unitary = ndarray::array![
+            [Complex64::new(c, 0.0), Complex64::new(0.0, s)],
+            [Complex64::new(0.0, s), Complex64::new(c, 0.0)],
+        ];
+        let number_op = |mode: ModeIndex| FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![mode, mode],
+            boundaries: vec![0, 2],
+        };
+
+        // the rotated number operator of a single mode keeps a trace of 1
+        let rotated = number_op(0).rotate_orbitals(unitary.view()).simplify(1e-12);
+        let trace: Complex64 = rotated
+            .iter()
+            .filter(|term| term.indices[0] == term.indices[1])
+            .map(|term| term.coeff)
+            .sum();
+        assert!((trace - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+        assert!(!rotated.equiv(&number_op(0), 1e-8));
+
+        // the total particle number is invariant
+        let total = number_op(0).__add__(&number_op(1));
+        assert!(total.rotate_orbitals(unitary.view()).equiv(&total, 1e-12));
+
+        // rotating back restores the original operator
+        let op = FermionOperator::random(10, 2, 4, Some(0));
+        let inverse = unitary.t().mapv(|u| u.conj());
+        let restored = op
+            .rotate_orbitals(unitary.view())
+            .rotate_orbitals(inverse.view());
+        assert!(restored.equiv(&op, 1e-12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_orbitals_out_of_bounds() {
+        let unitary = ndarray::Array2::<Complex64>::eye(2);
+        FermionOperator::random(10, 4, 2, Some(0)).rotate_orbitals(unitary.view());
+    }
+
+    #[test]
+    fn test_adjoint() {
+        let op1 = FermionOperator {
+            coeffs: vec![Complex64::new(0.0, 2.0), Complex64::new(3.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        let adj = op1.adjoint();
+        assert_eq!(
+            adj,
+            FermionOperator {
+                coeffs: vec![Complex64::new(0.0, -2.0), Complex64::new(3.0, 0.0)],
+                actions: vec![true, false],
+                indices: vec![1, 0],
+                boundaries: vec![0, 0, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_equiv() {
+        let zero = FermionOperator::zero();
+        let op = Complex64::new(1e-8, 0.0) * FermionOperator::one();
+        assert!(op.equiv(&zero, 1e-6));
+        assert!(!op.equiv(&zero, 1e-10));
+    }
+
+    #[test]
+    fn test_normal_ordered_1() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+
+        assert_eq!(op.normal_ordered(), op);
+    }
+
+    #[test]
+    fn test_normal_ordered_2() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, true],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(-1.0, 0.0)],
+            actions: vec![true, true],
+            indices: vec![1, 0],
+            boundaries: vec![0, 2],
+        };
+
+        assert_eq!(op.normal_ordered(), expected);
+    }
+
+    #[test]
+    fn test_normal_ordered_3() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![false, true],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2],
+        };
+
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(-1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2, 2],
+        };
+
+        assert_eq!(op.normal_ordered(), expected);
+    }
+
+    #[test]
+    fn test_normal_ordered_repeated_actions() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, true, true, true, true],
+            indices: vec![0, 1, 0, 0, 0],
+            boundaries: vec![0, 3, 5],
+        };
+
+        assert_eq!(op.normal_ordered(), FermionOperator::zero());
+    }
+
+    #[test]
+    fn test_normal_ordered_repeated_actions_interrupted() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, true],
+            indices: vec![0, 0, 0],
+            boundaries: vec![0, 3],
+        };
+
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+
+        assert!(op.normal_ordered().equiv(&expected, 1e-12));
+    }
+
+    #[test]
+    fn test_truncate_order() {
+        // 1 + a†_0 a_1 + a†_3 a†_0 a_3 a_0
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(4.0, 0.0),
+            ],
+            actions: vec![true, false, true, true, false, false],
+            indices: vec![0, 1, 3, 0, 3, 0],
+            boundaries: vec![0, 0, 2, 6],
+        };
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        assert_eq!(op.truncate_order(3), expected);
+        assert_eq!(op.truncate_order(4), op);
+        assert_eq!(op.truncate_order(0).coeffs.len(), 1);
+    }
+
+    #[test]
+    fn test_restrict_to_modes() {
+        // 1 + a†_0 a_1 + a†_2 a_1 + a†_3 a†_0 a_3 a_0
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(3.0, 0.0),
+                Complex64::new(4.0, 0.0),
+            ],
+            actions: vec![true, false, true, false, true, true, false, false],
+            indices: vec![0, 1, 2, 1, 3, 0, 3, 0],
+            boundaries: vec![0, 0, 2, 4, 8],
+        };
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        assert_eq!(op.restrict_to_modes(2), expected);
+        assert_eq!(op.restrict_to_modes(4), op);
+    }
+
+    #[test]
+    fn test_drop_vanishing_terms() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(3.0, 0.0),
+            ],
+            actions: vec![
+                true, true, false, false, true, false, true, true, true, false, false,
+            ],
+            indices: vec![0, 0, 1, 2, 0, 0, 0, 0, 1, 2, 2],
+            boundaries: vec![0, 4, 7, 11],
+        };
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0)],
+            actions: vec![true, false, true],
+            indices: vec![0, 0, 0],
+            boundaries: vec![0, 3],
+        };
+        assert_eq!(op.drop_vanishing_terms(), expected);
+    }
+
+    #[test]
+    fn test_normal_ordered_bounded() {
+        // a_0 a†_0 a_1 a†_1 ... normal orders into 2^10 terms
+        let num_modes = 10;
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: (0..num_modes).flat_map(|_| [false, true]).collect(),
+            indices: (0..num_modes).flat_map(|i| [i, i]).collect(),
+            boundaries: vec![0, 2 * num_modes as usize],
+        };
+
+        assert_eq!(
+            op.normal_ordered_bounded(100),
+            Err(NormalOrderError { max_terms: 100 })
+        );
+        let ordered = op.normal_ordered_bounded(1 << num_modes).unwrap();
+        assert_eq!(ordered.coeffs.len(), 1 << num_modes);
+        assert_eq!(ordered, op.normal_ordered());
+    }
+
+    #[test]
+    fn test_normal_ordered_with_provenance() {
+        // a_0 a†_0 a_1 a†_1 normal orders into 4 terms, preceded by a term which remains as is
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, false, true, false, true],
+            indices: vec![0, 1, 0, 0, 1, 1],
+            boundaries: vec![0, 2, 6],
+        };
+
+        let (ordered, provenance) = op.normal_ordered_with_provenance();
+        assert_eq!(ordered, op.normal_ordered());
+        assert_eq!(provenance, vec![0, 1, 1, 1, 1]);
+
+        let (ordered, provenance) = op
+            .filter_terms(|term| term.actions.len() == 4)
+            .normal_ordered_with_provenance();
+        assert_eq!(ordered.coeffs.len(), 4);
+        assert!(provenance.iter().all(|i| *i == 0));
+    }
+
+    #[test]
+    fn test_hermitian_generators() {
+        // (1+2i) a†_0 a_1 + (1-2i) a†_1 a_0
+        let hopping = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 2.0), Complex64::new(1.0, -2.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 1, 1, 0],
+            boundaries: vec![0, 2, 4],
+        };
+        let generators = hopping.hermitian_generators(1e-8);
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 2.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(generators, vec![expected]);
+
+        // adding a number operator and an interaction which are their own adjoints
+        let number = FermionOperator {
+            coeffs: vec![Complex64::new(3.0, 0.0), Complex64::new(0.5, 0.0)],
+            actions: vec![true, false, true, true, false, false],
+            indices: vec![0, 0, 1, 0, 1, 0],
+            boundaries: vec![0, 2, 6],
+        };
+        let op = hopping.__add__(&number);
+        let generators = op.hermitian_generators(1e-8);
+        assert_eq!(generators.len(), 3);
+        let mut reconstructed = FermionOperator::zero();
+        for generator in &generators {
+            reconstructed.__iadd__(&generator.__add__(&generator.adjoint()));
+        }
+        assert!(
+            reconstructed
+                .normal_ordered()
+                .equiv(&op.normal_ordered(), 1e-8)
+        );
+    }
+
+    #[test]
+    fn test_iter_nonzero() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1e-10, 0.0),
+                Complex64::new(0.0, -2.0),
+                Complex64::new(0.0, 0.0),
+            ],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 1, 2, 3],
+            boundaries: vec![0, 1, 2, 3, 4],
+        };
+        let terms: Vec<_> = op.iter_nonzero(1e-8).map(|term| term.into_vec()).collect();
+        assert_eq!(terms, vec![vec![(true, 0)], vec![(true, 2)]]);
+        assert_eq!(op.iter_nonzero(0.0).count(), 3);
+        assert_eq!(op.iter_nonzero(2.0).count(), 0);
+    }
+
+    #[test]
+    fn test_checked_iter() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 1, 2],
+        };
+        assert!(op.checked_iter().all(|term| term.is_ok()));
+
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(3.0, 0.0),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 1, 3],
+        };
+        let terms: Vec<_> = op.checked_iter().collect();
+        assert_eq!(terms.len(), 3);
+        assert_eq!(terms[0].as_ref().unwrap().into_vec(), vec![(true, 0)]);
+        assert_eq!(
+            terms[1].as_ref().unwrap_err(),
+            "Term 1 spans 1..3 which is invalid for 2 actions and 2 indices."
+        );
+        assert_eq!(
+            terms[2].as_ref().unwrap_err(),
+            "Term 2 has no boundaries: expected 4 boundaries but found 3."
+        );
+    }
+
+    #[test]
+    fn test_permutation_parity_to_normal() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0); 4],
+            actions: vec![true, true, true, true, false, true, true, false, false],
+            indices: vec![1, 0, 0, 1, 0, 1, 2, 0, 1],
+            boundaries: vec![0, 2, 4, 6, 9],
+        };
+        let parities: Vec<i32> = op
+            .iter()
+            .map(|term| term.permutation_parity_to_normal())
+            .collect();
+        assert_eq!(parities, vec![1, -1, -1, -1]);
+    }
+
+    #[test]
+    fn test_is_hermitian() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(0.0, 1.00001), Complex64::new(0.0, -1.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 1, 1, 0],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!(op.is_hermitian(1e-4));
+        assert!(!op.is_hermitian(1e-6));
+    }
+
+    #[test]
+    fn test_many_body_order() {
+        assert_eq!(FermionOperator::one().many_body_order(), 0);
+
+        assert_eq!(
+            FermionOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                actions: vec![true],
+                indices: vec![0],
+                boundaries: vec![0, 1],
+            }
+            .many_body_order(),
+            1
+        );
+
+        assert_eq!(
+            FermionOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                actions: vec![true, false],
+                indices: vec![0, 1],
+                boundaries: vec![0, 2],
+            }
+            .many_body_order(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_many_body_order_long_term() {
+        let len = u16::MAX as usize + 2;
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true; len + 1],
+            indices: vec![0; len + 1],
+            boundaries: vec![0, 1, len + 1],
+        };
+        assert_eq!(op.max_term_length(), len);
+        assert_eq!(op.many_body_order(), len as u32);
+        assert_eq!(FermionOperator::zero().max_term_length(), 0);
+    }
+
+    #[test]
+    fn test_num_actions() {
+        let op = FermionOperator::random(100, 4, 6, Some(0));
+        let total: usize = op.iter().map(|term| term.actions.len()).sum();
+        assert_eq!(op.num_actions(), total);
+        assert_eq!(op.average_term_length(), total as f64 / 100.0);
+
+        assert_eq!(FermionOperator::zero().num_actions(), 0);
+        assert_eq!(FermionOperator::zero().average_term_length(), 0.0);
+        assert_eq!(FermionOperator::one().average_term_length(), 0.0);
+    }
+
+    #[test]
+    fn test_conserves_particle_number() {
+        let op1 = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+
+        assert!(op1.conserves_particle_number());
+
+        let op2 = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+
+        assert!(!op2.conserves_particle_number());
+    }
+
+    #[test]
+    fn test_conserves_spin_numbers() {
+        let norb = 2;
+        let alpha_hopping = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        assert!(alpha_hopping.conserves_spin_numbers(norb));
+
+        let spin_flip = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, norb],
+            boundaries: vec![0, 2],
+        };
+        assert!(spin_flip.conserves_particle_number());
+        assert!(!spin_flip.conserves_spin_numbers(norb));
+
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        assert!(FermionOperator::from(&fcidump).conserves_spin_numbers(fcidump.norb as ModeIndex));
+    }
+
+    #[test]
+    fn test_random() {
+        let op = FermionOperator::random(100, 4, 3, Some(42));
+        assert_eq!(op.coeffs.len(), 100);
+        assert_eq!(op.boundaries.len(), 101);
+        assert!(op.many_body_order() <= 3);
+        assert!(op.indices.iter().all(|i| *i < 4));
+        assert_eq!(op, FermionOperator::random(100, 4, 3, Some(42)));
+    }
+
+    #[test]
+    fn test_apply_to_state() {
+        let cre = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+        assert_eq!(
+            cre.apply_to_state(&[false]),
+            vec![(Complex64::new(1.0, 0.0), vec![true])]
+        );
+
+        let ann = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![false],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+        assert_eq!(ann.apply_to_state(&[false]), vec![]);
+    }
+
+    #[test]
+    fn test_apply_to_state_signs() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, false],
+            indices: vec![1, 0, 1],
+            boundaries: vec![0, 2, 3],
+        };
+        assert_eq!(
+            op.apply_to_state(&[true, false]),
+            vec![(Complex64::new(2.0, 0.0), vec![false, true])]
+        );
+        assert_eq!(
+            op.apply_to_state(&[true, true]),
+            vec![(Complex64::new(-1.0, 0.0), vec![true, false])]
+        );
+    }
+
+    #[test]
+    fn test_matrix_element() {
+        let cre = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true],
+            indices: vec![0],
+            boundaries: vec![0, 1],
+        };
+        assert_eq!(
+            cre.matrix_element(&[true], &[false]),
+            Complex64::new(1.0, 0.0)
+        );
+        assert_eq!(
+            cre.matrix_element(&[false], &[false]),
+            Complex64::new(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_matrix_element_hermitian() {
+        // (1+2i) a+_0 a_2 + (1-2i) a+_2 a_0 + 0.5 a+_1 a_1, where the hopping picks up a sign from
+        // the occupation of mode 1
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 2.0),
+                Complex64::new(1.0, -2.0),
+                Complex64::new(0.5, 0.0),
+            ],
+            actions: vec![true, false, true, false, true, false],
+            indices: vec![0, 2, 2, 0, 1, 1],
+            boundaries: vec![0, 2, 4, 6],
+        };
+        assert!(op.is_hermitian(1e-8));
+        let states: Vec<Vec<bool>> = (0..8)
+            .map(|k| (0..3).map(|i| k >> i & 1 == 1).collect())
+            .collect();
+        for bra in &states {
+            for ket in &states {
+                assert_eq!(
+                    op.matrix_element(bra, ket),
+                    op.matrix_element(ket, bra).conj()
+                );
+            }
+        }
+        assert_eq!(
+            op.matrix_element(&[true, true, false], &[false, true, true]),
+            Complex64::new(-1.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn test_chop_relative() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1e-3, 0.0),
+                Complex64::new(0.0, 1e-7),
+            ],
+            actions: vec![true, false, true],
+            indices: vec![0, 1, 2],
+            boundaries: vec![0, 1, 2, 3],
+        };
+
+        let chopped = op.chop_relative(1e-4);
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1e-3, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 1, 2],
+        };
+        assert!(chopped.equiv(&expected, 0.0));
+        assert_eq!(chopped.coeffs.len(), 2);
+
+        assert_eq!(op.chop_relative(0.0).coeffs.len(), 3);
+        assert_eq!(
+            FermionOperator::zero().chop_relative(1e-4),
+            FermionOperator::zero()
+        );
+    }
+
+    #[test]
+    fn test_term_count_vs_threshold() {
+        // 1 + 1e-3 a†_0 + 1e-6 a_1 + 1e-9 a†_2, where the last term is split into two halves
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1e-3, 0.0),
+                Complex64::new(0.0, 1e-6),
+                Complex64::new(5e-10, 0.0),
+                Complex64::new(5e-10, 0.0),
+            ],
+            actions: vec![true, false, true, true],
+            indices: vec![0, 1, 2, 2],
+            boundaries: vec![0, 0, 1, 2, 3, 4],
+        };
+        let thresholds = [0.0, 1e-10, 1e-8, 1e-5, 1e-2, 1.0];
+        let counts = op.term_count_vs_threshold(&thresholds);
+        assert_eq!(counts, vec![4, 4, 3, 2, 1, 0]);
+        assert!(counts.windows(2).all(|w| w[0] >= w[1]));
+        for (atol, count) in zip(thresholds, counts) {
+            assert_eq!(op.simplify(atol).coeffs.len(), count);
+        }
+    }
+
+    #[test]
+    fn test_f32_round_trip() {
+        let op = FermionOperator::random(100, 8, 4, Some(0));
+        let op32 = FermionOperatorF32::from(&op);
+        assert!(op32.nbytes() < op.nbytes());
+        assert_eq!(
+            op.nbytes() - op32.nbytes(),
+            op.coeffs.len() * size_of::<Complex32>()
+        );
+
+        let round_trip = FermionOperator::from(&op32);
+        assert_eq!(round_trip.actions, op.actions);
+        assert_eq!(round_trip.indices, op.indices);
+        assert_eq!(round_trip.boundaries, op.boundaries);
+        for (a, b) in zip(&round_trip.coeffs, &op.coeffs) {
+            assert!((a - b).norm() <= f32::EPSILON as f64 * b.norm());
+        }
+    }
+
+    #[test]
+    fn test_is_real() {
+        let mut op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-0.5, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 1, 1, 0],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!(op.is_real(0.0));
+
+        op.coeffs[1].im = 1e-6;
+        assert!(!op.is_real(1e-8));
+        assert!(op.is_real(1e-4));
+    }
+
+    #[test]
+    fn test_coefficient_sum_and_constant() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.5, 0.0),
+                Complex64::new(2.0, 1.0),
+                Complex64::new(-0.5, 0.0),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2, 2],
+        };
+        assert_eq!(op.coefficient_sum(), Complex64::new(3.0, 1.0));
+        assert_eq!(op.constant(), Complex64::new(1.0, 0.0));
+        assert_eq!(FermionOperator::zero().constant(), Complex64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_coefficient_stats() {
+        let mut op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(3.0, 4.0),
+                Complex64::new(-1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2, 2],
+        };
+        assert_eq!(
+            op.coefficient_stats(),
+            CoeffStats {
+                min_abs: 0.0,
+                max_abs: 5.0,
+                mean_abs: 2.0,
+                has_nan: false,
+                has_inf: false,
+            }
+        );
+
+        op.coeffs[2] = Complex64::new(f64::NAN, 0.0);
+        let stats = op.coefficient_stats();
+        assert!(stats.has_nan);
+        assert!(!stats.has_inf);
+        assert_eq!(
+            (stats.min_abs, stats.max_abs, stats.mean_abs),
+            (1.0, 5.0, 3.0)
+        );
+
+        op.coeffs[1] = Complex64::new(0.0, f64::NEG_INFINITY);
+        let stats = op.coefficient_stats();
+        assert!(stats.has_nan && stats.has_inf);
+        assert_eq!(
+            (stats.min_abs, stats.max_abs, stats.mean_abs),
+            (5.0, 5.0, 5.0)
+        );
+
+        assert_eq!(
+            FermionOperator::zero().coefficient_stats(),
+            CoeffStats::default()
+        );
+    }
+
+    #[test]
+    fn test_invalid_coeffs() {
+        let mut op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(f64::NAN, 0.0),
+                Complex64::new(0.0, f64::INFINITY),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 1, 2],
+        };
+        assert!(op.has_invalid_coeffs());
+        assert_eq!(op.check_coeffs(), Err(InvalidCoeffError { term: 1 }));
+
+        let purged = op.purge_nan_inf();
+        assert_eq!(purged, FermionOperator::one());
+        assert!(!purged.has_invalid_coeffs());
+        assert_eq!(purged.check_coeffs(), Ok(()));
+
+        // simplify silently drops the NaN term but keeps the infinite one
+        assert_eq!(op.simplify(1e-8).coeffs.len(), 2);
+        op.ichop(1e-8);
+        assert_eq!(op.coeffs.len(), 2);
+    }
+
+    #[test]
+    fn test_round_coeffs() {
+        let mut op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0000001, 0.0),
+                Complex64::new(-0.1234567, 2.5e-7),
+                Complex64::new(1234.5, f64::NAN),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 1, 2],
+        };
+        let rounded = op.round_coeffs(6);
+        assert_eq!(rounded.coeffs[0], Complex64::new(1.0, 0.0));
+        assert_eq!(rounded.coeffs[1], Complex64::new(-0.123457, 0.0));
+        assert!(rounded.coeffs[2].im.is_nan());
+        assert_eq!(rounded.actions, op.actions);
+        assert_eq!(rounded.boundaries, op.boundaries);
+
+        // negative and very large numbers of decimals
+        assert_eq!(op.round_coeffs(-2).coeffs[2].re, 1200.0);
+        assert_eq!(op.round_coeffs(-400).coeffs[2].re, 0.0);
+        assert_eq!(op.round_coeffs(400).coeffs[1].re, -0.1234567);
+
+        op.round_coeffs_mut(0);
+        assert_eq!(op.coeffs[0], Complex64::new(1.0, 0.0));
+        assert_eq!(op.coeffs[2].re, 1235.0);
+    }
+
+    #[test]
+    fn test_remove_constant() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.5, 0.0),
+                Complex64::new(2.0, 1.0),
+                Complex64::new(-0.5, 0.0),
+            ],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2, 2],
+        };
+        let (traceless, constant) = op.remove_constant();
+        assert_eq!(constant, Complex64::new(1.0, 0.0));
+        assert_eq!(traceless.constant(), Complex64::new(0.0, 0.0));
+        assert_eq!(traceless.coeffs.len(), 1);
+        let reconstructed = traceless + FermionOperator::one() * constant;
+        assert!(reconstructed.simplify(0.0).equiv(&op.simplify(0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_add_constant() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(0.5, 0.0), Complex64::new(2.0, 1.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        let shifted = op.add_constant(Complex64::new(1.5, 0.0));
+        assert_eq!(shifted.constant(), Complex64::new(2.0, 0.0));
+        assert_eq!(shifted.coeffs.len(), 3);
+        assert!(shifted.__sub__(&op).simplify(0.0).equiv(
+            &FermionOperator::one().__mul__(Complex64::new(1.5, 0.0)),
+            1e-12
+        ));
+
+        let mut inplace = op.clone();
+        inplace.add_constant_mut(Complex64::new(1.5, 0.0));
+        assert_eq!(inplace, shifted);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(FermionOperator::zero().to_string(), "");
+        assert_eq!(
+            FermionOperator::one().to_string(),
+            "  1.000000e0 +0.000000e0j * ()"
+        );
+
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(0.5, -2.0)],
+            actions: vec![true, true, false, false],
+            indices: vec![0, 1, 2, 3],
+            boundaries: vec![0, 4],
+        };
+        assert_eq!(
+            op.to_string(),
+            " 5.000000e-1 -2.000000e0j * (+_0 +_1 -_2 -_3)"
+        );
+    }
+
+    #[test]
+    fn test_to_str() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(0.5, -2.0), Complex64::new(3.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2, 2],
+        };
+        assert_eq!(op.to_str(6, CoeffStyle::Scientific), op.to_string());
+        assert_eq!(
+            op.to_str(2, CoeffStyle::Fixed),
+            "    3.00   +0.00j * ()\n    0.50   -2.00j * (+_0 -_1)"
+        );
+        assert_eq!(
+            op.to_str(1, CoeffStyle::Scientific),
+            "  3.0e0 +0.0e0j * ()\n 5.0e-1 -2.0e0j * (+_0 -_1)"
+        );
+    }
+
+    #[test]
+    fn test_to_latex() {
+        assert_eq!(FermionOperator::zero().to_latex(None), "0");
+        assert_eq!(FermionOperator::one().to_latex(None), "1");
+
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(0.5, 0.0),
+                Complex64::new(-2.0, 0.0),
+                Complex64::new(1.5, -1.0),
+                Complex64::new(0.0, 3.0),
+            ],
+            actions: vec![true, true, false, false, false, true, true, false],
+            indices: vec![0, 1, 1, 0, 12, 2, 0, 1],
+            boundaries: vec![0, 4, 5, 6, 8],
+        };
+        assert_eq!(
+            op.to_latex(None),
+            "-2\\, a_{12} + 3i\\, a^\\dagger_0 a_1 + 0.5\\, a^\\dagger_0 a^\\dagger_1 a_1 a_0 \
+             + (1.5 - 1i)\\, a^\\dagger_2"
+        );
+        assert_eq!(
+            op.to_latex(Some(2)),
+            "-2\\, a_{12} + 3i\\, a^\\dagger_0 a_1 + \\cdots"
+        );
+        assert_eq!(op.to_latex(Some(4)), op.to_latex(None));
+        assert_eq!(FermionOperator::zero().to_latex(Some(0)), "0");
+        assert_eq!(op.to_latex(Some(0)), "\\cdots");
+
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, -3.0)],
+            actions: vec![false, true],
+            indices: vec![0, 1],
+            boundaries: vec![0, 1, 2],
+        };
+        assert_eq!(op.to_latex(None), "1\\, a_0 - 3i\\, a^\\dagger_1");
+    }
+
+    #[test]
+    fn test_chunks() {
+        let op = FermionOperator::random(10, 4, 4, Some(0));
+        let chunks = op.chunks(3);
+        assert_eq!(
+            chunks.iter().map(|c| c.coeffs.len()).collect::<Vec<_>>(),
+            [4, 3, 3]
+        );
+
+        let mut concatenated = FermionOperator::zero();
+        for chunk in &chunks {
+            assert_eq!(chunk.boundaries[0], 0);
+            assert_eq!(*chunk.boundaries.last().unwrap(), chunk.indices.len());
+            concatenated.__iadd__(chunk);
+        }
+        assert_eq!(concatenated, op);
+
+        let chunks = FermionOperator::one().chunks(3);
+        assert_eq!(chunks[0], FermionOperator::one());
+        assert_eq!(chunks[1], FermionOperator::zero());
+        assert_eq!(chunks[2], FermionOperator::zero());
+    }
+
+    #[test]
+    fn test_term_operators() {
+        let op = FermionOperator::random(10, 4, 4, Some(0));
+        let terms = op.term_operators();
+        assert_eq!(terms.len(), 10);
+
+        let mut sum = FermionOperator::zero();
+        for (term_op, term) in zip(&terms, op.iter()) {
+            assert_eq!(term_op.coeffs, [term.coeff]);
+            assert_eq!(term_op.iter().next(), Some(term));
+            sum.__iadd__(term_op);
+        }
+        assert_eq!(sum, op);
+
+        assert!(FermionOperator::zero().term_operators().is_empty());
+        assert_eq!(
+            FermionOperator::one().term_operators(),
+            [FermionOperator::one()]
+        );
+    }
+
+    #[test]
+    fn test_simplify_deterministic() {
+        let op = FermionOperator::random(1000, 4, 3, Some(0));
+        let first = op.simplify(1e-8);
+        let second = op.clone().simplify(1e-8);
+        assert_eq!(first, second);
+
+        let mut sorted = first.clone();
+        sorted.sort_terms();
+        assert_eq!(first, sorted);
+    }
+
+    #[test]
+    fn test_simplify_sorted() {
+        let op = FermionOperator::random(1000, 4, 3, Some(0));
+        let mut sorted = op.clone();
+        sorted.simplify_sorted(1e-8);
+        let hashed = op.simplify(1e-8);
+        assert!(sorted.equiv(&hashed, 1e-12));
+        assert_eq!(sorted.actions, hashed.actions);
+        assert_eq!(sorted.indices, hashed.indices);
+        assert_eq!(sorted.boundaries, hashed.boundaries);
+
+        // the result does not depend on the order of the input terms
+        let other = FermionOperator::random(1000, 4, 3, Some(1));
+        let mut forward = op.__add__(&other);
+        forward.simplify_sorted(1e-8);
+        let mut backward = other.__add__(&op);
+        backward.simplify_sorted(1e-8);
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_sort_terms() {
+        let mut op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(2.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(3.0, 0.0),
+                Complex64::new(1.0, 0.0),
+            ],
+            actions: vec![true, false, true, true],
+            indices: vec![1, 0, 1, 0],
+            boundaries: vec![0, 1, 2, 3, 4],
+        };
+        op.sort_terms();
+
+        let expected = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(3.0, 0.0),
+            ],
+            actions: vec![false, true, true, true],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 1, 2, 3, 4],
+        };
+        assert_eq!(op, expected);
+    }
+
+    #[test]
+    fn test_extend_terms() {
+        let source = FermionOperator::random(1000, 8, 4, Some(0));
+        let term_lengths: Vec<usize> = source.boundaries.windows(2).map(|w| w[1] - w[0]).collect();
+
+        let mut bulk = FermionOperator::one();
+        bulk.extend_terms(
+            &source.coeffs,
+            &term_lengths,
+            &source.actions,
+            &source.indices,
+        );
+
+        let mut single = FermionOperator::one();
+        for term in source.iter() {
+            single.coeffs.push(term.coeff);
+            single.actions.extend_from_slice(term.actions);
+            single.indices.extend_from_slice(term.indices);
+            single.boundaries.push(single.indices.len());
+        }
+
+        assert_eq!(bulk, single);
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of actions must equal the sum of the term lengths")]
+    fn test_extend_terms_inconsistent() {
+        let mut op = FermionOperator::zero();
+        op.extend_terms(&[Complex64::new(1.0, 0.0)], &[2], &[true], &[0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the Fock space of 31 modes exceeds the maximum of 30 modes")]
+    fn test_to_sparse_matrix_too_many_modes() {
+        FermionOperator::one().to_sparse_matrix(31);
+    }
+
+    #[test]
+    fn test_to_sparse_matrix() {
+        let number_op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        // the vanishing entry of the vacuum is omitted
+        let (rows, cols, values) = number_op.to_sparse_matrix(2);
+        assert_eq!(rows, vec![1, 2, 3]);
+        assert_eq!(cols, vec![1, 2, 3]);
+        let diagonal = number_op.fock_diagonal(2);
+        for (row, value) in zip(rows, values) {
+            assert_eq!(value, diagonal[row]);
+        }
+
+        // a†_2 a_0 picks up a sign from the occupation of mode 1
+        let hopping = FermionOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![2, 0],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(
+            hopping.to_sparse_matrix(3),
+            (
+                vec![4, 6],
+                vec![1, 3],
+                vec![Complex64::new(2.0, 0.0), Complex64::new(-2.0, 0.0)]
+            )
+        );
+    }
+
+    #[test]
+    fn test_fock_diagonal() {
+        let number_op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!(number_op.is_diagonal());
+        assert_eq!(
+            number_op.fock_diagonal(2),
+            ndarray::array![0.0, 1.0, 1.0, 2.0].mapv(|x| Complex64::new(x, 0.0))
+        );
+
+        // a†_0 a_0 a_0 a†_0 vanishes on all states and a_0 a†_0 = 1 - n_0
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false, false, true, false, true],
+            indices: vec![0, 0, 0, 0, 0, 0],
+            boundaries: vec![0, 4, 6],
+        };
+        assert_eq!(
+            op.fock_diagonal(1),
+            ndarray::array![2.0, 0.0].mapv(|x| Complex64::new(x, 0.0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the operator must be diagonal")]
+    fn test_fock_diagonal_hopping() {
+        let hopping = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        assert!(!hopping.is_diagonal());
+        hopping.fock_diagonal(2);
+    }
+
+    #[test]
+    fn test_to_1body_matrix() {
+        let norb = 2;
+        let one_body_a = Array1::from_vec(vec![1.0, 2.0, 3.0]);
+        let op = FermionOperator::from_1body_tril_spin_sym(one_body_a.view(), norb);
+        let expected = ndarray::array![
+            [1.0, 2.0, 0.0, 0.0],
+            [2.0, 3.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 2.0, 3.0],
+        ]
+        .mapv(|x| Complex64::new(x, 0.0));
+        assert_eq!(op.to_1body_matrix(2 * norb), Ok(expected));
+
+        assert_eq!(
+            op.to_1body_matrix(norb),
+            Err(OneBodyMatrixError::IndexOutOfRange { index: 2, norb })
+        );
+        let with_constant = op.add_constant(Complex64::new(1.0, 0.0));
+        assert_eq!(
+            with_constant.to_1body_matrix(2 * norb),
+            Err(OneBodyMatrixError::NotOneBody { term: 8 })
+        );
+        let annihilation_first = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![false, true],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(
+            annihilation_first.to_1body_matrix(2),
+            Err(OneBodyMatrixError::NotOneBody { term: 0 })
+        );
+    }
+
+    #[test]
+    fn test_quadratic_spectrum() {
+        let hopping = |t: Complex64| FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(-1.0, 0.0),
+                t,
+                t.conj(),
+            ],
+            actions: vec![true, false, true, false, true, false, true, false],
+            indices: vec![0, 0, 1, 1, 0, 1, 1, 0],
+            boundaries: vec![0, 2, 4, 6, 8],
+        };
+        // the eigenvalues of [[1, t], [t*, -1]] are ±sqrt(1 + |t|^2)
+        for t in [Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)] {
+            let spectrum = hopping(t).quadratic_spectrum(2).unwrap();
+            assert_eq!(spectrum.len(), 2);
+            assert!((spectrum[0] + 2f64.sqrt()).abs() < 1e-12);
+            assert!((spectrum[1] - 2f64.sqrt()).abs() < 1e-12);
+        }
+
+        // a padded orbital contributes a zero energy
+        let spectrum = hopping(Complex64::new(1.0, 0.0))
+            .quadratic_spectrum(3)
+            .unwrap();
+        assert!(spectrum[1].abs() < 1e-12);
+
+        let diagonal = FermionOperator {
+            coeffs: vec![Complex64::new(3.0, 0.0), Complex64::new(-2.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        assert_eq!(
+            diagonal.quadratic_spectrum(2).unwrap().to_vec(),
+            vec![-2.0, 3.0]
+        );
+
+        assert_eq!(
+            FermionOperator::one().quadratic_spectrum(2),
+            Err(OneBodyMatrixError::NotOneBody { term: 0 })
+        );
+    }
+
+    #[test]
+    fn test_ground_energy() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let op = FermionOperator::from(&fcidump);
+        // the known FCI ground state energy of H2 in the STO-3G basis
+        let energy = op.ground_energy(4, 100, 1e-10).unwrap();
+        assert!((energy - -1.137306035753).abs() < 1e-8);
+
+        let number = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-2.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!((number.ground_energy(2, 100, 1e-10).unwrap() - -2.0).abs() < 1e-10);
+        assert_eq!(
+            FermionOperator::zero().ground_energy(2, 100, 1e-10),
+            Ok(0.0)
+        );
 
-    #[test]
-    fn test_and() {
-        let op1 = FermionOperator {
-            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0)],
+        let non_hermitian = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
             actions: vec![true, false],
             indices: vec![0, 1],
-            boundaries: vec![0, 0, 2],
-        };
-        let op2 = FermionOperator {
-            coeffs: vec![Complex64::new(1.5, 0.0), Complex64::new(4.0, 0.0)],
-            actions: vec![true, false],
-            indices: vec![1, 0],
-            boundaries: vec![0, 0, 2],
+            boundaries: vec![0, 2],
         };
-        let result = op1 & op2;
         assert_eq!(
-            result,
-            FermionOperator {
-                coeffs: vec![
-                    Complex64::new(3.0, 0.0),
-                    Complex64::new(8.0, 0.0),
-                    Complex64::new(4.5, 0.0),
-                    Complex64::new(12.0, 0.0),
-                ],
-                actions: vec![true, false, true, false, true, false, true, false],
-                indices: vec![1, 0, 0, 1, 1, 0, 0, 1],
-                boundaries: vec![0, 0, 2, 4, 8],
-            }
+            non_hermitian.ground_energy(2, 100, 1e-10),
+            Err(GroundEnergyError::NotHermitian)
         );
+        assert!(matches!(
+            op.ground_energy(4, 1, 1e-10),
+            Err(GroundEnergyError::NotConverged { .. })
+        ));
     }
 
     #[test]
-    fn test_and_assign() {
-        let mut op1 = FermionOperator {
-            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0)],
+    fn test_symmetric_eigh() {
+        let matrix = ndarray::array![[2.0, 1.0, 0.0], [1.0, 2.0, 0.5], [0.0, 0.5, -1.0]];
+        let (eigenvalues, eigenvectors) = _symmetric_eigh(matrix.clone());
+        assert!(eigenvalues.is_sorted());
+        for (j, &eigenvalue) in eigenvalues.iter().enumerate() {
+            let vector = eigenvectors.column(j);
+            assert!((vector.dot(&vector) - 1.0).abs() < 1e-12);
+            let residual = matrix.dot(&vector) - &vector * eigenvalue;
+            assert!(residual.iter().all(|x| x.abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_expectation_determinant() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let op = FermionOperator::from(&fcidump);
+        // the spin-orbitals are blocked by spin, so the alpha and beta electrons of the
+        // Hartree-Fock state occupy the modes 0 and 2, respectively
+        let energy = op.expectation_determinant(&[0, 2], 4);
+        assert!((energy - Complex64::new(-1.116998996754, 0.0)).norm() < 1e-10);
+
+        let projector = FermionOperator::determinant_projector(4, &[0, 2]);
+        assert!((projector.expectation_determinant(&[0, 2], 4) - 1.0).norm() < 1e-12);
+        assert!(projector.expectation_determinant(&[0, 1], 4).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_map_coeffs() {
+        let mut op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 2.0), Complex64::new(-0.5, -1.0)],
             actions: vec![true, false],
             indices: vec![0, 1],
             boundaries: vec![0, 0, 2],
         };
-        let op2 = FermionOperator {
-            coeffs: vec![Complex64::new(1.5, 0.0), Complex64::new(4.0, 0.0)],
+        assert!(!op.is_real(1e-8));
+        op.map_coeffs(|c| Complex64::new(c.re, 0.0));
+        assert!(op.is_real(1e-8));
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-0.5, 0.0)],
             actions: vec![true, false],
-            indices: vec![1, 0],
+            indices: vec![0, 1],
             boundaries: vec![0, 0, 2],
         };
-        op1 &= op2;
-        assert_eq!(
-            op1,
-            FermionOperator {
-                coeffs: vec![
-                    Complex64::new(3.0, 0.0),
-                    Complex64::new(8.0, 0.0),
-                    Complex64::new(4.5, 0.0),
-                    Complex64::new(12.0, 0.0),
-                ],
-                actions: vec![true, false, true, false, true, false, true, false],
-                indices: vec![1, 0, 0, 1, 1, 0, 0, 1],
-                boundaries: vec![0, 0, 2, 4, 8],
-            }
-        );
+        assert_eq!(op, expected);
     }
 
     #[test]
-    fn test_pow() {
+    fn test_filter_terms() {
         let op = FermionOperator {
-            coeffs: vec![Complex64::new(2.0, 0.0)],
-            actions: vec![true],
-            indices: vec![0],
-            boundaries: vec![0, 1],
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.5, 0.0),
+                Complex64::new(1e-3, 0.0),
+                Complex64::new(2.0, 0.0),
+            ],
+            actions: vec![true, false, true, false, true, false],
+            indices: vec![0, 0, 0, 1, 1, 1],
+            boundaries: vec![0, 0, 2, 4, 6],
         };
-        // exponent=0
-        let one = FermionOperator::one();
-        assert_eq!(op.__pow__(0), one);
 
-        // exponent=1
-        assert_eq!(op.__pow__(1), op);
+        // keep only Hermitian diagonal (number operator) terms
+        let diagonal = op.filter_terms(|term| {
+            term.actions == [true, false] && term.indices[0] == term.indices[1]
+        });
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(0.5, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        assert_eq!(diagonal, expected);
 
-        // exponent=2
-        let squared = op.__pow__(2);
-        assert_eq!(
-            squared,
-            FermionOperator {
-                coeffs: vec![Complex64::new(4.0, 0.0)],
-                actions: vec![true, true],
-                indices: vec![0, 0],
-                boundaries: vec![0, 2],
-            }
-        );
+        // keep only terms above a coefficient threshold
+        let large = op.filter_terms(|term| term.coeff.norm() > 0.75);
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![1, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        assert_eq!(large, expected);
     }
 
     #[test]
-    fn test_ichop() {
-        let mut op = FermionOperator {
+    fn test_parity_operator() {
+        let parity = FermionOperator::parity_operator(2);
+        let squared = parity.__and__(&parity).normal_ordered().simplify(1e-10);
+        assert!(squared.equiv(&FermionOperator::one(), 1e-10));
+
+        let number = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        let comm = commutator(&parity, &number)
+            .normal_ordered()
+            .simplify(1e-10);
+        assert_eq!(comm, FermionOperator::zero());
+    }
+
+    #[test]
+    fn test_is_quadratic() {
+        let hopping = FermionOperator {
             coeffs: vec![
-                Complex64::new(1e-4, 0.0),
-                Complex64::new(1e-6, 0.0),
-                Complex64::new(1e-8, 0.0),
+                Complex64::new(-1.0, 0.0),
+                Complex64::new(-1.0, 0.0),
+                Complex64::new(0.5, 0.0),
             ],
-            actions: vec![true, false],
-            indices: vec![0, 0],
-            boundaries: vec![0, 0, 1, 2],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 1, 1, 0],
+            boundaries: vec![0, 2, 4, 4],
         };
+        assert!(hopping.is_quadratic());
 
-        op.ichop(1e-7);
-
-        let expected1 = FermionOperator {
-            coeffs: vec![Complex64::new(1e-4, 0.0), Complex64::new(1e-6, 0.0)],
-            actions: vec![true],
-            indices: vec![0],
-            boundaries: vec![0, 0, 1],
+        let hubbard = FermionOperator {
+            coeffs: vec![Complex64::new(4.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 4],
         };
+        assert!(!hubbard.is_quadratic());
+        assert!(!hopping.__add__(&hubbard).is_quadratic());
 
-        assert_eq!(op, expected1);
-
-        op.ichop(1e-5);
-
-        let expected2 = FermionOperator {
-            coeffs: vec![Complex64::new(1e-4, 0.0)],
-            actions: vec![],
-            indices: vec![],
-            boundaries: vec![0, 0],
+        // n_0 n_0 = n_0 is quadratic after normal ordering
+        let number_squared = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 0, 0],
+            boundaries: vec![0, 4],
         };
+        assert!(number_squared.is_quadratic());
 
-        assert_eq!(op, expected2);
+        let pairing = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, true],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        assert!(!pairing.is_quadratic());
     }
 
     #[test]
-    fn test_adjoint() {
-        let op1 = FermionOperator {
-            coeffs: vec![Complex64::new(0.0, 2.0), Complex64::new(3.0, 0.0)],
-            actions: vec![true, false],
-            indices: vec![0, 1],
-            boundaries: vec![0, 0, 2],
+    fn test_is_constant_and_is_identity() {
+        let one = FermionOperator::one();
+        assert!(one.is_constant(1e-8));
+        assert!(one.is_identity(1e-8));
+
+        let two = one.__mul__(Complex64::new(2.0, 0.0));
+        assert!(two.is_constant(1e-8));
+        assert!(!two.is_identity(1e-8));
+
+        let hopping = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1e-10, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 1, 1, 0],
+            boundaries: vec![0, 2, 4],
         };
-        let adj = op1.adjoint();
-        assert_eq!(
-            adj,
-            FermionOperator {
-                coeffs: vec![Complex64::new(0.0, -2.0), Complex64::new(3.0, 0.0)],
-                actions: vec![true, false],
-                indices: vec![1, 0],
-                boundaries: vec![0, 0, 2],
-            }
-        );
+        assert!(!hopping.is_constant(1e-8));
+        assert!(!hopping.is_identity(1e-8));
+        assert!(hopping.is_constant(10.0));
+
+        // a_0 a†_0 + a†_0 a_0 = 1
+        let anticommutator = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![false, true, true, false],
+            indices: vec![0, 0, 0, 0],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!(anticommutator.is_constant(1e-8));
+        assert!(anticommutator.is_identity(1e-8));
     }
 
     #[test]
-    fn test_equiv() {
-        let zero = FermionOperator::zero();
-        let op = Complex64::new(1e-8, 0.0) * FermionOperator::one();
-        assert!(op.equiv(&zero, 1e-6));
-        assert!(!op.equiv(&zero, 1e-10));
+    fn test_group_by_order() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let op = FermionOperator::from(&fcidump);
+
+        let groups = op.group_by_order();
+        assert_eq!(groups.keys().cloned().collect::<Vec<_>>(), vec![0, 2, 4]);
+        assert_eq!(groups[&0].coeffs.len(), 1);
+        assert_eq!(groups[&2].coeffs.len(), 8);
+        assert_eq!(groups[&4].coeffs.len(), 32);
+
+        let mut total = FermionOperator::zero();
+        groups.values().for_each(|group| total.__iadd__(group));
+        assert!(total.equiv(&op, 1e-12));
     }
 
     #[test]
-    fn test_normal_ordered_1() {
-        let op = FermionOperator {
+    fn test_index_range() {
+        assert_eq!(FermionOperator::zero().index_range(), None);
+        assert_eq!(FermionOperator::one().index_range(), None);
+
+        let single = FermionOperator {
             coeffs: vec![Complex64::new(1.0, 0.0)],
             actions: vec![true, false],
-            indices: vec![0, 1],
+            indices: vec![3, 3],
             boundaries: vec![0, 2],
         };
+        assert_eq!(single.index_range(), Some((3, 3)));
 
-        assert_eq!(op.normal_ordered(), op);
+        let wide = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![7, 2, ModeIndex::MAX, 0],
+            boundaries: vec![0, 2, 4],
+        };
+        assert_eq!(wide.index_range(), Some((0, ModeIndex::MAX)));
     }
 
+    #[cfg(feature = "u64")]
     #[test]
-    fn test_normal_ordered_2() {
+    fn test_u64_mode_indices() {
+        let high = ModeIndex::from(u32::MAX) + 5;
         let op = FermionOperator {
             coeffs: vec![Complex64::new(1.0, 0.0)],
-            actions: vec![true, true],
-            indices: vec![0, 1],
+            actions: vec![false, true],
+            indices: vec![high, 0],
             boundaries: vec![0, 2],
         };
+        assert_eq!(op.index_range(), Some((0, high)));
 
         let expected = FermionOperator {
             coeffs: vec![Complex64::new(-1.0, 0.0)],
-            actions: vec![true, true],
-            indices: vec![1, 0],
+            actions: vec![true, false],
+            indices: vec![0, high],
             boundaries: vec![0, 2],
         };
-
-        assert_eq!(op.normal_ordered(), expected);
+        assert!(op.normal_ordered().equiv(&expected, 1e-12));
+        assert!(op.__add__(&op.adjoint()).is_hermitian(1e-12));
     }
 
     #[test]
-    fn test_normal_ordered_3() {
+    fn test_compactify_indices() {
         let op = FermionOperator {
-            coeffs: vec![Complex64::new(1.0, 0.0)],
-            actions: vec![false, true],
-            indices: vec![0, 0],
-            boundaries: vec![0, 2],
-        };
-
-        let expected = FermionOperator {
-            coeffs: vec![Complex64::new(-1.0, 0.0), Complex64::new(1.0, 0.0)],
-            actions: vec![true, false],
-            indices: vec![0, 0],
-            boundaries: vec![0, 2, 2],
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false, true, true, false],
+            indices: vec![5, 0, 2, 5, 0],
+            boundaries: vec![0, 2, 5],
         };
+        let (compact, mapping) = op.compactify_indices();
+        assert_eq!(mapping, [0, 2, 5]);
+        assert_eq!(compact.indices, [2, 0, 1, 2, 0]);
+        assert_eq!(compact.actions, op.actions);
+        assert_eq!(compact.coeffs, op.coeffs);
+        assert_eq!(compact.boundaries, op.boundaries);
+
+        let recovered: Vec<ModeIndex> = compact
+            .indices
+            .iter()
+            .map(|i| mapping[*i as usize])
+            .collect();
+        assert_eq!(recovered, op.indices);
 
-        assert_eq!(op.normal_ordered(), expected);
+        let (one, mapping) = FermionOperator::one().compactify_indices();
+        assert_eq!(one, FermionOperator::one());
+        assert!(mapping.is_empty());
     }
 
     #[test]
-    fn test_is_hermitian() {
-        let op = FermionOperator {
-            coeffs: vec![Complex64::new(0.0, 1.00001), Complex64::new(0.0, -1.0)],
-            actions: vec![true, false, true, false],
-            indices: vec![0, 1, 1, 0],
-            boundaries: vec![0, 2, 4],
-        };
-        assert!(op.is_hermitian(1e-4));
-        assert!(!op.is_hermitian(1e-6));
+    fn test_determinant_projector() {
+        let projector = FermionOperator::determinant_projector(3, &[0, 2]);
+
+        let squared = projector
+            .__and__(&projector)
+            .normal_ordered()
+            .simplify(1e-10);
+        assert!(squared.equiv(&projector, 1e-10));
+
+        for state in 0..8_u32 {
+            let occupation: Vec<bool> = (0..3).map(|i| state & (1 << i) != 0).collect();
+            let expectation: Complex64 = projector
+                .apply_to_state(&occupation)
+                .into_iter()
+                .filter(|(_, out)| *out == occupation)
+                .map(|(coeff, _)| coeff)
+                .sum();
+            let expected = if occupation == [true, false, true] {
+                1.0
+            } else {
+                0.0
+            };
+            assert!((expectation - expected).norm() < 1e-10);
+        }
     }
 
     #[test]
-    fn test_many_body_order() {
-        assert_eq!(FermionOperator::one().many_body_order(), 0);
-
-        assert_eq!(
-            FermionOperator {
-                coeffs: vec![Complex64::new(1.0, 0.0)],
-                actions: vec![true],
-                indices: vec![0],
-                boundaries: vec![0, 1],
-            }
-            .many_body_order(),
-            1
+    fn test_from_hopping_edges() {
+        let t = Complex64::new(-1.0, 0.5);
+        let u = Complex64::new(2.0, 0.0);
+        let ring = FermionOperator::from_hopping_edges(
+            &[(0, 1, t), (1, 2, t), (2, 0, t)],
+            &[(0, u), (1, u), (2, u)],
         );
+        assert_eq!(ring.coeffs.len(), 9);
+        assert!(ring.is_hermitian(1e-12));
+        assert!(ring.is_quadratic());
+        assert_eq!(&ring.coeffs[..2], &[t, t.conj()]);
+        assert_eq!(&ring.indices[..4], &[0, 1, 1, 0]);
+
+        let matrix = ring.to_1body_matrix(3).unwrap();
+        assert_eq!(matrix[[0, 1]], t);
+        assert_eq!(matrix[[1, 0]], t.conj());
+        assert_eq!(matrix[[2, 2]], u);
 
         assert_eq!(
-            FermionOperator {
-                coeffs: vec![Complex64::new(1.0, 0.0)],
-                actions: vec![true, false],
-                indices: vec![0, 1],
-                boundaries: vec![0, 2],
-            }
-            .many_body_order(),
-            2
+            FermionOperator::from_hopping_edges(&[], &[]),
+            FermionOperator::zero()
         );
     }
 
     #[test]
-    fn test_conserves_particle_number() {
-        let op1 = FermionOperator {
-            coeffs: vec![Complex64::new(1.0, 0.0)],
+    fn test_trace_out_vacuum() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(0.5, 0.0),
+            ],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4, 4],
+        };
+
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(0.5, 0.0)],
             actions: vec![true, false],
-            indices: vec![0, 1],
-            boundaries: vec![0, 2],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2, 2],
         };
 
-        assert!(op1.conserves_particle_number());
+        assert_eq!(op.trace_out_vacuum(&[1]), expected);
+    }
 
-        let op2 = FermionOperator {
-            coeffs: vec![Complex64::new(1.0, 0.0)],
-            actions: vec![true],
-            indices: vec![0],
-            boundaries: vec![0, 1],
+    #[test]
+    fn test_trace_out_vacuum_signs() {
+        let op = FermionOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1.0, 0.0),
+            ],
+            actions: vec![false, true, true, false, true, false, false, true],
+            indices: vec![1, 0, 1, 0, 0, 1, 1, 1],
+            boundaries: vec![0, 4, 6, 8],
         };
 
-        assert!(!op2.conserves_particle_number());
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(-1.0, 0.0), Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2, 2],
+        };
+
+        assert_eq!(op.trace_out_vacuum(&[1]), expected);
     }
 }
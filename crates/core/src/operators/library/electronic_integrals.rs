@@ -10,12 +10,34 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
-use crate::operators::OperatorTrait;
 use crate::operators::fermion_operator::FermionOperator;
+use crate::operators::{ModeIndex, OperatorMacro, OperatorTrait};
 use ndarray::ArrayView1;
 use num_complex::Complex64;
+use std::fmt;
+
+/// The error returned by [`verify_s8_layout`] when the number of 2-body integrals does not
+/// match the S8-fold symmetric layout for `norb` orbitals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct S8LayoutError {
+    pub norb: ModeIndex,
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl fmt::Display for S8LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Expected {} S8-fold symmetric 2-body integrals for {} orbitals, but got {}.",
+            self.expected, self.norb, self.actual
+        )
+    }
+}
+
+impl std::error::Error for S8LayoutError {}
 
-fn _inflate_index(index: u32) -> (u32, u32) {
+fn _inflate_index(index: ModeIndex) -> (ModeIndex, ModeIndex) {
     let mut p = 0;
     while (p + 1) * (p + 2) / 2 <= index {
         p += 1;
@@ -24,13 +46,14 @@ fn _inflate_index(index: u32) -> (u32, u32) {
     (p, q)
 }
 
-fn _expand_s4_index(iajb: u32, npair: u32) -> Vec<(u32, u32, u32, u32)> {
-    let ia = iajb / npair;
-    let jb = iajb % npair;
-
-    let (i, a) = _inflate_index(ia);
-    let (j, b) = _inflate_index(jb);
-
+/// Returns the distinct index tuples obtained from `(i, a, j, b)` by swapping `i` with `a` and
+/// `j` with `b`, assuming `i >= a` and `j >= b`.
+fn _expand_pair_swaps(
+    i: ModeIndex,
+    a: ModeIndex,
+    j: ModeIndex,
+    b: ModeIndex,
+) -> Vec<(ModeIndex, ModeIndex, ModeIndex, ModeIndex)> {
     let mut res = vec![(i, a, j, b)];
     if i > a {
         res.push((a, i, j, b));
@@ -44,51 +67,107 @@ fn _expand_s4_index(iajb: u32, npair: u32) -> Vec<(u32, u32, u32, u32)> {
     res
 }
 
-fn _expand_s8_index(iajb: u32) -> Vec<(u32, u32, u32, u32)> {
+fn _expand_s4_index(
+    iajb: ModeIndex,
+    npair: ModeIndex,
+) -> Vec<(ModeIndex, ModeIndex, ModeIndex, ModeIndex)> {
+    let ia = iajb / npair;
+    let jb = iajb % npair;
+
+    let (i, a) = _inflate_index(ia);
+    let (j, b) = _inflate_index(jb);
+
+    _expand_pair_swaps(i, a, j, b)
+}
+
+fn _expand_s8_index(iajb: ModeIndex) -> Vec<(ModeIndex, ModeIndex, ModeIndex, ModeIndex)> {
     let (ia, jb) = _inflate_index(iajb);
-    let (mut i, mut a) = _inflate_index(ia);
-    let (mut j, mut b) = _inflate_index(jb);
+    let (i, a) = _inflate_index(ia);
+    let (j, b) = _inflate_index(jb);
 
-    let mut res = vec![(i, a, j, b)];
-    if i > a {
-        res.push((a, i, j, b));
-    }
-    if j > b {
-        res.push((i, a, b, j));
+    let mut res = _expand_pair_swaps(i, a, j, b);
+    if ia > jb {
+        res.extend(_expand_pair_swaps(j, b, i, a));
     }
-    if i > a && j > b {
-        res.push((a, i, b, j));
+    res
+}
+
+/// Checks that the length of `two_body_aa` matches the S8-fold symmetric layout for `norb`
+/// orbitals, as expected by [`From2Body::from_2body_tril_spin_sym`].
+///
+/// This is a layout check only, which does not compare any values. The S8-fold symmetric layout
+/// stores every unique integral exactly once, so it cannot encode integrals which lack this
+/// symmetry. Instead, such data (for example S4-fold symmetric integrals with `npair * npair`
+/// entries) is detected by its length, which must be `npair * (npair + 1) / 2` with
+/// `npair = norb * (norb + 1) / 2`. Otherwise, surplus entries are silently expanded onto
+/// orbitals beyond `norb`, which alias the beta-spin orbitals, and missing entries are silently
+/// treated as zero.
+pub fn verify_s8_layout(
+    two_body_aa: ArrayView1<f64>,
+    norb: ModeIndex,
+) -> Result<(), S8LayoutError> {
+    let norb_usize = norb as usize;
+    let npair = norb_usize * (norb_usize + 1) / 2;
+    let expected = npair * (npair + 1) / 2;
+    if two_body_aa.len() == expected {
+        Ok(())
+    } else {
+        Err(S8LayoutError {
+            norb,
+            expected,
+            actual: two_body_aa.len(),
+        })
     }
-    if ia > jb {
-        (i, a, j, b) = (j, b, i, a);
-        res.push((i, a, j, b));
-        if i > a {
-            res.push((a, i, j, b));
-        }
-        if j > b {
-            res.push((i, a, b, j));
+}
+
+/// The convention by which spin orbitals are indexed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpinOrdering {
+    /// The `norb` alpha-spin orbitals occupy `[0, norb)` and the beta-spin ones `[norb, 2 * norb)`.
+    Block,
+    /// Alpha-spin (beta-spin) orbitals occupy the even (odd) indices.
+    Interleaved,
+}
+
+impl SpinOrdering {
+    /// Returns the spin-orbital index of spatial orbital `orb` of the given spin species.
+    #[inline]
+    pub fn spin_orbital(&self, orb: ModeIndex, beta: bool, norb: ModeIndex) -> ModeIndex {
+        match self {
+            Self::Block => orb + norb * (beta as ModeIndex),
+            Self::Interleaved => 2 * orb + (beta as ModeIndex),
         }
-        if i > a && j > b {
-            res.push((a, i, b, j));
+    }
+
+    /// Returns the spatial orbital and spin species (`true` for beta) of spin-orbital `index`.
+    #[inline]
+    pub fn spatial_orbital(&self, index: ModeIndex, norb: ModeIndex) -> (ModeIndex, bool) {
+        match self {
+            Self::Block => (index % norb, index >= norb),
+            Self::Interleaved => (index / 2, index % 2 == 1),
         }
     }
-    res
 }
 
 pub trait From1Body {
-    fn add_1body_tril_spin_sym(&mut self, one_body_a: ArrayView1<f64>, norb: u32);
+    fn add_1body_tril_spin_sym(&mut self, one_body_a: ArrayView1<f64>, norb: ModeIndex);
     fn add_1body_tril_spin(
         &mut self,
         one_body_a: ArrayView1<f64>,
         one_body_b: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
     );
 
-    fn from_1body_tril_spin_sym(one_body_a: ArrayView1<f64>, norb: u32) -> Self;
+    fn from_1body_tril_spin_sym(one_body_a: ArrayView1<f64>, norb: ModeIndex) -> Self;
+    fn from_1body_tril_spin_sym_ordered(
+        one_body_a: ArrayView1<f64>,
+        norb: ModeIndex,
+        ordering: SpinOrdering,
+    ) -> Self;
     fn from_1body_tril_spin(
         one_body_a: ArrayView1<f64>,
         one_body_b: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
     ) -> Self;
 
     // TODO:
@@ -100,7 +179,7 @@ pub trait From1Body {
 
 impl FermionOperator {
     #[inline]
-    fn _insert_1body_idx(op: &mut Self, c: Complex64, i: u32, a: u32) {
+    fn _insert_1body_idx(op: &mut Self, c: Complex64, i: ModeIndex, a: ModeIndex) {
         op.coeffs.push(c);
         op.actions.push(true);
         op.actions.push(false);
@@ -118,7 +197,14 @@ impl FermionOperator {
     }
 
     #[inline]
-    fn _insert_2body_idx(op: &mut Self, c: Complex64, i: u32, j: u32, b: u32, a: u32) {
+    fn _insert_2body_idx(
+        op: &mut Self,
+        c: Complex64,
+        i: ModeIndex,
+        j: ModeIndex,
+        b: ModeIndex,
+        a: ModeIndex,
+    ) {
         op.coeffs.push(c);
         op.actions.push(true);
         op.actions.push(true);
@@ -130,38 +216,119 @@ impl FermionOperator {
         op.indices.push(a);
         op.boundaries.push(op.indices.len());
     }
+
+    /// Converts the spin-orbital indices of this operator acting on `2 * norb` modes from the
+    /// `from` ordering convention into the `to` one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this operator acts on an index outside of `0..2 * norb`, even if `from` and `to`
+    /// are the same convention.
+    pub fn reorder_spin(&self, norb: ModeIndex, from: SpinOrdering, to: SpinOrdering) -> Self {
+        if let Some(&index) = self.indices.iter().find(|&&index| index >= 2 * norb) {
+            panic!("index {index} is out of range for {norb} spatial orbitals");
+        }
+        if from == to {
+            return self.clone();
+        }
+        let indices = self
+            .indices
+            .iter()
+            .map(|&index| {
+                let (orb, beta) = from.spatial_orbital(index, norb);
+                to.spin_orbital(orb, beta, norb)
+            })
+            .collect();
+        Self {
+            coeffs: self.coeffs.clone(),
+            actions: self.actions.clone(),
+            indices,
+            boundaries: self.boundaries.clone(),
+        }
+    }
+
+    /// Places this operator, acting on the `norb` spatial orbitals of a single spin species, into
+    /// the beta-spin block of the block spin ordering by shifting all of its indices by `norb`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this operator acts on an index outside of `0..norb`.
+    pub fn to_beta_block(&self, norb: ModeIndex) -> Self {
+        Self {
+            coeffs: self.coeffs.clone(),
+            actions: self.actions.clone(),
+            indices: self
+                .indices
+                .iter()
+                .map(|&index| {
+                    assert!(
+                        index < norb,
+                        "index {index} is out of range for {norb} spatial orbitals"
+                    );
+                    index + norb
+                })
+                .collect(),
+            boundaries: self.boundaries.clone(),
+        }
+    }
+
+    /// Returns the sum of `alpha` and `beta`, each acting on the `norb` spatial orbitals of their
+    /// spin species, as an operator on `2 * norb` spin orbitals in the block spin ordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` or `beta` act on an index outside of `0..norb`.
+    pub fn combine_spin_blocks(alpha: &Self, beta: &Self, norb: ModeIndex) -> Self {
+        assert!(
+            alpha.indices.iter().all(|&index| index < norb),
+            "the alpha-spin operator acts outside of {norb} spatial orbitals"
+        );
+        alpha.__add__(&beta.to_beta_block(norb))
+    }
 }
 
 impl From1Body for FermionOperator {
-    fn add_1body_tril_spin_sym(&mut self, one_body_a: ArrayView1<f64>, norb: u32) {
+    fn add_1body_tril_spin_sym(&mut self, one_body_a: ArrayView1<f64>, norb: ModeIndex) {
         one_body_a
             .indexed_iter()
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(ia, &coeff)| {
-                let (i, a) = _inflate_index(ia as u32);
+                let (i, a) = _inflate_index(ia as ModeIndex);
                 let c = Complex64::new(coeff, 0.0);
                 Self::_insert_1body_idx(self, c, i, a);
                 Self::_insert_1body_idx(self, c, i + norb, a + norb);
             });
     }
 
-    fn from_1body_tril_spin_sym(one_body_a: ArrayView1<f64>, norb: u32) -> Self {
+    fn from_1body_tril_spin_sym(one_body_a: ArrayView1<f64>, norb: ModeIndex) -> Self {
         let mut op = Self::zero();
         op.add_1body_tril_spin_sym(one_body_a, norb);
         op
     }
 
+    fn from_1body_tril_spin_sym_ordered(
+        one_body_a: ArrayView1<f64>,
+        norb: ModeIndex,
+        ordering: SpinOrdering,
+    ) -> Self {
+        Self::from_1body_tril_spin_sym(one_body_a, norb).reorder_spin(
+            norb,
+            SpinOrdering::Block,
+            ordering,
+        )
+    }
+
     fn add_1body_tril_spin(
         &mut self,
         one_body_a: ArrayView1<f64>,
         one_body_b: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
     ) {
         one_body_a
             .indexed_iter()
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(ia, &coeff)| {
-                let (i, a) = _inflate_index(ia as u32);
+                let (i, a) = _inflate_index(ia as ModeIndex);
                 let c = Complex64::new(coeff, 0.0);
                 Self::_insert_1body_idx(self, c, i, a);
             });
@@ -170,7 +337,7 @@ impl From1Body for FermionOperator {
             .indexed_iter()
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(ia, &coeff)| {
-                let (i, a) = _inflate_index(ia as u32);
+                let (i, a) = _inflate_index(ia as ModeIndex);
                 let c = Complex64::new(coeff, 0.0);
                 Self::_insert_1body_idx(self, c, i + norb, a + norb);
             });
@@ -179,7 +346,7 @@ impl From1Body for FermionOperator {
     fn from_1body_tril_spin(
         one_body_a: ArrayView1<f64>,
         one_body_b: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
     ) -> Self {
         let mut op = Self::zero();
         op.add_1body_tril_spin(one_body_a, one_body_b, norb);
@@ -187,22 +354,37 @@ impl From1Body for FermionOperator {
     }
 }
 
+/// Constructors of 2-body operators from electronic integrals.
+///
+/// When `drop_vanishing` is set, terms of the form `a†_i a†_i a_l a_j` or `a†_i a†_k a_l a_l`,
+/// which vanish identically by the Pauli exclusion principle, are skipped during construction.
 pub trait From2Body {
-    fn add_2body_tril_spin_sym(&mut self, two_body_aa: ArrayView1<f64>, norb: u32);
+    fn add_2body_tril_spin_sym(
+        &mut self,
+        two_body_aa: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    );
     fn add_2body_tril_spin(
         &mut self,
         two_body_aa: ArrayView1<f64>,
         two_body_ab: ArrayView1<f64>,
         two_body_bb: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
+        drop_vanishing: bool,
     );
 
-    fn from_2body_tril_spin_sym(two_body_aa: ArrayView1<f64>, norb: u32) -> Self;
+    fn from_2body_tril_spin_sym(
+        two_body_aa: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) -> Self;
     fn from_2body_tril_spin(
         two_body_aa: ArrayView1<f64>,
         two_body_ab: ArrayView1<f64>,
         two_body_bb: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
+        drop_vanishing: bool,
     ) -> Self;
 
     // TODO:
@@ -213,26 +395,40 @@ pub trait From2Body {
 }
 
 impl From2Body for FermionOperator {
-    fn add_2body_tril_spin_sym(&mut self, two_body_aa: ArrayView1<f64>, norb: u32) {
+    fn add_2body_tril_spin_sym(
+        &mut self,
+        two_body_aa: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) {
+        let mut insert = |c, i, j, b, a| {
+            if !(drop_vanishing && (i == j || b == a)) {
+                Self::_insert_2body_idx(self, c, i, j, b, a);
+            }
+        };
         two_body_aa
             .indexed_iter()
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(iajb, &coeff)| {
                 let c = Complex64::new(0.5 * coeff, 0.0);
-                _expand_s8_index(iajb as u32)
+                _expand_s8_index(iajb as ModeIndex)
                     .iter()
                     .for_each(|&(i, a, j, b)| {
-                        Self::_insert_2body_idx(self, c, i, j, b, a);
-                        Self::_insert_2body_idx(self, c, i + norb, j, b, a + norb);
-                        Self::_insert_2body_idx(self, c, i, j + norb, b + norb, a);
-                        Self::_insert_2body_idx(self, c, i + norb, j + norb, b + norb, a + norb);
+                        insert(c, i, j, b, a);
+                        insert(c, i + norb, j, b, a + norb);
+                        insert(c, i, j + norb, b + norb, a);
+                        insert(c, i + norb, j + norb, b + norb, a + norb);
                     });
             });
     }
 
-    fn from_2body_tril_spin_sym(two_body_aa: ArrayView1<f64>, norb: u32) -> Self {
+    fn from_2body_tril_spin_sym(
+        two_body_aa: ArrayView1<f64>,
+        norb: ModeIndex,
+        drop_vanishing: bool,
+    ) -> Self {
         let mut op = Self::zero();
-        op.add_2body_tril_spin_sym(two_body_aa, norb);
+        op.add_2body_tril_spin_sym(two_body_aa, norb, drop_vanishing);
         op
     }
 
@@ -241,17 +437,23 @@ impl From2Body for FermionOperator {
         two_body_aa: ArrayView1<f64>,
         two_body_ab: ArrayView1<f64>,
         two_body_bb: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
+        drop_vanishing: bool,
     ) {
+        let mut insert = |c, i, j, b, a| {
+            if !(drop_vanishing && (i == j || b == a)) {
+                Self::_insert_2body_idx(self, c, i, j, b, a);
+            }
+        };
         two_body_aa
             .indexed_iter()
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(iajb, &coeff)| {
                 let c = Complex64::new(0.5 * coeff, 0.0);
-                _expand_s8_index(iajb as u32)
+                _expand_s8_index(iajb as ModeIndex)
                     .iter()
                     .for_each(|&(i, a, j, b)| {
-                        Self::_insert_2body_idx(self, c, i, j, b, a);
+                        insert(c, i, j, b, a);
                     });
             });
 
@@ -261,11 +463,11 @@ impl From2Body for FermionOperator {
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(iajb, &coeff)| {
                 let c = Complex64::new(0.5 * coeff, 0.0);
-                _expand_s4_index(iajb as u32, npair)
+                _expand_s4_index(iajb as ModeIndex, npair)
                     .iter()
                     .for_each(|&(i, a, j, b)| {
-                        Self::_insert_2body_idx(self, c, i, j + norb, b + norb, a);
-                        Self::_insert_2body_idx(self, c, j + norb, i, a, b + norb);
+                        insert(c, i, j + norb, b + norb, a);
+                        insert(c, j + norb, i, a, b + norb);
                     });
             });
 
@@ -274,10 +476,10 @@ impl From2Body for FermionOperator {
             .filter(|&(_, coeff)| coeff.abs() > 0.0)
             .for_each(|(iajb, &coeff)| {
                 let c = Complex64::new(0.5 * coeff, 0.0);
-                _expand_s8_index(iajb as u32)
+                _expand_s8_index(iajb as ModeIndex)
                     .iter()
                     .for_each(|&(i, a, j, b)| {
-                        Self::_insert_2body_idx(self, c, i + norb, j + norb, b + norb, a + norb);
+                        insert(c, i + norb, j + norb, b + norb, a + norb);
                     });
             });
     }
@@ -286,10 +488,11 @@ impl From2Body for FermionOperator {
         two_body_aa: ArrayView1<f64>,
         two_body_ab: ArrayView1<f64>,
         two_body_bb: ArrayView1<f64>,
-        norb: u32,
+        norb: ModeIndex,
+        drop_vanishing: bool,
     ) -> Self {
         let mut op = Self::zero();
-        op.add_2body_tril_spin(two_body_aa, two_body_ab, two_body_bb, norb);
+        op.add_2body_tril_spin(two_body_aa, two_body_ab, two_body_bb, norb, drop_vanishing);
         op
     }
 }
@@ -300,6 +503,70 @@ mod tests {
     use num_complex::Complex64;
 
     use super::*;
+    use crate::operators::library::fcidump::FCIDump;
+    use std::collections::HashSet;
+
+    fn _tril_index(p: ModeIndex, q: ModeIndex) -> ModeIndex {
+        let (p, q) = if p >= q { (p, q) } else { (q, p) };
+        p * (p + 1) / 2 + q
+    }
+
+    fn _all_indices(
+        norb: ModeIndex,
+    ) -> impl Iterator<Item = (ModeIndex, ModeIndex, ModeIndex, ModeIndex)> {
+        (0..norb.pow(4)).map(move |n| {
+            (
+                n / norb.pow(3),
+                n / norb.pow(2) % norb,
+                n / norb % norb,
+                n % norb,
+            )
+        })
+    }
+
+    #[test]
+    fn test_expand_s4_index() {
+        let norb = 3;
+        let npair = norb * (norb + 1) / 2;
+        let mut seen = HashSet::new();
+        for iajb in 0..npair * npair {
+            let expanded = _expand_s4_index(iajb, npair);
+            let unique: HashSet<_> = expanded.iter().cloned().collect();
+            assert_eq!(unique.len(), expanded.len());
+
+            let mut reference = HashSet::new();
+            for (i, a, j, b) in _all_indices(norb) {
+                if _tril_index(i, a) * npair + _tril_index(j, b) == iajb {
+                    reference.insert((i, a, j, b));
+                }
+            }
+            assert_eq!(unique, reference, "mismatch for S4 index {iajb}");
+            seen.extend(unique);
+        }
+        assert_eq!(seen.len(), norb.pow(4) as usize);
+    }
+
+    #[test]
+    fn test_expand_s8_index() {
+        let norb = 3;
+        let npair = norb * (norb + 1) / 2;
+        let mut seen = HashSet::new();
+        for iajb in 0..npair * (npair + 1) / 2 {
+            let expanded = _expand_s8_index(iajb);
+            let unique: HashSet<_> = expanded.iter().cloned().collect();
+            assert_eq!(unique.len(), expanded.len());
+
+            let mut reference = HashSet::new();
+            for (i, a, j, b) in _all_indices(norb) {
+                if _tril_index(_tril_index(i, a), _tril_index(j, b)) == iajb {
+                    reference.insert((i, a, j, b));
+                }
+            }
+            assert_eq!(unique, reference, "mismatch for S8 index {iajb}");
+            seen.extend(unique);
+        }
+        assert_eq!(seen.len(), norb.pow(4) as usize);
+    }
 
     #[test]
     fn test_1body_tril_spin_sym() {
@@ -321,6 +588,111 @@ mod tests {
         assert_eq!(op, expected);
     }
 
+    #[test]
+    fn test_1body_tril_spin_sym_ordered() {
+        let norb = 2;
+        let one_body_a = Array1::from_iter((1..4).map(f64::from));
+
+        let block = FermionOperator::from_1body_tril_spin_sym_ordered(
+            ArrayView1::from(&one_body_a),
+            norb,
+            SpinOrdering::Block,
+        );
+        assert_eq!(
+            block,
+            FermionOperator::from_1body_tril_spin_sym(ArrayView1::from(&one_body_a), norb)
+        );
+
+        let interleaved = FermionOperator::from_1body_tril_spin_sym_ordered(
+            ArrayView1::from(&one_body_a),
+            norb,
+            SpinOrdering::Interleaved,
+        );
+
+        let expected = FermionOperator {
+            coeffs: [1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 3.0, 3.0]
+                .iter()
+                .map(|c| Complex64::new(*c, 0.0))
+                .collect(),
+            actions: [true, false].iter().cloned().cycle().take(16).collect(),
+            indices: vec![0, 0, 1, 1, 2, 0, 0, 2, 3, 1, 1, 3, 2, 2, 3, 3],
+            boundaries: vec![0, 2, 4, 6, 8, 10, 12, 14, 16],
+        };
+
+        assert_eq!(interleaved, expected);
+    }
+
+    #[test]
+    fn test_reorder_spin() {
+        let fcidump = FCIDump::from_file(String::from("../../tests/h2.fcidump"));
+        let block = FermionOperator::from(&fcidump);
+
+        let interleaved = block.reorder_spin(
+            fcidump.norb as ModeIndex,
+            SpinOrdering::Block,
+            SpinOrdering::Interleaved,
+        );
+        assert_ne!(interleaved, block);
+        assert_eq!(
+            interleaved.reorder_spin(
+                fcidump.norb as ModeIndex,
+                SpinOrdering::Interleaved,
+                SpinOrdering::Block
+            ),
+            block
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 is out of range for 2 spatial orbitals")]
+    fn test_reorder_spin_same_ordering_out_of_range() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![4, 0],
+            boundaries: vec![0, 2],
+        };
+        op.reorder_spin(2, SpinOrdering::Block, SpinOrdering::Block);
+    }
+
+    #[test]
+    fn test_combine_spin_blocks() {
+        // alpha: n_0, beta: 2 n_0
+        let alpha = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 0],
+            boundaries: vec![0, 2],
+        };
+        let beta = alpha.__mul__(Complex64::new(2.0, 0.0));
+
+        let shifted = beta.to_beta_block(1);
+        assert_eq!(shifted.indices, vec![1, 1]);
+        assert_eq!(shifted.coeffs, beta.coeffs);
+
+        let combined = FermionOperator::combine_spin_blocks(&alpha, &beta, 1);
+        let expected = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            actions: vec![true, false, true, false],
+            indices: vec![0, 0, 1, 1],
+            boundaries: vec![0, 2, 4],
+        };
+        assert_eq!(combined, expected);
+        assert!(combined.conserves_spin_numbers(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 is out of range for 1 spatial orbitals")]
+    fn test_to_beta_block_out_of_range() {
+        let op = FermionOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            actions: vec![true, false],
+            indices: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        op.to_beta_block(1);
+    }
+
     #[test]
     fn test_1body_tril_spin() {
         let norb = 2;
@@ -351,7 +723,8 @@ mod tests {
         let norb = 2;
         let two_body_aa = Array1::from_iter((1..7).map(|i| f64::from(i)));
 
-        let op = FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa), norb);
+        let op =
+            FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa), norb, false);
 
         let expected = FermionOperator {
             coeffs: vec![
@@ -399,6 +772,7 @@ mod tests {
             ArrayView1::from(&two_body_ab),
             ArrayView1::from(&two_body_bb),
             norb,
+            false,
         );
 
         let expected = FermionOperator {
@@ -435,4 +809,74 @@ mod tests {
 
         assert_eq!(op, expected);
     }
+
+    #[test]
+    fn test_verify_s8_layout() {
+        let norb = 2;
+        let two_body_aa = Array1::from_iter((1..7).map(f64::from));
+        assert_eq!(
+            verify_s8_layout(ArrayView1::from(&two_body_aa), norb),
+            Ok(())
+        );
+
+        // S4-fold symmetric integrals, in which (ia|jb) and (jb|ia) differ
+        let asymmetric = Array1::from_iter((11..20).map(f64::from));
+        assert_eq!(
+            verify_s8_layout(ArrayView1::from(&asymmetric), norb),
+            Err(S8LayoutError {
+                norb,
+                expected: 6,
+                actual: 9
+            })
+        );
+        // without the check, the surplus integrals end up on the beta-spin orbitals
+        let op =
+            FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&asymmetric), norb, false);
+        assert!(!op.conserves_spin_numbers(norb));
+
+        assert!(verify_s8_layout(two_body_aa.slice(ndarray::s![..5]), norb).is_err());
+        assert_eq!(verify_s8_layout(ArrayView1::from(&[1.0]), 1), Ok(()));
+    }
+
+    #[test]
+    fn test_2body_drop_vanishing() {
+        let norb = 2;
+        let two_body_aa = Array1::from_iter((1..7).map(f64::from));
+        let two_body_ab = Array1::from_iter((11..20).map(f64::from));
+        let two_body_bb = Array1::from_iter((1..7).map(|i| f64::from(-i)));
+
+        let full = FermionOperator::from_2body_tril_spin(
+            ArrayView1::from(&two_body_aa),
+            ArrayView1::from(&two_body_ab),
+            ArrayView1::from(&two_body_bb),
+            norb,
+            false,
+        );
+        let dropped = FermionOperator::from_2body_tril_spin(
+            ArrayView1::from(&two_body_aa),
+            ArrayView1::from(&two_body_ab),
+            ArrayView1::from(&two_body_bb),
+            norb,
+            true,
+        );
+
+        assert!(dropped.coeffs.len() < full.coeffs.len());
+        assert!(
+            dropped.iter().all(
+                |term| term.indices[0] != term.indices[1] && term.indices[2] != term.indices[3]
+            )
+        );
+        assert_eq!(dropped, full.drop_vanishing_terms());
+        assert!(
+            dropped
+                .normal_ordered()
+                .equiv(&full.normal_ordered(), 1e-12)
+        );
+
+        let full =
+            FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa), norb, false);
+        let dropped =
+            FermionOperator::from_2body_tril_spin_sym(ArrayView1::from(&two_body_aa), norb, true);
+        assert_eq!(dropped, full.drop_vanishing_terms());
+    }
 }
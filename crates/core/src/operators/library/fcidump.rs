@@ -10,9 +10,9 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
-use crate::operators::OperatorTrait;
 use crate::operators::fermion_operator::FermionOperator;
 use crate::operators::library::electronic_integrals::{From1Body, From2Body};
+use crate::operators::{ModeIndex, OperatorTrait};
 use ndarray::{Array1, ArrayView1};
 use num_complex::Complex64;
 use regex::{Captures, Regex};
@@ -86,6 +86,9 @@ impl FCIDump {
         let integral_line = Regex::new(r"(.+)\s+(\d+)\s+(\d+)\s+(\d+)\s+(\d+)").unwrap();
 
         let mut beta_present: bool = false;
+        // tracks which spin blocks received at least one integral
+        let (mut seen_a, mut seen_b) = (false, false);
+        let (mut seen_aa, mut seen_ab, mut seen_bb) = (false, false, false);
         let mut constant: Option<f64> = None;
         let mut one_body_a = Array1::<f64>::zeros(npair);
         let mut one_body_b = Array1::<f64>::zeros(npair);
@@ -102,6 +105,9 @@ impl FCIDump {
             let a = unwrap_cap(&integral, 3).parse::<usize>().unwrap();
             let j = unwrap_cap(&integral, 4).parse::<usize>().unwrap();
             let b = unwrap_cap(&integral, 5).parse::<usize>().unwrap();
+            if let Some(index) = [i, a, j, b].into_iter().find(|&index| index > 2 * norb) {
+                panic!("Integral index {index} exceeds twice the number of orbitals ({norb})!");
+            }
 
             match (i, a, j, b) {
                 (0, 0, 0, 0) => constant = Some(coeff),
@@ -110,11 +116,13 @@ impl FCIDump {
                     if _i < _a {
                         (_i, _a) = (_a, _i);
                     }
-                    if (_i, _a) < (norb, norb) {
+                    if !is_beta_pair(_i, _a, norb) {
                         let _ia = _i * (_i + 1) / 2 + _a;
                         one_body_a[_ia] = coeff;
+                        seen_a = true;
                     } else {
                         beta_present = true;
+                        seen_b = true;
                         let _ia = (_i - norb) * (_i - norb + 1) / 2 + (_a - norb);
                         one_body_b[_ia] = coeff;
                     }
@@ -128,7 +136,13 @@ impl FCIDump {
                     if _j < _b {
                         (_j, _b) = (_b, _j);
                     }
-                    if (_j, _b) < (norb, norb) {
+                    let ia_beta = is_beta_pair(_i, _a, norb);
+                    let jb_beta = is_beta_pair(_j, _b, norb);
+                    if ia_beta && !jb_beta {
+                        // (ia|jb) = (jb|ia) allows listing the alpha pair first
+                        (_i, _a, _j, _b) = (_j, _b, _i, _a);
+                    }
+                    if !ia_beta && !jb_beta {
                         let mut _ia = _i * (_i + 1) / 2 + _a;
                         let mut _jb = _j * (_j + 1) / 2 + _b;
                         if _ia < _jb {
@@ -136,13 +150,15 @@ impl FCIDump {
                         }
                         let _iajb = _ia * (_ia + 1) / 2 + _jb;
                         two_body_aa[_iajb] = coeff;
+                        seen_aa = true;
                     } else {
                         beta_present = true;
-                        if (_i, _a) < (norb, norb) {
+                        if ia_beta != jb_beta {
                             let mut _ia = _i * (_i + 1) / 2 + _a;
                             let mut _jb = (_j - norb) * (_j - norb + 1) / 2 + (_b - norb);
                             let _iajb = _ia * npair + _jb;
                             two_body_ab[_iajb] = coeff;
+                            seen_ab = true;
                         } else {
                             let mut _ia = (_i - norb) * (_i - norb + 1) / 2 + (_a - norb);
                             let mut _jb = (_j - norb) * (_j - norb + 1) / 2 + (_b - norb);
@@ -151,12 +167,32 @@ impl FCIDump {
                             }
                             let _iajb = _ia * (_ia + 1) / 2 + _jb;
                             two_body_bb[_iajb] = coeff;
+                            seen_bb = true;
                         }
                     }
                 }
             }
         }
 
+        if beta_present {
+            // an unrestricted file must provide both spin species for every kind of integral,
+            // otherwise the missing block would silently be treated as zero
+            assert_eq!(
+                seen_a, seen_b,
+                "Unrestricted FCIDump contains one-body integrals for only one spin species!"
+            );
+            assert_eq!(
+                seen_aa, seen_bb,
+                "Unrestricted FCIDump contains same-spin two-body integrals for only one spin \
+                 species!"
+            );
+            assert!(
+                seen_aa || !seen_ab,
+                "Unrestricted FCIDump contains alpha-beta two-body integrals without any \
+                 same-spin ones!"
+            );
+        }
+
         Self {
             norb: norb as u32,
             nelec: nelec as u32,
@@ -180,10 +216,54 @@ impl FCIDump {
     }
 }
 
+/// Returns whether both (0-based) indices of a pair belong to the beta-spin block.
+///
+/// # Panics
+///
+/// Panics if the indices straddle the boundary between the alpha- and beta-spin blocks.
+fn is_beta_pair(p: usize, q: usize, norb: usize) -> bool {
+    match (p < norb, q < norb) {
+        (true, true) => false,
+        (false, false) => true,
+        _ => panic!(
+            "Integral indices {} and {} straddle the alpha/beta spin block boundary!",
+            p + 1,
+            q + 1
+        ),
+    }
+}
+
+/// Returns upper bounds on the numbers of 1- and 2-body terms of the operator constructed from
+/// `fcidump`, based on the number of terms into which every non-zero integral expands at most.
+fn _max_num_terms(fcidump: &FCIDump) -> (usize, usize) {
+    let nnz = |integrals: &Array1<f64>| integrals.iter().filter(|c| c.abs() > 0.0).count();
+    match (
+        &fcidump.one_body_b,
+        &fcidump.two_body_ab,
+        &fcidump.two_body_bb,
+    ) {
+        (Some(one_body_b), Some(two_body_ab), Some(two_body_bb)) => (
+            2 * (nnz(&fcidump.one_body_a) + nnz(one_body_b)),
+            8 * (nnz(&fcidump.two_body_aa) + nnz(two_body_ab) + nnz(two_body_bb)),
+        ),
+        _ => (4 * nnz(&fcidump.one_body_a), 32 * nnz(&fcidump.two_body_aa)),
+    }
+}
+
 impl From<&FCIDump> for FermionOperator {
     fn from(fcidump: &FCIDump) -> Self {
         let mut op = Self::zero();
 
+        // PERF: reserving the upper bounds avoids repeated reallocations. They are tight except
+        // for the integrals with repeated indices, whose share shrinks with the number of orbitals.
+        let (num_1body, num_2body) = _max_num_terms(fcidump);
+        let num_terms = 1 + num_1body + num_2body;
+        let num_actions = 2 * num_1body + 4 * num_2body;
+        op.coeffs.reserve(num_terms);
+        op.actions.reserve(num_actions);
+        op.indices.reserve(num_actions);
+        op.boundaries.reserve(num_terms);
+
         if let Some(coeff) = fcidump.constant {
             op.coeffs.push(Complex64::new(coeff, 0.0));
             op.boundaries.push(op.boundaries.len() - 1);
@@ -194,18 +274,26 @@ impl From<&FCIDump> for FermionOperator {
                 op.add_1body_tril_spin(
                     ArrayView1::from(&fcidump.one_body_a),
                     ArrayView1::from(fcidump.one_body_b.as_ref().unwrap()),
-                    fcidump.norb,
+                    fcidump.norb as ModeIndex,
                 );
                 op.add_2body_tril_spin(
                     ArrayView1::from(&fcidump.two_body_aa),
                     ArrayView1::from(fcidump.two_body_ab.as_ref().unwrap()),
                     ArrayView1::from(fcidump.two_body_bb.as_ref().unwrap()),
-                    fcidump.norb,
+                    fcidump.norb as ModeIndex,
+                    false,
                 );
             }
             None => {
-                op.add_1body_tril_spin_sym(ArrayView1::from(&fcidump.one_body_a), fcidump.norb);
-                op.add_2body_tril_spin_sym(ArrayView1::from(&fcidump.two_body_aa), fcidump.norb);
+                op.add_1body_tril_spin_sym(
+                    ArrayView1::from(&fcidump.one_body_a),
+                    fcidump.norb as ModeIndex,
+                );
+                op.add_2body_tril_spin_sym(
+                    ArrayView1::from(&fcidump.two_body_aa),
+                    fcidump.norb as ModeIndex,
+                    false,
+                );
             }
         }
 
@@ -248,6 +336,36 @@ mod tests {
         assert_eq!(fcidump, expected);
     }
 
+    #[test]
+    #[should_panic(
+        expected = "Unrestricted FCIDump contains one-body integrals for only one spin species!"
+    )]
+    fn test_from_file_mixed_spin() {
+        FCIDump::from_file(String::from("../../tests/h2_mixed_spin.fcidump"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Integral indices 3 and 2 straddle the alpha/beta spin block boundary!"
+    )]
+    fn test_from_file_straddling_index() {
+        FCIDump::from_file(String::from("../../tests/h2_straddling.fcidump"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Integral indices 3 and 1 straddle the alpha/beta spin block boundary!"
+    )]
+    fn test_from_file_straddling_index_2body() {
+        FCIDump::from_file(String::from("../../tests/h2_straddling_2body.fcidump"));
+    }
+
+    #[test]
+    fn test_is_beta_pair() {
+        assert!(!is_beta_pair(0, 1, 2));
+        assert!(is_beta_pair(2, 3, 2));
+    }
+
     #[test]
     fn test_to_fermion_operator() {
         let fcidump = FCIDump {
@@ -274,6 +392,10 @@ mod tests {
         };
 
         let op = FermionOperator::from(&fcidump);
+        // the reserved capacity suffices, so the buffers were never reallocated
+        let (num_1body, num_2body) = _max_num_terms(&fcidump);
+        assert!(op.coeffs.len() <= 1 + num_1body + num_2body);
+        assert!(op.indices.len() <= 2 * num_1body + 4 * num_2body);
 
         let expected = FermionOperator {
             coeffs: vec![
@@ -353,6 +475,14 @@ mod tests {
         assert!(op.equiv(&expected, 1e-10));
     }
 
+    #[test]
+    fn test_from_file_beta_alpha_order() {
+        // the alpha-beta integrals of this file list the beta pair first
+        let fcidump = FCIDump::from_file(String::from("../../tests/heh_beta_alpha.fcidump"));
+        let expected = FCIDump::from_file(String::from("../../tests/heh.fcidump"));
+        assert_eq!(fcidump, expected);
+    }
+
     #[test]
     fn test_from_file_beta() {
         let file_path = String::from("../../tests/heh.fcidump");
@@ -452,6 +582,10 @@ mod tests {
         };
 
         let op = FermionOperator::from(&fcidump);
+        // the reserved capacity suffices, so the buffers were never reallocated
+        let (num_1body, num_2body) = _max_num_terms(&fcidump);
+        assert!(op.coeffs.len() <= 1 + num_1body + num_2body);
+        assert!(op.indices.len() <= 2 * num_1body + 4 * num_2body);
 
         let expected = FermionOperator {
             coeffs: vec![
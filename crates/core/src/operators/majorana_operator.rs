@@ -10,19 +10,111 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
-use crate::operators::{OperatorMacro, OperatorTrait};
+use crate::operators::library::commutators;
+use crate::operators::{ModeIndex, OperatorMacro, OperatorTrait, fock_dim, round_coeff};
+use ndarray::ArrayView2;
 use num_complex::{Complex64, ComplexFloat};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-pub type MajoranaAction<'a> = &'a u32;
+pub type MajoranaAction<'a> = &'a ModeIndex;
+
+/// The magic bytes at the start of every serialized [`MajoranaOperator`].
+const BYTES_MAGIC: &[u8; 4] = b"QFMO";
+
+/// The version of the layout written by [`MajoranaOperator::to_bytes`].
+///
+/// Version 1 stored the modes as `u32`, while version 2 stores them as `u64` such that operators
+/// with mode indices beyond `u32::MAX` (see the `u64` feature) can be serialized. Both versions
+/// can be read by [`MajoranaOperator::from_bytes`].
+pub const BYTES_VERSION: u32 = 2;
+
+/// The error returned when [`MajoranaOperator::from_bytes`] cannot decode its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MajoranaBytesError {
+    /// The data does not start with the expected magic bytes.
+    InvalidMagic,
+    /// The data was written with a layout version that is not supported.
+    UnsupportedVersion { version: u32 },
+    /// The data ends before all announced fields could be read.
+    UnexpectedEnd,
+    /// The data continues after all announced fields have been read.
+    TrailingBytes { count: usize },
+    /// The boundaries are not non-decreasing or do not match the number of modes.
+    InvalidBoundaries,
+    /// A mode exceeds the range of mode indices supported by this build.
+    ModeOutOfRange { mode: u64 },
+}
+
+impl fmt::Display for MajoranaBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "The data is not a serialized MajoranaOperator."),
+            Self::UnsupportedVersion { version } => {
+                write!(f, "Unsupported serialization version {version}.")
+            }
+            Self::UnexpectedEnd => write!(f, "The data ended unexpectedly."),
+            Self::TrailingBytes { count } => {
+                write!(f, "The data contains {count} unexpected trailing bytes.")
+            }
+            Self::InvalidBoundaries => write!(f, "The term boundaries are inconsistent."),
+            Self::ModeOutOfRange { mode } => write!(
+                f,
+                "The mode {mode} exceeds the supported range of mode indices."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MajoranaBytesError {}
+
+/// A cursor over serialized bytes which fails with [`MajoranaBytesError::UnexpectedEnd`].
+struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], MajoranaBytesError> {
+        let (head, tail) = self
+            .data
+            .split_first_chunk::<N>()
+            .ok_or(MajoranaBytesError::UnexpectedEnd)?;
+        self.data = tail;
+        Ok(*head)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, MajoranaBytesError> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn read_u64(&mut self) -> Result<u64, MajoranaBytesError> {
+        self.take().map(u64::from_le_bytes)
+    }
+
+    fn read_f64(&mut self) -> Result<f64, MajoranaBytesError> {
+        self.take().map(f64::from_le_bytes)
+    }
+
+    /// Reads a length prefix and checks that at least `len * item_size` bytes remain, such that
+    /// corrupted lengths cannot trigger huge allocations.
+    fn read_len(&mut self, item_size: usize) -> Result<usize, MajoranaBytesError> {
+        let len =
+            usize::try_from(self.read_u64()?).map_err(|_| MajoranaBytesError::UnexpectedEnd)?;
+        match len.checked_mul(item_size) {
+            Some(size) if size <= self.data.len() => Ok(len),
+            _ => Err(MajoranaBytesError::UnexpectedEnd),
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MajoranaOperatorTermView<'a> {
     pub coeff: Complex64,
-    pub modes: &'a [u32],
+    pub modes: &'a [ModeIndex],
 }
 
 impl MajoranaOperatorTermView<'_> {
@@ -36,7 +128,7 @@ impl MajoranaOperatorTermView<'_> {
         self.modes.iter().collect()
     }
 
-    pub fn into_vec(&'_ self) -> Vec<u32> {
+    pub fn into_vec(&'_ self) -> Vec<ModeIndex> {
         self.modes.to_vec()
     }
 }
@@ -44,13 +136,45 @@ impl MajoranaOperatorTermView<'_> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct MajoranaOperator {
     pub coeffs: Vec<Complex64>,
-    pub modes: Vec<u32>,
+    pub modes: Vec<ModeIndex>,
     pub boundaries: Vec<usize>,
 }
 
 crate::impl_operator_macro!(MajoranaOperator);
 
 impl MajoranaOperator {
+    /// Constructs the fermionic parity operator `prod_k (1 - 2 n_k) = prod_k (-i γ_{2k} γ_{2k+1})`
+    /// acting on `num_modes` fermionic modes, under the default convention of
+    /// [`fermion_to_majorana`](crate::mappers::library::majorana_fermion::fermion_to_majorana).
+    ///
+    /// The single term stores the modes `0..2 * num_modes` in ascending order. Since the modes of a
+    /// term act in the order in which they are stored, this is the product `γ_{2n-1} ... γ_1 γ_0`,
+    /// whose reversal contributes a sign of `(-1)^n`. Thus, the stored coefficient is `i^n`.
+    pub fn parity_operator(num_modes: ModeIndex) -> Self {
+        Self {
+            coeffs: vec![Complex64::i().powi((num_modes % 4) as i32)],
+            modes: (0..2 * num_modes).collect(),
+            boundaries: vec![0, 2 * num_modes as usize],
+        }
+    }
+
+    /// Constructs the quadratic operator `sum_{j<k} h_{jk} (i γ_j γ_k)` from the antisymmetric
+    /// matrix `h` indexed by Majorana modes. Only the strictly upper triangle of `h` is read and
+    /// zero entries are skipped.
+    pub fn from_antisymmetric_matrix(h: ArrayView2<f64>) -> Self {
+        assert_eq!(h.nrows(), h.ncols(), "the matrix must be square");
+        let mut out = Self::zero();
+        for ((j, k), &value) in h.indexed_iter() {
+            if j < k && value != 0.0 {
+                out.coeffs.push(Complex64::new(0.0, value));
+                out.modes
+                    .extend_from_slice(&[j as ModeIndex, k as ModeIndex]);
+                out.boundaries.push(out.modes.len());
+            }
+        }
+        out
+    }
+
     pub fn simplify(&self, atol: f64) -> Self {
         let mut terms = HashMap::new();
         for term in self.iter() {
@@ -59,18 +183,138 @@ impl MajoranaOperator {
                 .and_modify(|c| *c += term.coeff)
                 .or_insert(term.coeff);
         }
+        let mut terms: Vec<_> = terms
+            .into_iter()
+            .filter(|(_, coeff)| coeff.abs() > atol)
+            .collect();
+        // sort the terms to make the output independent of the hashing order
+        terms.sort_unstable_by_key(|(modes, _)| *modes);
+        let mut out = Self::zero();
+        terms.iter().for_each(|(modes, coeff)| {
+            out.coeffs.push(*coeff);
+            out.modes.extend_from_slice(modes);
+            out.boundaries.push(out.modes.len());
+        });
+        out
+    }
+
+    /// Simplifies this operator and then removes terms whose coefficient magnitude lies below
+    /// `rtol` times the largest one. An `rtol` of `0` keeps all terms.
+    pub fn chop_relative(&self, rtol: f64) -> Self {
+        let simplified = self.simplify(f64::NEG_INFINITY);
+        let atol = rtol
+            * simplified
+                .coeffs
+                .iter()
+                .map(|c| c.norm())
+                .fold(0.0, f64::max);
         let mut out = Self::zero();
-        terms
+        simplified
             .iter()
-            .filter(|(_, coeff)| coeff.abs() > atol)
-            .for_each(|(modes, coeff)| {
-                out.coeffs.push(*coeff);
-                out.modes.extend_from_slice(modes);
+            .filter(|term| term.coeff.norm() >= atol)
+            .for_each(|term| {
+                out.coeffs.push(term.coeff);
+                out.modes.extend_from_slice(term.modes);
+                out.boundaries.push(out.modes.len());
+            });
+        out
+    }
+
+    /// Projects this operator onto the modes below `max_index_exclusive`, by keeping only the
+    /// terms which act exclusively on these modes.
+    ///
+    /// This is **not** a partial trace: terms touching any other mode are discarded rather than
+    /// evaluated on some state of the remaining modes.
+    pub fn restrict_to_modes(&self, max_index_exclusive: ModeIndex) -> Self {
+        let mut out = Self::zero();
+        self.iter()
+            .filter(|term| term.modes.iter().all(|m| *m < max_index_exclusive))
+            .for_each(|term| {
+                out.coeffs.push(term.coeff);
+                out.modes.extend_from_slice(term.modes);
                 out.boundaries.push(out.modes.len());
             });
         out
     }
 
+    /// Splits this operator into its even and odd fermion-parity sectors, returned as
+    /// `(even, odd)`. The terms acting on an even (odd) number of modes go to the first (second)
+    /// operator; repeated modes are not reduced beforehand.
+    pub fn split_by_parity(&self) -> (Self, Self) {
+        let mut even = Self::zero();
+        let mut odd = Self::zero();
+        for term in self.iter() {
+            let out = if term.modes.len() % 2 == 0 {
+                &mut even
+            } else {
+                &mut odd
+            };
+            out.coeffs.push(term.coeff);
+            out.modes.extend_from_slice(term.modes);
+            out.boundaries.push(out.modes.len());
+        }
+        (even, odd)
+    }
+
+    /// Computes the sparse matrix of this operator on the Fock space of `num_modes` fermionic
+    /// modes, as coordinate (COO) triplets of row indices, column indices and values.
+    ///
+    /// The Majorana modes `2j` and `2j + 1` act as `γ_j = a†_j + a_j` and `γ'_j = i (a†_j - a_j)`,
+    /// respectively, where the fermionic operators are represented by their Jordan-Wigner images.
+    /// The modes of a term act in the order in which they are stored, i.e. the first one acts
+    /// first. Hence, the matrix of a mapped operator (using the default convention) equals the one
+    /// of the original [`FermionOperator`](crate::operators::fermion_operator::FermionOperator). The
+    /// basis, the signs and the order of the triplets are the same as in
+    /// [`FermionOperator::to_sparse_matrix`](crate::operators::fermion_operator::FermionOperator::to_sparse_matrix).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any Majorana mode is not smaller than `2 * num_modes` or if `num_modes` exceeds
+    /// [`MAX_FOCK_MODES`](crate::operators::MAX_FOCK_MODES).
+    pub fn to_sparse_matrix(
+        &self,
+        num_modes: ModeIndex,
+    ) -> (Vec<usize>, Vec<usize>, Vec<Complex64>) {
+        assert!(
+            self.modes.iter().all(|mode| mode / 2 < num_modes),
+            "the operator acts on a Majorana mode outside of the {num_modes} fermionic modes"
+        );
+        let mut rows = vec![];
+        let mut cols = vec![];
+        let mut values = vec![];
+        for col in 0..fock_dim(num_modes) {
+            let mut column: BTreeMap<usize, Complex64> = BTreeMap::new();
+            for term in self.iter() {
+                let mut row = col;
+                let mut coeff = term.coeff;
+                for mode in term.iter() {
+                    let index = (mode / 2) as usize;
+                    if (row & ((1 << index) - 1)).count_ones() % 2 == 1 {
+                        coeff = -coeff;
+                    }
+                    if !mode.is_multiple_of(2) {
+                        // γ'_j = i (a†_j - a_j) picks up -i from annihilating an occupied mode
+                        coeff *= if row >> index & 1 == 1 {
+                            -Complex64::i()
+                        } else {
+                            Complex64::i()
+                        };
+                    }
+                    row ^= 1 << index;
+                }
+                *column.entry(row).or_default() += coeff;
+            }
+            for (row, value) in column {
+                if value != Complex64::new(0.0, 0.0) {
+                    rows.push(row);
+                    cols.push(col);
+                    values.push(value);
+                }
+            }
+        }
+        (rows, cols, values)
+    }
+
     pub fn iter(&'_ self) -> impl ExactSizeIterator<Item = MajoranaOperatorTermView<'_>> + '_ {
         self.coeffs.iter().enumerate().map(|(i, coeff)| {
             let start = self.boundaries[i];
@@ -103,24 +347,149 @@ impl MajoranaOperator {
         }
     }
 
+    /// Returns the commutator `self & other - other & self`, where the composition `a & b`
+    /// corresponds to left-multiplication. See [`commutators::commutator`].
+    pub fn commutator(&self, other: &Self) -> Self {
+        commutators::commutator(self, other)
+    }
+
+    /// Returns the anti-commutator `self & other + other & self`. See
+    /// [`commutators::anti_commutator`].
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        commutators::anti_commutator(self, other)
+    }
+
+    /// Returns whether this operator is Hermitian, i.e. whether `self - self.adjoint()` vanishes up
+    /// to `atol`.
+    ///
+    /// Rather than constructing the adjoint, this reduces and merges the terms. Each resulting term
+    /// consists of `k` distinct modes and reversing it for the adjoint picks up the sign
+    /// `(-1)^(k (k - 1) / 2)`, so each coefficient `c` must equal `c*` times that sign.
     pub fn is_hermitian(&self, atol: f64) -> bool {
-        let mut diff = (self.__sub__(&self.adjoint())).normal_ordered(true);
-        diff.ichop(atol);
-        diff.equiv(&Self::zero(), atol)
+        self.normal_ordered(true).simplify(0.0).iter().all(|term| {
+            let reversal_sign = if (term.modes.len() / 2) % 2 == 0 {
+                1.0
+            } else {
+                -1.0
+            };
+            (term.coeff - reversal_sign * term.coeff.conj()).abs() <= atol
+        })
     }
 
+    /// Returns this operator with complex conjugated coefficients. Unlike
+    /// [`OperatorTrait::adjoint`], this leaves the terms themselves unchanged.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            coeffs: self.coeffs.iter().map(|c| c.conj()).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns this operator with the real and imaginary parts of all coefficients rounded to
+    /// `decimals` decimal places. Terms whose coefficients round to zero are kept; use
+    /// [`MajoranaOperator::simplify`] to remove them.
+    pub fn round_coeffs(&self, decimals: i32) -> Self {
+        let mut out = self.clone();
+        out.round_coeffs_mut(decimals);
+        out
+    }
+
+    /// In-place variant of [`MajoranaOperator::round_coeffs`].
+    pub fn round_coeffs_mut(&mut self, decimals: i32) {
+        self.coeffs
+            .iter_mut()
+            .for_each(|coeff| *coeff = round_coeff(*coeff, decimals));
+    }
+
+    /// Returns whether the imaginary parts of all coefficients are at most `atol` in magnitude.
+    pub fn is_real(&self, atol: f64) -> bool {
+        self.coeffs.iter().all(|c| c.im.abs() <= atol)
+    }
+
+    /// Returns the number of actions in the longest term.
+    pub fn max_term_length(&self) -> usize {
+        self.boundaries
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the many-body order, i.e. the number of actions in the longest term.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the longest term has more than `u32::MAX` actions.
     pub fn many_body_order(&self) -> u32 {
-        let mut max = 0;
-        let mut prev_b = 0;
-        // TODO: refactor this
-        self.boundaries[1..].iter().for_each(|b| {
-            let d = b - prev_b;
-            if d > max {
-                max = d;
-            }
-            prev_b = *b;
-        });
-        max as u32
+        u32::try_from(self.max_term_length()).expect("many-body order exceeds u32::MAX")
+    }
+
+    /// Returns the many-body order after removing repeated pairs of modes from each term.
+    ///
+    /// Since every Majorana operator squares to the identity, a term like `γ_0 γ_0 γ_1` reduces
+    /// to `γ_1`. Unlike [`MajoranaOperator::many_body_order`], which reports the raw length of the
+    /// longest term, this reports the longest length after this reduction. The operator itself is
+    /// left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the longest reduced term has more than `u32::MAX` actions.
+    pub fn reduced_many_body_order(&self) -> u32 {
+        let max_len = self
+            .iter()
+            .map(|term| {
+                let mut sorted = term.modes.to_vec();
+                sorted.sort_unstable();
+                reduce_pairs(&sorted).len()
+            })
+            .max()
+            .unwrap_or(0);
+        u32::try_from(max_len).expect("many-body order exceeds u32::MAX")
+    }
+
+    /// Returns whether this operator is quadratic, i.e. whether each of its reduced terms acts on
+    /// at most two modes. Like in
+    /// [`FermionOperator::is_quadratic`](crate::operators::fermion_operator::FermionOperator::is_quadratic),
+    /// terms which cancel out are ignored.
+    pub fn is_quadratic(&self) -> bool {
+        self.normal_ordered(true).simplify(0.0).many_body_order() <= 2
+    }
+
+    /// Returns the sum of all coefficients.
+    pub fn coefficient_sum(&self) -> Complex64 {
+        self.coeffs.iter().sum()
+    }
+
+    /// Returns the sum of the coefficients of all identity terms.
+    pub fn constant(&self) -> Complex64 {
+        self.iter()
+            .filter(|term| term.modes.is_empty())
+            .map(|term| term.coeff)
+            .sum()
+    }
+
+    /// Returns this operator with an identity term of coefficient `c` appended. Existing identity
+    /// terms are not merged with the new one.
+    pub fn add_constant(&self, c: Complex64) -> Self {
+        let mut out = self.clone();
+        out.add_constant_mut(c);
+        out
+    }
+
+    /// In-place variant of [`MajoranaOperator::add_constant`].
+    pub fn add_constant_mut(&mut self, c: Complex64) {
+        self.coeffs.push(c);
+        self.boundaries.push(self.modes.len());
+    }
+
+    /// Returns the smallest and largest mode acted upon, or `None` if there are no modes.
+    pub fn mode_range(&self) -> Option<(ModeIndex, ModeIndex)> {
+        let first = *self.modes.first()?;
+        Some(
+            self.modes[1..]
+                .iter()
+                .fold((first, first), |(min, max), &m| (min.min(m), max.max(m))),
+        )
     }
 
     pub fn is_even(&self) -> bool {
@@ -133,6 +502,97 @@ impl MajoranaOperator {
         }
         true
     }
+
+    /// Serializes this operator into a versioned binary format.
+    ///
+    /// The layout consists of the magic bytes `QFMO` and the [`BYTES_VERSION`] as a `u32`,
+    /// followed by the `coeffs` (real and imaginary parts as `f64`), `modes` (`u64`) and
+    /// `boundaries` (`u64`), each prefixed by their length as a `u64`. All numbers are stored in
+    /// little-endian byte order.
+    // the mode conversions are no-ops if the `u64` feature is enabled
+    #[allow(clippy::useless_conversion)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(
+            32 + 16 * self.coeffs.len() + 8 * self.modes.len() + 8 * self.boundaries.len(),
+        );
+        out.extend_from_slice(BYTES_MAGIC);
+        out.extend_from_slice(&BYTES_VERSION.to_le_bytes());
+        out.extend_from_slice(&(self.coeffs.len() as u64).to_le_bytes());
+        for coeff in &self.coeffs {
+            out.extend_from_slice(&coeff.re.to_le_bytes());
+            out.extend_from_slice(&coeff.im.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.modes.len() as u64).to_le_bytes());
+        for &mode in &self.modes {
+            out.extend_from_slice(&u64::from(mode).to_le_bytes());
+        }
+        out.extend_from_slice(&(self.boundaries.len() as u64).to_le_bytes());
+        for boundary in &self.boundaries {
+            out.extend_from_slice(&(*boundary as u64).to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserializes an operator previously serialized with [`MajoranaOperator::to_bytes`], in any
+    /// of the layout versions up to [`BYTES_VERSION`].
+    // the mode conversions are no-ops if the `u64` feature is enabled
+    #[allow(clippy::useless_conversion, clippy::unnecessary_fallible_conversions)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, MajoranaBytesError> {
+        let mut reader = ByteReader { data };
+        if &reader.take::<4>()? != BYTES_MAGIC {
+            return Err(MajoranaBytesError::InvalidMagic);
+        }
+        let version = reader.read_u32()?;
+        if !(1..=BYTES_VERSION).contains(&version) {
+            return Err(MajoranaBytesError::UnsupportedVersion { version });
+        }
+
+        let num_coeffs = reader.read_len(16)?;
+        let mut coeffs = Vec::with_capacity(num_coeffs);
+        for _ in 0..num_coeffs {
+            coeffs.push(Complex64::new(reader.read_f64()?, reader.read_f64()?));
+        }
+        let mode_size = if version == 1 { 4 } else { 8 };
+        let num_modes = reader.read_len(mode_size)?;
+        let mut modes = Vec::with_capacity(num_modes);
+        for _ in 0..num_modes {
+            let mode = if version == 1 {
+                u64::from(reader.read_u32()?)
+            } else {
+                reader.read_u64()?
+            };
+            modes.push(
+                ModeIndex::try_from(mode)
+                    .map_err(|_| MajoranaBytesError::ModeOutOfRange { mode })?,
+            );
+        }
+        let num_boundaries = reader.read_len(8)?;
+        let mut boundaries = Vec::with_capacity(num_boundaries);
+        for _ in 0..num_boundaries {
+            let boundary = usize::try_from(reader.read_u64()?)
+                .map_err(|_| MajoranaBytesError::InvalidBoundaries)?;
+            boundaries.push(boundary);
+        }
+        if !reader.data.is_empty() {
+            return Err(MajoranaBytesError::TrailingBytes {
+                count: reader.data.len(),
+            });
+        }
+
+        if boundaries.len() != coeffs.len() + 1
+            || boundaries[0] != 0
+            || boundaries[boundaries.len() - 1] != modes.len()
+            || boundaries.windows(2).any(|w| w[0] > w[1])
+        {
+            return Err(MajoranaBytesError::InvalidBoundaries);
+        }
+
+        Ok(Self {
+            coeffs,
+            modes,
+            boundaries,
+        })
+    }
 }
 
 /// FIXME: follow rustdoc standards
@@ -178,13 +638,13 @@ fn permutation_parity(perm: &[usize]) -> i32 {
 ///     A tuple (sorted_tpl, sign):
 ///     - sorted_tpl: tuple containing the sorted integers.
 ///     - sign: +1 if the sorting permutation is even, -1 if it is odd.
-fn sort_and_parity(tpl: &[u32]) -> (Vec<u32>, i32) {
-    let mut indexed: Vec<(usize, u32)> = tpl.iter().cloned().enumerate().collect();
+fn sort_and_parity(tpl: &[ModeIndex]) -> (Vec<ModeIndex>, i32) {
+    let mut indexed: Vec<(usize, ModeIndex)> = tpl.iter().cloned().enumerate().collect();
     // we need stable sort to ensure that the parity is correctly computed
-    indexed.sort_by_key(|&(_, val)| -(val as i32));
+    indexed.sort_by_key(|&(_, val)| Reverse(val));
 
     let perm: Vec<usize> = indexed.iter().map(|&(i, _)| i).collect();
-    let sorted_tpl: Vec<u32> = indexed.iter().map(|&(_, val)| val).collect();
+    let sorted_tpl: Vec<ModeIndex> = indexed.iter().map(|&(_, val)| val).collect();
 
     (sorted_tpl, permutation_parity(&perm))
 }
@@ -198,7 +658,7 @@ fn sort_and_parity(tpl: &[u32]) -> (Vec<u32>, i32) {
 ///
 /// Returns:
 ///     A tuple of integers after removing pairs.
-fn reduce_pairs(tpl: &[u32]) -> Vec<u32> {
+fn reduce_pairs(tpl: &[ModeIndex]) -> Vec<ModeIndex> {
     let mut reduced = Vec::new();
     let mut i = 0;
     let n = tpl.len();
@@ -269,6 +729,14 @@ impl OperatorTrait for MajoranaOperator {
             .extend(other.boundaries[1..].iter().map(|b| b + offset));
     }
 
+    fn scaled_iadd(&mut self, other: &Self, scale: Complex64) {
+        self.coeffs.extend(other.coeffs.iter().map(|c| c * scale));
+        self.modes.extend_from_slice(&other.modes);
+        let offset = self.boundaries[self.boundaries.len() - 1];
+        self.boundaries
+            .extend(other.boundaries[1..].iter().map(|b| b + offset));
+    }
+
     fn __imul__(&mut self, other: Complex64) {
         self.coeffs.iter_mut().for_each(|c| *c *= other);
     }
@@ -311,9 +779,28 @@ impl OperatorTrait for MajoranaOperator {
     }
 }
 
+/// Formats the operator with one term per line, sorted by their modes.
+impl fmt::Display for MajoranaOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sorted: Vec<_> = self.iter().collect();
+        sorted.sort_by_key(|&term| term.into_vec());
+        let mut items_str = Vec::new();
+        for term in sorted {
+            let key_parts: Vec<String> = term.iter().map(|mode| format!("{mode}")).collect();
+            let key_str = format!("({})", key_parts.join(" "));
+            let val_str = format!("{:12.6e}{:+12.6e}j", term.coeff.re, term.coeff.im);
+            items_str.push(format!("{val_str} * {key_str}"));
+        }
+        write!(f, "{}", items_str.join("\n"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mappers::library::majorana_fermion::fermion_to_majorana;
+    use crate::operators::fermion_operator::FermionOperator;
+    use crate::operators::library::commutators::commutator;
 
     #[test]
     fn test_zero() {
@@ -379,6 +866,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scaled_add_assign() {
+        let mut op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)],
+            modes: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        let other = MajoranaOperator {
+            coeffs: vec![Complex64::new(2.0, 0.0), Complex64::new(0.0, 1.0)],
+            modes: vec![1, 2, 3],
+            boundaries: vec![0, 2, 3],
+        };
+        let scale = Complex64::new(3.0, 0.0);
+        let expected = op.__add__(&other.__mul__(scale));
+        op.scaled_iadd(&other, scale);
+        assert_eq!(op, expected);
+    }
+
     #[test]
     fn test_sub() {
         let one = MajoranaOperator::one();
@@ -623,6 +1128,19 @@ mod tests {
         assert_eq!(op, expected2);
     }
 
+    #[test]
+    fn test_conjugate() {
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 2.0)],
+            modes: vec![0, 1],
+            boundaries: vec![0, 2],
+        };
+        let conj = op.conjugate();
+        assert_eq!(conj.coeffs, [Complex64::new(1.0, -2.0)]);
+        assert_eq!(conj.conjugate(), op);
+        assert_ne!(conj, op.adjoint());
+    }
+
     #[test]
     fn test_adjoint() {
         let op1 = MajoranaOperator {
@@ -709,6 +1227,103 @@ mod tests {
         assert!(!op.is_hermitian(1e-6));
     }
 
+    #[test]
+    fn test_is_hermitian_reversal_sign() {
+        let single = |coeff: Complex64, modes: Vec<ModeIndex>| MajoranaOperator {
+            coeffs: vec![coeff],
+            boundaries: vec![0, modes.len()],
+            modes,
+        };
+        let one = Complex64::new(1.0, 0.0);
+        let i = Complex64::new(0.0, 1.0);
+
+        // (γ_0 γ_1)† = γ_1 γ_0 = -γ_0 γ_1
+        let g01 = single(one, vec![0, 1]);
+        let adjoint = g01.adjoint();
+        assert_eq!(adjoint, single(one, vec![1, 0]));
+        assert!(
+            adjoint
+                .normal_ordered(true)
+                .equiv(&g01.__neg__().normal_ordered(true), 1e-12)
+        );
+        assert!(!g01.is_hermitian(1e-8));
+        assert!(single(i, vec![0, 1]).is_hermitian(1e-8));
+
+        // the reversal sign for k modes is (-1)^(k (k - 1) / 2)
+        for (k, real_is_hermitian) in [(0, true), (1, true), (2, false), (3, false), (4, true)] {
+            let modes: Vec<ModeIndex> = (0..k).collect();
+            assert_eq!(
+                single(one, modes.clone()).is_hermitian(1e-8),
+                real_is_hermitian
+            );
+            assert_eq!(single(i, modes).is_hermitian(1e-8), !real_is_hermitian);
+        }
+
+        // repeated modes reduce before the sign is determined: γ_0 γ_1 γ_0 = -γ_1
+        assert!(single(one, vec![0, 1, 0]).is_hermitian(1e-8));
+        assert!(!single(i, vec![0, 1, 0]).is_hermitian(1e-8));
+
+        // the fast path agrees with the definition
+        let op = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(0.5, 0.25),
+                Complex64::new(-0.5, 0.25),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(0.0, 3.0),
+            ],
+            modes: vec![0, 2, 2, 0, 1, 2, 3, 4, 5, 6],
+            boundaries: vec![0, 2, 4, 7, 10],
+        };
+        for op in [op.clone(), op.__add__(&op.adjoint())] {
+            let diff = op.__sub__(&op.adjoint()).normal_ordered(true);
+            assert_eq!(
+                op.is_hermitian(1e-8),
+                diff.equiv(&MajoranaOperator::zero(), 1e-8)
+            );
+        }
+        assert!(op.__add__(&op.adjoint()).is_hermitian(1e-8));
+    }
+
+    #[test]
+    fn test_from_antisymmetric_matrix() {
+        let h = ndarray::array![
+            [0.0, 1.0, 0.0, -0.5],
+            [-1.0, 0.0, 2.0, 0.0],
+            [0.0, -2.0, 0.0, 0.25],
+            [0.5, 0.0, -0.25, 0.0],
+        ];
+        let op = MajoranaOperator::from_antisymmetric_matrix(h.view());
+        let expected = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(0.0, 1.0),
+                Complex64::new(0.0, -0.5),
+                Complex64::new(0.0, 2.0),
+                Complex64::new(0.0, 0.25),
+            ],
+            modes: vec![0, 1, 0, 3, 1, 2, 2, 3],
+            boundaries: vec![0, 2, 4, 6, 8],
+        };
+        assert_eq!(op, expected);
+        assert!(op.is_hermitian(1e-12));
+        assert!(op.is_quadratic());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(MajoranaOperator::zero().to_string(), "");
+        assert_eq!(
+            MajoranaOperator::one().to_string(),
+            "  1.000000e0 +0.000000e0j * ()"
+        );
+
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(0.5, -2.0)],
+            modes: vec![0, 1, 2, 3],
+            boundaries: vec![0, 4],
+        };
+        assert_eq!(op.to_string(), " 5.000000e-1 -2.000000e0j * (0 1 2 3)");
+    }
+
     #[test]
     fn test_many_body_order() {
         assert_eq!(MajoranaOperator::one().many_body_order(), 0);
@@ -734,6 +1349,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_many_body_order_long_term() {
+        let len = u16::MAX as usize + 2;
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            modes: vec![0; len + 1],
+            boundaries: vec![0, 1, len + 1],
+        };
+        assert_eq!(op.max_term_length(), len);
+        assert_eq!(op.many_body_order(), len as u32);
+        assert_eq!(MajoranaOperator::zero().max_term_length(), 0);
+    }
+
+    #[test]
+    fn test_commutator_methods() {
+        let gamma_0 = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![0],
+            boundaries: vec![0, 1],
+        };
+        let gamma_1 = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![1],
+            boundaries: vec![0, 1],
+        };
+
+        let anti = gamma_0.anticommutator(&gamma_0).normal_ordered(true);
+        assert!(anti.simplify(1e-12).equiv(
+            &MajoranaOperator::one().__mul__(Complex64::new(2.0, 0.0)),
+            1e-12
+        ));
+        assert!(
+            gamma_0
+                .anticommutator(&gamma_1)
+                .normal_ordered(true)
+                .equiv(&MajoranaOperator::zero(), 1e-12)
+        );
+
+        let comm = gamma_0.commutator(&gamma_1).normal_ordered(true);
+        assert!(!comm.equiv(&MajoranaOperator::zero(), 1e-12));
+        assert!(
+            comm.equiv(
+                &gamma_0
+                    .__and__(&gamma_1)
+                    .__mul__(Complex64::new(2.0, 0.0))
+                    .normal_ordered(true),
+                1e-12
+            )
+        );
+        assert!(
+            gamma_0
+                .commutator(&gamma_0)
+                .normal_ordered(true)
+                .equiv(&MajoranaOperator::zero(), 1e-12)
+        );
+    }
+
+    #[test]
+    fn test_reduced_many_body_order() {
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![0, 0, 1],
+            boundaries: vec![0, 3],
+        };
+        assert_eq!(op.many_body_order(), 3);
+        assert_eq!(op.reduced_many_body_order(), 1);
+
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+            modes: vec![0, 1, 0, 2, 3],
+            boundaries: vec![0, 3, 5],
+        };
+        assert_eq!(op.many_body_order(), 3);
+        assert_eq!(op.reduced_many_body_order(), 2);
+        assert_eq!(MajoranaOperator::zero().reduced_many_body_order(), 0);
+    }
+
     #[test]
     fn test_is_even() {
         assert!(MajoranaOperator::zero().is_even());
@@ -755,4 +1447,378 @@ mod tests {
             .is_even()
         );
     }
+
+    #[test]
+    fn test_split_by_parity() {
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 2.0)],
+            modes: vec![0, 1, 2, 3, 4],
+            boundaries: vec![0, 2, 5],
+        };
+        let (even, odd) = op.split_by_parity();
+        assert!(even.is_even());
+        assert!(!odd.is_even());
+        assert_eq!(even.coeffs, [Complex64::new(1.0, 0.0)]);
+        assert_eq!(even.modes, [0, 1]);
+        assert_eq!(odd.coeffs, [Complex64::new(0.0, 2.0)]);
+        assert_eq!(odd.modes, [2, 3, 4]);
+        assert!(even.__add__(&odd).equiv(&op, 0.0));
+
+        let (even, odd) = MajoranaOperator::one().split_by_parity();
+        assert_eq!(even, MajoranaOperator::one());
+        assert_eq!(odd, MajoranaOperator::zero());
+    }
+
+    #[test]
+    fn test_to_sparse_matrix() {
+        // (1 + i γ_0 γ'_0) / 2 is the number operator of mode 0, where γ'_0 acts first
+        let number_op = MajoranaOperator {
+            coeffs: vec![Complex64::new(0.5, 0.0), Complex64::new(0.0, 0.5)],
+            modes: vec![1, 0],
+            boundaries: vec![0, 0, 2],
+        };
+        assert_eq!(
+            number_op.to_sparse_matrix(2),
+            (
+                vec![1, 3],
+                vec![1, 3],
+                vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)]
+            )
+        );
+
+        // γ_1 = a†_1 + a_1 picks up the Jordan-Wigner sign of mode 0
+        let gamma = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![2],
+            boundaries: vec![0, 1],
+        };
+        assert_eq!(
+            gamma.to_sparse_matrix(2),
+            (
+                vec![2, 3, 0, 1],
+                vec![0, 1, 2, 3],
+                vec![
+                    Complex64::new(1.0, 0.0),
+                    Complex64::new(-1.0, 0.0),
+                    Complex64::new(1.0, 0.0),
+                    Complex64::new(-1.0, 0.0)
+                ]
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 1 fermionic modes")]
+    fn test_to_sparse_matrix_out_of_range() {
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![2],
+            boundaries: vec![0, 1],
+        };
+        op.to_sparse_matrix(1);
+    }
+
+    #[test]
+    fn test_chop_relative() {
+        let op = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1e-3, 0.0),
+                Complex64::new(0.0, 1e-7),
+            ],
+            modes: vec![0, 1, 2],
+            boundaries: vec![0, 1, 2, 3],
+        };
+
+        let chopped = op.chop_relative(1e-4);
+        let expected = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(1e-3, 0.0)],
+            modes: vec![0, 1],
+            boundaries: vec![0, 1, 2],
+        };
+        assert!(chopped.equiv(&expected, 0.0));
+        assert_eq!(chopped.coeffs.len(), 2);
+
+        assert_eq!(op.chop_relative(0.0).coeffs.len(), 3);
+        assert_eq!(
+            MajoranaOperator::zero().chop_relative(1e-4),
+            MajoranaOperator::zero()
+        );
+    }
+
+    #[test]
+    fn test_restrict_to_modes() {
+        let op = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0, 0.0),
+                Complex64::new(3.0, 0.0),
+            ],
+            modes: vec![0, 1, 1, 2, 3],
+            boundaries: vec![0, 0, 2, 5],
+        };
+        let expected = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            modes: vec![0, 1],
+            boundaries: vec![0, 0, 2],
+        };
+        assert_eq!(op.restrict_to_modes(2), expected);
+        assert_eq!(op.restrict_to_modes(0), MajoranaOperator::one());
+    }
+
+    #[test]
+    fn test_is_real() {
+        let mut op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(-0.5, 0.0)],
+            modes: vec![0, 1, 2, 3],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!(op.is_real(0.0));
+
+        op.coeffs[1].im = 1e-6;
+        assert!(!op.is_real(1e-8));
+        assert!(op.is_real(1e-4));
+    }
+
+    #[test]
+    fn test_coefficient_sum_and_constant() {
+        let op = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(1.5, 0.0),
+                Complex64::new(2.0, 1.0),
+                Complex64::new(-0.5, 0.0),
+            ],
+            modes: vec![0, 1],
+            boundaries: vec![0, 0, 2, 2],
+        };
+        assert_eq!(op.coefficient_sum(), Complex64::new(3.0, 1.0));
+        assert_eq!(op.constant(), Complex64::new(1.0, 0.0));
+        assert_eq!(
+            MajoranaOperator::zero().constant(),
+            Complex64::new(0.0, 0.0)
+        );
+
+        let shifted = op.add_constant(Complex64::new(1.5, 0.0));
+        assert_eq!(shifted.constant(), Complex64::new(2.5, 0.0));
+        assert_eq!(shifted.coeffs.len(), 4);
+        let mut inplace = op.clone();
+        inplace.add_constant_mut(Complex64::new(1.5, 0.0));
+        assert_eq!(inplace, shifted);
+    }
+
+    #[test]
+    fn test_parity_operator() {
+        let parity = MajoranaOperator::parity_operator(2);
+        assert_eq!(
+            parity,
+            MajoranaOperator {
+                coeffs: vec![Complex64::new(-1.0, 0.0)],
+                modes: vec![0, 1, 2, 3],
+                boundaries: vec![0, 4],
+            }
+        );
+
+        let squared = parity.__and__(&parity).normal_ordered(true).simplify(1e-10);
+        assert!(squared.equiv(&MajoranaOperator::one(), 1e-10));
+
+        // the sign only differs between an even and odd number of modes
+        for num_modes in 1..=4 {
+            let expected = fermion_to_majorana(&FermionOperator::parity_operator(num_modes))
+                .normal_ordered(true)
+                .simplify(1e-10);
+            let parity = MajoranaOperator::parity_operator(num_modes).normal_ordered(true);
+            assert!(parity.equiv(&expected, 1e-10), "{num_modes} modes");
+        }
+
+        let number = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.5),
+                Complex64::new(0.0, 0.5),
+            ],
+            modes: vec![0, 1, 2, 3],
+            boundaries: vec![0, 0, 2, 4],
+        };
+        let comm = commutator(&parity, &number)
+            .normal_ordered(true)
+            .simplify(1e-10);
+        assert_eq!(comm, MajoranaOperator::zero());
+    }
+
+    #[test]
+    fn test_is_quadratic() {
+        let hopping = MajoranaOperator {
+            coeffs: vec![Complex64::new(0.0, 0.5), Complex64::new(0.0, -0.5)],
+            modes: vec![0, 3, 1, 2],
+            boundaries: vec![0, 2, 4],
+        };
+        assert!(hopping.is_quadratic());
+
+        let hubbard = MajoranaOperator {
+            coeffs: vec![Complex64::new(-0.25, 0.0)],
+            modes: vec![0, 1, 2, 3],
+            boundaries: vec![0, 4],
+        };
+        assert!(!hubbard.is_quadratic());
+
+        // the repeated mode reduces away
+        let reducible = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![0, 1, 0],
+            boundaries: vec![0, 3],
+        };
+        assert!(reducible.is_quadratic());
+
+        // the quartic terms cancel out once normal-ordered
+        let cancelling = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.5),
+            ],
+            modes: vec![0, 1, 2, 3, 1, 0, 2, 3, 0, 1],
+            boundaries: vec![0, 4, 8, 10],
+        };
+        assert!(cancelling.is_quadratic());
+    }
+
+    #[test]
+    fn test_mode_range() {
+        assert_eq!(MajoranaOperator::zero().mode_range(), None);
+        assert_eq!(MajoranaOperator::one().mode_range(), None);
+
+        let single = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![5],
+            boundaries: vec![0, 1],
+        };
+        assert_eq!(single.mode_range(), Some((5, 5)));
+
+        let wide = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)],
+            modes: vec![7, 2, ModeIndex::MAX, 0],
+            boundaries: vec![0, 2, 4],
+        };
+        assert_eq!(wide.mode_range(), Some((0, ModeIndex::MAX)));
+    }
+
+    #[cfg(feature = "u64")]
+    #[test]
+    fn test_u64_mode_indices() {
+        let high = ModeIndex::from(u32::MAX) + 5;
+        let op = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![0, high],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(op.mode_range(), Some((0, high)));
+
+        let expected = MajoranaOperator {
+            coeffs: vec![Complex64::new(-1.0, 0.0)],
+            modes: vec![high, 0],
+            boundaries: vec![0, 2],
+        };
+        assert_eq!(op.normal_ordered(false), expected);
+        assert_eq!(MajoranaOperator::from_bytes(&op.to_bytes()), Ok(op));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let many = MajoranaOperator {
+            coeffs: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(-2.5, 0.5),
+                Complex64::new(0.0, -1e-12),
+            ],
+            modes: vec![0, 1, 7, u32::MAX as ModeIndex, 3, 2],
+            boundaries: vec![0, 0, 4, 6],
+        };
+        for op in [MajoranaOperator::zero(), MajoranaOperator::one(), many] {
+            let bytes = op.to_bytes();
+            assert_eq!(&bytes[..4], b"QFMO");
+            assert_eq!(MajoranaOperator::from_bytes(&bytes), Ok(op));
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_errors() {
+        let bytes = MajoranaOperator::one().to_bytes();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(
+            MajoranaOperator::from_bytes(&bad_magic),
+            Err(MajoranaBytesError::InvalidMagic)
+        );
+
+        let mut bad_version = bytes.clone();
+        bad_version[4..8].copy_from_slice(&99u32.to_le_bytes());
+        assert_eq!(
+            MajoranaOperator::from_bytes(&bad_version),
+            Err(MajoranaBytesError::UnsupportedVersion { version: 99 })
+        );
+
+        assert_eq!(
+            MajoranaOperator::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(MajoranaBytesError::UnexpectedEnd)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            MajoranaOperator::from_bytes(&trailing),
+            Err(MajoranaBytesError::TrailingBytes { count: 1 })
+        );
+
+        let inconsistent = MajoranaOperator {
+            coeffs: vec![Complex64::new(1.0, 0.0)],
+            modes: vec![0, 1],
+            boundaries: vec![0, 1],
+        };
+        assert_eq!(
+            MajoranaOperator::from_bytes(&inconsistent.to_bytes()),
+            Err(MajoranaBytesError::InvalidBoundaries)
+        );
+
+        #[cfg(not(feature = "u64"))]
+        {
+            let mut wide = MajoranaOperator {
+                coeffs: vec![Complex64::new(1.0, 0.0)],
+                modes: vec![0],
+                boundaries: vec![0, 1],
+            }
+            .to_bytes();
+            // the single mode is stored in the 8 bytes preceding the boundaries
+            let offset = wide.len() - 8 * 3 - 8;
+            wide[offset..offset + 8].copy_from_slice(&(1u64 << 32).to_le_bytes());
+            assert_eq!(
+                MajoranaOperator::from_bytes(&wide),
+                Err(MajoranaBytesError::ModeOutOfRange { mode: 1 << 32 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_version_1() {
+        // 0.5i * (1 2), with the modes stored as u32
+        let mut bytes = b"QFMO".to_vec();
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&1u64.to_le_bytes());
+        bytes.extend_from_slice(&0.0f64.to_le_bytes());
+        bytes.extend_from_slice(&0.5f64.to_le_bytes());
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+        assert_eq!(
+            MajoranaOperator::from_bytes(&bytes),
+            Ok(MajoranaOperator {
+                coeffs: vec![Complex64::new(0.0, 0.5)],
+                modes: vec![1, 2],
+                boundaries: vec![0, 2],
+            })
+        );
+    }
 }
@@ -12,6 +12,58 @@
 
 use num_complex::Complex64;
 
+/// The integer type used for fermionic mode indices throughout the crate.
+///
+/// This is `u32` by default and `u64` when the `u64` feature is enabled. The C and Python bindings
+/// only support the default width.
+#[cfg(not(feature = "u64"))]
+pub type ModeIndex = u32;
+/// The integer type used for fermionic mode indices throughout the crate.
+///
+/// This is `u32` by default and `u64` when the `u64` feature is enabled. The C and Python bindings
+/// only support the default width.
+#[cfg(feature = "u64")]
+pub type ModeIndex = u64;
+
+/// The maximum number of terms which the Python and C bindings allow an operator power to produce.
+pub const MAX_POW_TERMS: usize = 1 << 24;
+
+/// The maximum number of modes of the Fock spaces enumerated by methods like
+/// [`FermionOperator::to_sparse_matrix`](fermion_operator::FermionOperator::to_sparse_matrix),
+/// whose cost grows with the `2^num_modes` basis states.
+pub const MAX_FOCK_MODES: ModeIndex = 30;
+
+/// Returns the dimension `2^num_modes` of the Fock space of `num_modes` modes.
+///
+/// # Panics
+///
+/// Panics if `num_modes` exceeds [`MAX_FOCK_MODES`].
+pub(crate) fn fock_dim(num_modes: ModeIndex) -> usize {
+    assert!(
+        num_modes <= MAX_FOCK_MODES,
+        "the Fock space of {num_modes} modes exceeds the maximum of {MAX_FOCK_MODES} modes"
+    );
+    1 << num_modes
+}
+
+/// Rounds the real and imaginary parts of `coeff` to `decimals` decimal places, with ties rounded
+/// away from zero. A negative `decimals` rounds to the left of the decimal point.
+pub(crate) fn round_coeff(coeff: Complex64, decimals: i32) -> Complex64 {
+    let scale = 10f64.powi(decimals.saturating_abs());
+    let round = |x: f64| {
+        if decimals >= 0 {
+            let rounded = (x * scale).round() / scale;
+            // the scaling only overflows if `x` has fewer than `decimals` decimal places
+            if rounded.is_finite() { rounded } else { x }
+        } else if scale.is_finite() {
+            (x / scale).round() * scale
+        } else {
+            0.0 * x
+        }
+    };
+    Complex64::new(round(coeff.re), round(coeff.im))
+}
+
 pub trait OperatorTrait {
     fn zero() -> Self;
     fn one() -> Self;
@@ -20,6 +72,8 @@ pub trait OperatorTrait {
     fn adjoint(&self) -> Self;
 
     fn __iadd__(&mut self, other: &Self);
+    /// Adds `scale * other` to `self` without allocating a scaled copy of `other`.
+    fn scaled_iadd(&mut self, other: &Self, scale: Complex64);
     fn __imul__(&mut self, other: Complex64);
     fn __iand__(&mut self, other: &Self);
     fn ichop(&mut self, atol: f64);
@@ -33,6 +87,12 @@ pub trait OperatorMacro {
     fn __neg__(&self) -> Self;
     fn __and__(&self, other: &Self) -> Self;
     fn __pow__(&self, exponent: usize) -> Self;
+    /// Like [`OperatorMacro::__pow__`], but returns `None` instead of computing a result with more
+    /// than `max_terms` terms. Since terms are not simplified during composition, the result of
+    /// raising an operator with `n` terms to the power `k` has exactly `n^k` terms.
+    fn checked_pow(&self, exponent: usize, max_terms: usize) -> Option<Self>
+    where
+        Self: Sized;
 
     // more in-place operations
     fn __isub__(&mut self, other: &Self);
@@ -113,12 +173,33 @@ macro_rules! impl_operator_macro {
             where
                 Self: OperatorTrait,
             {
+                // exponentiation by squaring: all factors are powers of `self` and thus commute
                 let mut result = Self::one();
-                for _ in 0..exponent {
-                    result.__iand__(self);
+                let mut base = self.clone();
+                let mut exponent = exponent;
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        result.__iand__(&base);
+                    }
+                    exponent >>= 1;
+                    if exponent > 0 {
+                        base = base.__and__(&base);
+                    }
                 }
                 result
             }
+
+            fn checked_pow(&self, exponent: usize, max_terms: usize) -> Option<Self>
+            where
+                Self: OperatorTrait,
+            {
+                // an operator with at most one term never grows beyond a single term
+                let num_terms = match self.coeffs.len() {
+                    0 | 1 => 1,
+                    len => len.checked_pow(u32::try_from(exponent).ok()?)?,
+                };
+                (num_terms <= max_terms).then(|| self.__pow__(exponent))
+            }
         }
 
         impl Add for $name {
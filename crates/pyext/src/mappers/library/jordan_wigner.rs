@@ -11,9 +11,30 @@
 // that they have been altered from the originals.
 
 use crate::operators::fermion_operator::PyFermionOperator;
+use num_complex::Complex64;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3_stub_gen::derive::*;
-use qiskit_fermions_core::mappers::library::jordan_wigner::jordan_wigner;
+use qiskit_fermions_core::mappers::library::jordan_wigner::{
+    inverse_jordan_wigner, inverse_jordan_wigner_checked, jordan_wigner,
+    jordan_wigner_with_progress,
+};
+use std::mem::MaybeUninit;
+
+/// The version of the JSON schema produced by [py_jordan_wigner_to_json].
+const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Raises a ``ValueError`` if `op` acts on a mode which has no qubit among the first `num_qubits`.
+fn check_num_qubits(op: &PyFermionOperator, num_qubits: u32) -> PyResult<()> {
+    if let Some((_, max)) = op.inner.index_range()
+        && max >= num_qubits
+    {
+        return Err(PyValueError::new_err(format!(
+            "Mode {max} is out of range for {num_qubits} qubits."
+        )));
+    }
+    Ok(())
+}
 
 /// Map a :class:`.FermionOperator` to a :class:`~qiskit.quantum_info.SparseObservable` under the
 /// Jordan-Wigner transformation. [1]_
@@ -21,10 +42,18 @@ use qiskit_fermions_core::mappers::library::jordan_wigner::jordan_wigner;
 /// Args:
 ///     op: the fermionic operator to map.
 ///     num_qubits: the number of qubits for the resulting qubit operator.
+///     progress: an optional callable which gets invoked as ``progress(terms_done, terms_total)``
+///         after every ``progress_interval`` mapped terms and once all terms have been mapped.
+///     progress_interval: the number of mapped terms between invocations of ``progress``.
 ///
 /// Returns:
 ///     The mapped qubit operator.
 ///
+/// Raises:
+///     ValueError: if ``op`` acts on a mode which is not smaller than ``num_qubits``.
+///     Exception: the first exception raised by ``progress``. Once this has occurred, the callback
+///         is no longer invoked but the mapping still runs to completion before raising.
+///
 /// ----
 ///
 /// Definition
@@ -66,25 +95,283 @@ use qiskit_fermions_core::mappers::library::jordan_wigner::jordan_wigner;
 ///     >>> qop.simplify()
 ///     <SparseObservable with 5 terms on 4 qubits: (2.05-0.25j)() + (-0.05+0j)(Z_0) + (0+0.25j)(Z_1) + (0+0.25j)(Z_2 Z_1) + (0-0.25j)(Z_2)>
 ///
+/// For large operators, the mapping may take a while. Its progress can be monitored with a
+/// callback:
+///
+/// .. doctest::
+///     >>> _ = jordan_wigner(fop, 4, progress=lambda done, total: print(f"{done}/{total}"))
+///     3/3
+///
 /// ----
 ///
 /// .. [1] P. Jordan and E. Wigner, Über das Paulische Äquivalenzverbot,
 ///        Zeitschrift für Physik 47, No. 9. (1928), pp. 631–651,
 ///        `doi:10.1007/BF01331938 <https://link.springer.com/article/10.1007/BF01331938>`_.
 #[gen_stub_pyfunction(module = "qiskit_fermions.mappers.library.jordan_wigner")]
-#[pyfunction(name = "jordan_wigner")]
+#[pyfunction(
+    name = "jordan_wigner",
+    signature = (op, num_qubits, progress=None, progress_interval=1000)
+)]
 #[gen_stub(override_return_type(type_repr="qiskit.quantum_info.SparseObservable", imports=("qiskit.quantum_info")))]
-pub fn py_jordan_wigner(op: PyFermionOperator, num_qubits: u32) -> Py<PyAny> {
-    let obs = jordan_wigner(&op.inner, num_qubits);
+pub fn py_jordan_wigner(
+    op: PyFermionOperator,
+    num_qubits: u32,
+    progress: Option<Bound<'_, PyAny>>,
+    progress_interval: usize,
+) -> PyResult<Py<PyAny>> {
+    check_num_qubits(&op, num_qubits)?;
+    let obs = match progress {
+        None => jordan_wigner(&op.inner, num_qubits),
+        Some(callback) => {
+            // NOTE: the callback always runs on this thread which holds the GIL, while the
+            // mapping itself does not require it.
+            let mut err = None;
+            let obs = jordan_wigner_with_progress(
+                &op.inner,
+                num_qubits,
+                progress_interval,
+                |done, total| {
+                    if err.is_none() {
+                        err = callback.call1((done, total)).err();
+                    }
+                },
+            );
+            if let Some(err) = err {
+                unsafe { qiskit_sys::qk_obs_free(obs) };
+                return Err(err);
+            }
+            obs
+        }
+    };
     unsafe {
         let py = Python::assume_attached();
         let py_obs = qiskit_sys::qk_obs_to_python(obs);
-        Bound::from_owned_ptr(py, py_obs).into()
+        Ok(Bound::from_owned_ptr(py, py_obs).into())
+    }
+}
+
+/// Map a :class:`.FermionOperator` under the Jordan-Wigner transformation and serialize the
+/// resulting Pauli terms to JSON.
+///
+/// The mapping is identical to :func:`.jordan_wigner`. The resulting qubit operator is simplified
+/// before its terms get serialized into the following (versioned) schema:
+///
+/// .. code-block:: json
+///
+///    {
+///      "version": 1,
+///      "num_qubits": 2,
+///      "terms": [
+///        {"paulis": "IZ", "coeff": [-0.5, 0.0]},
+///        ...
+///      ]
+///    }
+///
+/// where ``"paulis"`` is a dense Pauli label of length ``num_qubits`` following Qiskit's
+/// little-endian convention (i.e. the rightmost character acts on qubit 0) and ``"coeff"`` holds
+/// the real and imaginary part of the term's coefficient.
+///
+/// .. doctest::
+///     >>> import json
+///     >>> from qiskit_fermions.mappers.library import jordan_wigner_to_json
+///     >>> from qiskit_fermions.operators import FermionOperator
+///     >>> fop = FermionOperator.from_dict({((True, 0), (False, 0)): 1.0})
+///     >>> data = json.loads(jordan_wigner_to_json(fop, 2))
+///     >>> data["version"], data["num_qubits"]
+///     (1, 2)
+///     >>> sorted((term["paulis"], term["coeff"]) for term in data["terms"])
+///     [('II', [0.5, 0.0]), ('IZ', [-0.5, 0.0])]
+///
+/// Args:
+///     op: the fermionic operator to map.
+///     num_qubits: the number of qubits for the resulting qubit operator.
+///     atol: the absolute tolerance below which mapped terms are discarded.
+///
+/// Returns:
+///     The JSON representation of the mapped qubit operator.
+///
+/// Raises:
+///     ValueError: if ``op`` acts on a mode which is not smaller than ``num_qubits``, or if a
+///         coefficient of ``op`` or of the mapped qubit operator is ``NaN`` or infinite, since JSON
+///         cannot represent such numbers.
+#[gen_stub_pyfunction(module = "qiskit_fermions.mappers.library.jordan_wigner")]
+#[pyfunction(name = "jordan_wigner_to_json", signature = (op, num_qubits, atol=1e-12))]
+pub fn py_jordan_wigner_to_json(
+    op: PyFermionOperator,
+    num_qubits: u32,
+    atol: f64,
+) -> PyResult<String> {
+    check_num_qubits(&op, num_qubits)?;
+    op.inner
+        .check_coeffs()
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let mapped = jordan_wigner(&op.inner, num_qubits);
+    let obs = unsafe { qiskit_sys::qk_obs_canonicalize(mapped, atol) };
+    unsafe { qiskit_sys::qk_obs_free(mapped) };
+
+    let num_terms = unsafe { qiskit_sys::qk_obs_num_terms(obs) };
+    let mut terms = Vec::with_capacity(num_terms as usize);
+    let mut term = MaybeUninit::<qiskit_sys::QkObsTerm>::uninit();
+    for j in 0..num_terms {
+        let term = unsafe {
+            qiskit_sys::qk_obs_term(obs, j as u64, term.as_mut_ptr());
+            term.assume_init_ref()
+        };
+        // NOTE: finite input coefficients can still overflow when mapped terms get combined.
+        if !(term.coeff.re.is_finite() && term.coeff.im.is_finite()) {
+            unsafe { qiskit_sys::qk_obs_free(obs) };
+            return Err(PyValueError::new_err(
+                "the mapped qubit operator has a NaN or infinite coefficient",
+            ));
+        }
+        let mut paulis = vec!['I'; num_qubits as usize];
+        for k in 0..term.len {
+            let (bit_term, index) = unsafe { (*term.bit_terms.add(k), *term.indices.add(k)) };
+            paulis[(num_qubits - 1 - index) as usize] = match bit_term {
+                qiskit_sys::QkBitTerm_QkBitTerm_X => 'X',
+                qiskit_sys::QkBitTerm_QkBitTerm_Y => 'Y',
+                qiskit_sys::QkBitTerm_QkBitTerm_Z => 'Z',
+                _ => unreachable!("the Jordan-Wigner transformation only produces Pauli terms"),
+            };
+        }
+        terms.push(format!(
+            "{{\"paulis\": \"{}\", \"coeff\": [{:?}, {:?}]}}",
+            paulis.iter().collect::<String>(),
+            term.coeff.re,
+            term.coeff.im,
+        ));
     }
+
+    unsafe { qiskit_sys::qk_obs_free(obs) };
+
+    Ok(format!(
+        "{{\"version\": {JSON_SCHEMA_VERSION}, \"num_qubits\": {num_qubits}, \"terms\": [{}]}}",
+        terms.join(", ")
+    ))
+}
+
+/// Builds a `QkObs` from the data of a :class:`~qiskit.quantum_info.SparseObservable`. The caller
+/// owns the returned observable.
+fn obs_from_python(obs: &Bound<'_, PyAny>) -> PyResult<*mut qiskit_sys::QkObs> {
+    let num_qubits: u32 = obs.getattr("num_qubits")?.extract()?;
+    let mut coeffs = obs
+        .getattr("coeffs")?
+        .try_iter()?
+        .map(|coeff| {
+            let coeff: Complex64 = coeff?.extract()?;
+            Ok(qiskit_sys::QkComplex64 {
+                re: coeff.re,
+                im: coeff.im,
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let mut bit_terms = obs
+        .getattr("bit_terms")?
+        .try_iter()?
+        .map(|bit_term| Ok(bit_term?.extract::<u8>()? as qiskit_sys::QkBitTerm))
+        .collect::<PyResult<Vec<_>>>()?;
+    let mut indices = obs
+        .getattr("indices")?
+        .try_iter()?
+        .map(|index| index?.extract())
+        .collect::<PyResult<Vec<u32>>>()?;
+    let mut boundaries = obs
+        .getattr("boundaries")?
+        .try_iter()?
+        .map(|boundary| boundary?.extract())
+        .collect::<PyResult<Vec<usize>>>()?;
+
+    Ok(unsafe {
+        qiskit_sys::qk_obs_new(
+            num_qubits,
+            coeffs.len() as u64,
+            bit_terms.len() as u64,
+            coeffs.as_mut_ptr(),
+            bit_terms.as_mut_ptr(),
+            indices.as_mut_ptr(),
+            boundaries.as_mut_ptr(),
+        )
+    })
+}
+
+/// Map a :class:`~qiskit.quantum_info.SparseObservable` back onto a :class:`.FermionOperator` by
+/// inverting the Jordan-Wigner transformation (see :func:`.jordan_wigner`).
+///
+/// The resulting operator acts on as many fermionic modes as ``obs`` has qubits. Projector terms
+/// get expanded into Pauli strings and the result is normal-ordered and simplified.
+///
+/// .. doctest::
+///     >>> from qiskit.quantum_info import SparseObservable
+///     >>> from qiskit_fermions.mappers.library import inverse_jordan_wigner
+///     >>> print(inverse_jordan_wigner(SparseObservable.from_label("1")))
+///       1.000000e0 +0.000000e0j * (+_0 -_0)
+///
+/// Args:
+///     obs: the qubit observable to map.
+///
+/// Returns:
+///     The fermionic operator whose Jordan-Wigner image is ``obs``.
+#[gen_stub_pyfunction(module = "qiskit_fermions.mappers.library.jordan_wigner")]
+#[pyfunction(name = "inverse_jordan_wigner")]
+pub fn py_inverse_jordan_wigner(
+    #[gen_stub(override_type(type_repr="qiskit.quantum_info.SparseObservable", imports=("qiskit.quantum_info")))]
+    obs: &Bound<'_, PyAny>,
+) -> PyResult<PyFermionOperator> {
+    let obs = obs_from_python(obs)?;
+    let inner = unsafe { inverse_jordan_wigner(obs) };
+    unsafe { qiskit_sys::qk_obs_free(obs) };
+    Ok(PyFermionOperator { inner })
+}
+
+/// Like :func:`.inverse_jordan_wigner`, but only keeps the terms acting on the first
+/// ``num_modes`` fermionic modes and verifies that mapping the result with :func:`.jordan_wigner`
+/// reproduces ``obs``.
+///
+/// This detects observables which are not the image of an operator on ``num_modes`` modes, for
+/// which the inverse is ill-defined.
+///
+/// .. doctest::
+///     >>> from qiskit.quantum_info import SparseObservable
+///     >>> from qiskit_fermions.mappers.library import inverse_jordan_wigner_checked
+///     >>> print(inverse_jordan_wigner_checked(SparseObservable.from_label("I1"), 1))
+///       1.000000e0 +0.000000e0j * (+_0 -_0)
+///     >>> inverse_jordan_wigner_checked(SparseObservable.from_label("1I"), 1)
+///     Traceback (most recent call last):
+///     ...
+///     ValueError: The observable is not the Jordan-Wigner image of an operator on 1 modes, 2 terms differ after mapping it back.
+///
+/// Args:
+///     obs: the qubit observable to map.
+///     num_modes: the number of fermionic modes of the resulting operator.
+///
+/// Returns:
+///     The fermionic operator whose Jordan-Wigner image is ``obs``.
+///
+/// Raises:
+///     ValueError: if ``obs`` is not the image of an operator on ``num_modes`` modes.
+#[gen_stub_pyfunction(module = "qiskit_fermions.mappers.library.jordan_wigner")]
+#[pyfunction(name = "inverse_jordan_wigner_checked")]
+pub fn py_inverse_jordan_wigner_checked(
+    #[gen_stub(override_type(type_repr="qiskit.quantum_info.SparseObservable", imports=("qiskit.quantum_info")))]
+    obs: &Bound<'_, PyAny>,
+    num_modes: u32,
+) -> PyResult<PyFermionOperator> {
+    let obs = obs_from_python(obs)?;
+    let inner = unsafe { inverse_jordan_wigner_checked(obs, num_modes) };
+    unsafe { qiskit_sys::qk_obs_free(obs) };
+    Ok(PyFermionOperator {
+        inner: inner.map_err(|err| PyValueError::new_err(err.to_string()))?,
+    })
 }
 
 #[pymodule]
 pub mod jordan_wigner {
+    #[pymodule_export]
+    use super::py_inverse_jordan_wigner;
+    #[pymodule_export]
+    use super::py_inverse_jordan_wigner_checked;
     #[pymodule_export]
     use super::py_jordan_wigner;
+    #[pymodule_export]
+    use super::py_jordan_wigner_to_json;
 }
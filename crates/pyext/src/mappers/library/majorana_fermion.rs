@@ -12,10 +12,11 @@
 
 use crate::operators::fermion_operator::PyFermionOperator;
 use crate::operators::majorana_operator::PyMajoranaOperator;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3_stub_gen::derive::*;
 use qiskit_fermions_core::mappers::library::majorana_fermion::{
-    fermion_to_majorana, majorana_to_fermion,
+    MajoranaConvention, fermion_to_majorana, majorana_to_fermion,
 };
 
 /// Map a :class:`.FermionOperator` to a :class:`.MajoranaOperator`.
@@ -64,6 +65,79 @@ pub fn py_fermion_to_majorana(fer_op: PyFermionOperator) -> PyMajoranaOperator {
     }
 }
 
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFermionOperator {
+    /// Maps this operator to a :class:`.MajoranaOperator` using multiple threads.
+    ///
+    /// The mapping is identical to :func:`.fermion_to_majorana`, but the terms of this operator
+    /// are processed in parallel. The order of the terms in the returned operator does not depend
+    /// on the number of threads or their scheduling.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> fer_op = FermionOperator.from_dict({((True, 0), (False, 0)): 1})
+    ///     >>> maj_op = fer_op.to_majorana(num_threads=2)
+    ///     >>> print(maj_op.normal_ordered().simplify())
+    ///      5.000000e-1 +0.000000e0j * ()
+    ///      0.000000e0+5.000000e-1j * (1 0)
+    ///
+    /// The relation between the fermionic and Majorana operators can be changed using the
+    /// ``convention`` argument, which supports the following values:
+    ///
+    /// - ``"default"``: :math:`\gamma_j = a^\dagger_j + a_j` and
+    ///   :math:`\gamma'_j = i (a^\dagger_j - a_j)`, such that
+    ///   :math:`a^\dagger_j = \frac{1}{2} (\gamma_j - i \gamma'_j)`.
+    /// - ``"flipped"``: :math:`\gamma_j = a_j + a^\dagger_j` and
+    ///   :math:`\gamma'_j = i (a_j - a^\dagger_j)`, such that
+    ///   :math:`a^\dagger_j = \frac{1}{2} (\gamma_j + i \gamma'_j)`.
+    /// - ``"normalized"``: :math:`\gamma_j = \frac{1}{\sqrt{2}} (a^\dagger_j + a_j)` and
+    ///   :math:`\gamma'_j = \frac{i}{\sqrt{2}} (a^\dagger_j - a_j)`, such that
+    ///   :math:`a^\dagger_j = \frac{1}{\sqrt{2}} (\gamma_j - i \gamma'_j)`.
+    ///
+    /// .. warning::
+    ///    The Majorana operators of the ``"normalized"`` convention square to :math:`1/2`, whereas
+    ///    methods like :meth:`.MajoranaOperator.normal_ordered` assume them to square to :math:`1`.
+    ///
+    /// .. doctest::
+    ///     >>> op = FermionOperator.from_dict({((True, 0),): 1})
+    ///     >>> print(op.to_majorana(convention="flipped"))
+    ///      5.000000e-1 +0.000000e0j * (0)
+    ///      0.000000e0+5.000000e-1j * (1)
+    ///
+    /// Args:
+    ///     num_threads: the maximum number of threads to use. If ``None``, all threads of the
+    ///         global thread pool are used.
+    ///     convention: the Majorana convention to use.
+    ///
+    /// Returns:
+    ///     The mapped Majorana operator.
+    ///
+    /// Raises:
+    ///     ValueError: if ``convention`` is not supported.
+    #[pyo3(signature = (num_threads=None, convention="default"))]
+    fn to_majorana(
+        &self,
+        num_threads: Option<usize>,
+        convention: &str,
+    ) -> PyResult<PyMajoranaOperator> {
+        let convention = match convention {
+            "default" => MajoranaConvention::Default,
+            "flipped" => MajoranaConvention::FlippedSign,
+            "normalized" => MajoranaConvention::Normalized,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unsupported convention '{convention}'. Expected 'default', 'flipped' or \
+                     'normalized'."
+                )));
+            }
+        };
+        Ok(PyMajoranaOperator {
+            inner: self.inner.to_majorana(convention, num_threads),
+        })
+    }
+}
+
 /// Map a :class:`.MajoranaOperator` to a :class:`.FermionOperator`.
 ///
 /// Args:
@@ -81,13 +155,17 @@ pub fn py_fermion_to_majorana(fer_op: PyFermionOperator) -> PyMajoranaOperator {
 ///
 /// .. math::
 ///
-///    \gamma_j \rightarrow a^\dagger_j + a_j ~~\text{and}~~
-///    \gamma'_j \rightarrow i (a^\dagger_j - a_j)
+///    \gamma_j \rightarrow a^\dagger_j + i a_j ~~\text{and}~~
+///    \gamma'_j \rightarrow a^\dagger_j - i a_j
 ///
 /// where :math:`\gamma_j`/:math:`\gamma'_j` are the two Majorana fermion operators (stored on the
 /// even and odd modes, respectively), and :math:`a^\dagger_j` (:math:`a_j`) is the fermionic
 /// creation (annihilation) operator acting on the :math:`j`-th spin-less fermionic mode.
 ///
+/// .. note::
+///    This transformation is **not** the inverse of :func:`.fermion_to_majorana`, which relies on
+///    :math:`\gamma_j = a^\dagger_j + a_j` and :math:`\gamma'_j = i (a^\dagger_j - a_j)`.
+///
 /// Usage
 /// =====
 ///
@@ -109,6 +187,39 @@ pub fn py_majorana_to_fermion(maj_op: PyMajoranaOperator) -> PyFermionOperator {
     }
 }
 
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyMajoranaOperator {
+    /// Maps this operator to a :class:`.FermionOperator`.
+    ///
+    /// The mapping is identical to :func:`.majorana_to_fermion`. Since its result is generally
+    /// not normal-ordered, this method by default also normal-orders and simplifies it.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> maj_op = MajoranaOperator.from_dict({(0, 1): 1})
+    ///     >>> print(maj_op.to_fermion())
+    ///      0.000000e0 -1.000000e0j * ()
+    ///      0.000000e0 +2.000000e0j * (+_0 -_0)
+    ///
+    /// Args:
+    ///     normal_order: whether to normal-order and simplify the mapped operator.
+    ///     atol: the absolute tolerance used for simplification. This is ignored when
+    ///         ``normal_order`` is ``False``.
+    ///
+    /// Returns:
+    ///     The mapped fermion operator.
+    #[pyo3(signature = (normal_order=true, atol=1e-8))]
+    fn to_fermion(&self, normal_order: bool, atol: f64) -> PyFermionOperator {
+        let inner = if normal_order {
+            self.inner.to_fermion_normal_ordered(atol)
+        } else {
+            majorana_to_fermion(&self.inner)
+        };
+        PyFermionOperator { inner }
+    }
+}
+
 #[pymodule]
 pub mod majorana_fermion {
     #[pymodule_export]
@@ -11,14 +11,20 @@
 // that they have been altered from the originals.
 
 use num_complex::Complex64;
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2};
 use pyo3::prelude::*;
-use pyo3::types::PyType;
-use pyo3::{class::basic::CompareOp, exceptions::PyNotImplementedError};
+use pyo3::types::{PyDict, PyInt, PyType};
+use pyo3::{
+    class::basic::CompareOp,
+    exceptions::{PyAssertionError, PyNotImplementedError, PyValueError},
+};
 use pyo3_stub_gen::derive::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use qiskit_fermions_core::operators::fermion_operator::FermionOperator;
-use qiskit_fermions_core::operators::{OperatorMacro, OperatorTrait};
+use qiskit_fermions_core::operators::fermion_operator::{
+    CoeffStyle, FermionOperator, FermionOperatorF32,
+};
+use qiskit_fermions_core::operators::{MAX_POW_TERMS, OperatorMacro, OperatorTrait};
 
 pub type PyFermionAction = (bool, u32);
 
@@ -43,6 +49,42 @@ impl FermionOperatorDataIter {
     }
 }
 
+#[gen_stub_pyclass]
+#[pyclass(
+    module = "qiskit_fermions.operators.fermion_operator",
+    name = "FermionOperatorParityIter"
+)]
+struct FermionOperatorParityIter {
+    inner: std::vec::IntoIter<(Vec<PyFermionAction>, Complex64, i32)>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl FermionOperatorParityIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(Vec<PyFermionAction>, Complex64, i32)> {
+        slf.inner.next()
+    }
+}
+
+/// Finds the first mode index in the keys of ``data`` which is an integer that does not fit into a
+/// `u32` and returns a descriptive error for it. All other malformed input is left to pyo3's own
+/// conversion errors.
+fn invalid_index_error(data: &Bound<'_, PyDict>) -> Option<PyErr> {
+    data.keys().iter().find_map(|key| {
+        key.try_iter().ok()?.flatten().find_map(|action| {
+            let (_, idx): (Bound<PyAny>, Bound<PyAny>) = action.extract().ok()?;
+            let out_of_range = idx.is_instance_of::<PyInt>() && idx.extract::<u32>().is_err();
+            out_of_range.then(|| {
+                PyValueError::new_err(format!("mode index {idx} out of range in key {key}"))
+            })
+        })
+    })
+}
+
 /// A spin-less fermionic operator.
 ///
 /// ----
@@ -147,6 +189,9 @@ impl FermionOperatorDataIter {
 ///
 ///    zero
 ///    one
+///    parity_operator
+///    determinant_projector
+///    from_hopping
 ///
 /// Iteration
 /// ---------
@@ -175,6 +220,7 @@ impl FermionOperatorDataIter {
 /// .. autosummary::
 ///
 ///    iter_terms
+///    iter_terms_with_parity
 ///
 /// Arithmetics
 /// -----------
@@ -219,7 +265,8 @@ impl FermionOperatorDataIter {
 /// .. note::
 ///    Operator composition corresponds to left-multiplication: ``c = a & b`` corresponds to
 ///    :math:`C = B A`. In other words, the composition of two operators returns a resulting
-///    operator that performs "first ``a`` and then ``b``".
+///    operator that performs "first ``a`` and then ``b``". If the mathematical (left-to-right)
+///    convention is preferred, use :meth:`.matmul` instead: ``a.matmul(b)`` equals ``b & a``.
 ///
 /// .. doctest::
 ///     >>> op1 = FermionOperator.from_dict({(): 2.0, (cre(0),): 3.0})
@@ -251,9 +298,49 @@ impl FermionOperatorDataIter {
 /// .. autosummary::
 ///
 ///    adjoint
+///    conjugate
+///    matmul
+///    tensor
+///    scaled_iadd
+///    scale_and_shift
+///    conjugate_by
+///    extend_terms
 ///    ichop
+///    purge_nan_inf
+///    round_coeffs
 ///    simplify
+///    sort_terms
+///    sorted
+///    chop_relative
+///    term_count_vs_threshold
+///    chunks
+///    term_operators
+///    filter_terms
+///    map_coeffs
+///    restrict_to_modes
+///    remove_constant
+///    add_constant
 ///    normal_ordered
+///    normal_ordered_with_provenance
+///    hermitian_generators
+///    drop_vanishing_terms
+///    group_by_order
+///    support_histogram
+///    to_majorana
+///    rotate_orbitals
+///    trace_out_vacuum
+///    compactify_indices
+///    apply_to_state
+///    matrix_element
+///    fock_diagonal
+///    to_sparse_matrix
+///    to_1body_matrix
+///    quadratic_spectrum
+///    ground_energy
+///    to_str
+///    to_latex
+///    expectation_determinant
+///    astype
 ///
 /// Properties
 /// ^^^^^^^^^^
@@ -263,8 +350,23 @@ impl FermionOperatorDataIter {
 /// .. autosummary::
 ///
 ///    is_hermitian
+///    is_real
+///    has_invalid_coeffs
+///    coeff_dtype
 ///    many_body_order
+///    num_actions
+///    average_term_length
 ///    conserves_particle_number
+///    conserves_spin_numbers
+///    is_quadratic
+///    is_constant
+///    is_identity
+///    index_range
+///    coefficient_sum
+///    coefficient_stats
+///    constant
+///    equiv_up_to_phase
+///    global_phase
 ///
 /// ----
 ///
@@ -322,8 +424,16 @@ impl PyFermionOperator {
     ///
     /// Returns:
     ///     A new operator.
+    ///
+    /// Raises:
+    ///     ValueError: if a mode index is negative or does not fit into a 32-bit unsigned integer.
     #[classmethod]
-    fn from_dict(_cls: &Bound<'_, PyType>, data: HashMap<Vec<(bool, u32)>, Complex64>) -> Self {
+    fn from_dict(_cls: &Bound<'_, PyType>, data: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let data: HashMap<Vec<(bool, u32)>, Complex64> = match data.extract() {
+            Ok(data) => data,
+            Err(err) => return Err(invalid_index_error(data).unwrap_or(err)),
+        };
+
         let mut coeffs = vec![];
         let mut actions = vec![];
         let mut indices = vec![];
@@ -338,14 +448,76 @@ impl PyFermionOperator {
             boundaries.push(indices.len());
         });
 
-        Self {
+        Ok(Self {
             inner: FermionOperator {
                 coeffs,
                 actions,
                 indices,
                 boundaries,
             },
+        })
+    }
+
+    /// Constructs a new operator from a dictionary with string labels.
+    ///
+    /// Each label consists of space-separated actions. An action is given by the index of the
+    /// fermionic mode it acts upon, followed by ``^`` in the case of a creation operator. For
+    /// example, ``"0^ 1"`` corresponds to :math:`a^\dagger_0 a_1` and the empty string ``""``
+    /// corresponds to the identity.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_label_dict({"": 1.0-1.0j, "0^ 1": 2.0})
+    ///     >>> print(op)
+    ///       1.000000e0 -1.000000e0j * ()
+    ///       2.000000e0 +0.000000e0j * (+_0 -_1)
+    ///     >>> op.equiv(FermionOperator.from_dict({(): 1.0-1.0j, (cre(0), ann(1)): 2.0}))
+    ///     True
+    ///
+    /// Args:
+    ///     data: a dictionary mapping string labels to complex coefficients.
+    ///
+    /// Returns:
+    ///     A new operator.
+    ///
+    /// Raises:
+    ///     ValueError: if a label contains a malformed action.
+    #[classmethod]
+    fn from_label_dict(
+        _cls: &Bound<'_, PyType>,
+        data: HashMap<String, Complex64>,
+    ) -> PyResult<Self> {
+        let mut coeffs = vec![];
+        let mut actions = vec![];
+        let mut indices = vec![];
+        let mut boundaries = vec![0];
+
+        for (label, coeff) in data.iter() {
+            coeffs.push(*coeff);
+            for token in label.split_whitespace() {
+                let (action, index) = match token.strip_suffix('^') {
+                    Some(index) => (true, index),
+                    None => (false, token),
+                };
+                let index = index.parse::<u32>().map_err(|_| {
+                    PyValueError::new_err(format!(
+                        "Malformed action '{token}' in the label '{label}'."
+                    ))
+                })?;
+                actions.push(action);
+                indices.push(index);
+            }
+            boundaries.push(indices.len());
         }
+
+        Ok(Self {
+            inner: FermionOperator {
+                coeffs,
+                actions,
+                indices,
+                boundaries,
+            },
+        })
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, _py: Python<'_>) -> PyResult<bool> {
@@ -412,19 +584,92 @@ impl PyFermionOperator {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        let mut sorted: Vec<_> = self.inner.iter().collect();
-        sorted.sort_by_key(|&term| term.into_vec());
-        let mut items_str = Vec::new();
-        for term in sorted {
-            let key_parts: Vec<String> = term
-                .iter()
-                .map(|(action, orb)| format!("{}_{}", if *action { "+" } else { "-" }, orb))
-                .collect();
-            let key_str = format!("({})", key_parts.join(" "));
-            let val_str = format!("{:12.6e}{:+12.6e}j", term.coeff.re, term.coeff.im);
-            items_str.push(format!("{val_str} * {key_str}"));
+        Ok(self.inner.to_string())
+    }
+
+    fn __format__(&self, format_spec: &str) -> PyResult<String> {
+        if format_spec.is_empty() {
+            return Ok(self.inner.to_string());
         }
-        Ok(items_str.join("\n").to_string())
+        let (precision, style) = format_spec
+            .strip_prefix('.')
+            .and_then(|spec| {
+                let style = match spec.chars().last()? {
+                    'e' => CoeffStyle::Scientific,
+                    'f' => CoeffStyle::Fixed,
+                    _ => return None,
+                };
+                let precision = spec[..spec.len() - 1].parse().ok()?;
+                Some((precision, style))
+            })
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Invalid format specifier '{format_spec}', expected e.g. '.3e' or '.3f'."
+                ))
+            })?;
+        Ok(self.inner.to_str(precision, style))
+    }
+
+    /// Renders this operator with a controllable coefficient precision.
+    ///
+    /// This is identical to ``str(op)`` (for the default arguments), except that the real and
+    /// imaginary parts of the coefficients are rendered with ``precision`` digits after the
+    /// decimal point, in the given ``style``. Equivalently, a format specifier like ``.3f`` or
+    /// ``.3e`` may be used in an f-string, e.g. ``f"{op:.3f}"``.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 2.0, (): -1.0})
+    ///     >>> print(op.to_str(precision=2, style="fixed"))
+    ///        -1.00   +0.00j * ()
+    ///         2.00   +0.00j * (+_0 -_1)
+    ///
+    /// Args:
+    ///     precision: the number of digits after the decimal point. This value defaults to ``6``.
+    ///     style: the notation of the coefficients, either ``"sci"`` for scientific or ``"fixed"``
+    ///         for fixed-point notation. This value defaults to ``"sci"``.
+    ///
+    /// Returns:
+    ///     The rendered operator.
+    ///
+    /// Raises:
+    ///     ValueError: if ``style`` is invalid.
+    #[pyo3(signature = (precision=6, style="sci"))]
+    fn to_str(&self, precision: usize, style: &str) -> PyResult<String> {
+        let style = match style {
+            "sci" => CoeffStyle::Scientific,
+            "fixed" => CoeffStyle::Fixed,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid style '{style}', expected 'sci' or 'fixed'."
+                )));
+            }
+        };
+        Ok(self.inner.to_str(precision, style))
+    }
+
+    /// Renders this operator as a LaTeX string.
+    ///
+    /// The terms are sorted like in :meth:`to_str` and joined into a sum. Creation and
+    /// annihilation operators are rendered as :math:`a^\dagger_j` and :math:`a_j`, respectively.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 0.5, (): -1.0, (ann(2),): 2.0j})
+    ///     >>> print(op.to_latex())
+    ///     -1 + 2i\, a_2 + 0.5\, a^\dagger_0 a_1
+    ///     >>> print(op.to_latex(max_terms=1))
+    ///     -1 + \cdots
+    ///
+    /// Args:
+    ///     max_terms: if provided, at most this many terms are rendered and the remaining ones
+    ///         are elided with ``\cdots``.
+    ///
+    /// Returns:
+    ///     The LaTeX string, which is ``"0"`` for an operator without terms.
+    #[pyo3(signature = (max_terms=None))]
+    fn to_latex(&self, max_terms: Option<usize>) -> String {
+        self.inner.to_latex(max_terms)
     }
 
     /// Constructs the additive identity operator.
@@ -465,6 +710,114 @@ impl PyFermionOperator {
         }
     }
 
+    /// Constructs the fermionic parity operator.
+    ///
+    /// The parity operator is defined as :math:`P = \prod_i (1 - 2 n_i)`, where
+    /// :math:`n_i = a^\dagger_i a_i` is the number operator of the :math:`i`-th mode.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> parity = FermionOperator.parity_operator(1)
+    ///     >>> print(parity.simplify())
+    ///       1.000000e0 +0.000000e0j * ()
+    ///      -2.000000e0 +0.000000e0j * (+_0 -_0)
+    ///     >>> squared = (parity & parity).normal_ordered().simplify()
+    ///     >>> squared.equiv(FermionOperator.one())
+    ///     True
+    ///
+    /// Args:
+    ///     num_modes: the number of fermionic modes.
+    ///
+    /// Returns:
+    ///     The parity operator acting on ``num_modes`` modes.
+    #[classmethod]
+    fn parity_operator(_cls: &Bound<'_, PyType>, num_modes: u32) -> Self {
+        Self {
+            inner: FermionOperator::parity_operator(num_modes),
+        }
+    }
+
+    /// Constructs the projector onto a single Slater determinant.
+    ///
+    /// The projector is defined as
+    ///
+    /// .. math::
+    ///
+    ///    P = \prod_{i \in \text{occ}} a^\dagger_i a_i \prod_{j \notin \text{occ}} a_j a^\dagger_j \, ,
+    ///
+    /// and is returned in normal-ordered and simplified form.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> projector = FermionOperator.determinant_projector(2, [0])
+    ///     >>> print(projector)
+    ///       1.000000e0 +0.000000e0j * (+_0 -_0)
+    ///       1.000000e0 +0.000000e0j * (+_1 +_0 -_1 -_0)
+    ///
+    /// Args:
+    ///     num_modes: the total number of fermionic modes.
+    ///     occupied: the indices of the occupied modes.
+    ///
+    /// Returns:
+    ///     The projector onto the specified Slater determinant.
+    ///
+    /// Raises:
+    ///     ValueError: if an occupied mode is not smaller than ``num_modes``.
+    #[classmethod]
+    fn determinant_projector(
+        _cls: &Bound<'_, PyType>,
+        num_modes: u32,
+        occupied: Vec<u32>,
+    ) -> PyResult<Self> {
+        if let Some(index) = occupied.iter().find(|&&i| i >= num_modes) {
+            return Err(PyValueError::new_err(format!(
+                "Occupied mode {index} is out of range for {num_modes} modes."
+            )));
+        }
+        Ok(Self {
+            inner: FermionOperator::determinant_projector(num_modes, &occupied),
+        })
+    }
+
+    /// Constructs a lattice Hamiltonian from hopping edges and on-site energies.
+    ///
+    /// The operator is defined as
+    ///
+    /// .. math::
+    ///
+    ///    H = \sum_{(i, j, t)} \left( t a^\dagger_i a_j + t^* a^\dagger_j a_i \right)
+    ///        + \sum_{(i, U)} U a^\dagger_i a_i \, ,
+    ///
+    /// which is Hermitian for real on-site energies. Every edge contributes two terms and every
+    /// on-site energy one term. Duplicate entries are not merged.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_hopping([(0, 1, -1.0)], [(0, 0.5)])
+    ///     >>> print(op)
+    ///      5.000000e-1 +0.000000e0j * (+_0 -_0)
+    ///      -1.000000e0 +0.000000e0j * (+_0 -_1)
+    ///      -1.000000e0 -0.000000e0j * (+_1 -_0)
+    ///
+    /// Args:
+    ///     edges: a list of ``(i, j, t)`` tuples, each describing the hopping between the modes
+    ///         ``i`` and ``j`` with amplitude ``t``.
+    ///     onsite: a list of ``(i, U)`` tuples, each describing the energy ``U`` of mode ``i``.
+    ///
+    /// Returns:
+    ///     The lattice Hamiltonian.
+    #[classmethod]
+    #[pyo3(signature = (edges, onsite=vec![]))]
+    fn from_hopping(
+        _cls: &Bound<'_, PyType>,
+        edges: Vec<(u32, u32, Complex64)>,
+        onsite: Vec<(u32, Complex64)>,
+    ) -> Self {
+        Self {
+            inner: FermionOperator::from_hopping_edges(&edges, &onsite),
+        }
+    }
+
     fn __len__(&self) -> usize {
         self.inner.boundaries.len() - 1
     }
@@ -472,12 +825,14 @@ impl PyFermionOperator {
     fn __pow__(&self, exponent: u32, modulo: Option<u32>) -> PyResult<Self> {
         match modulo {
             Some(_) => Err(PyNotImplementedError::new_err("mod argument not supported")),
-            None => {
-                let result = Self {
-                    inner: self.inner.__pow__(exponent as usize),
-                };
-                Ok(result)
-            }
+            None => match self.inner.checked_pow(exponent as usize, MAX_POW_TERMS) {
+                Some(inner) => Ok(Self { inner }),
+                None => Err(PyValueError::new_err(format!(
+                    "Raising an operator with {} terms to the power {exponent} exceeds the \
+                     maximum of {MAX_POW_TERMS} terms.",
+                    self.inner.coeffs.len()
+                ))),
+            },
         }
     }
 
@@ -503,18 +858,135 @@ impl PyFermionOperator {
     ///     >>> op.ichop(1e-4)
     ///     >>> assert op.equiv(op.zero(), 1e-6)
     ///
+    /// Two strategies exist for finding equal terms. The default ``"hashmap"`` strategy collects
+    /// the terms in a hash map, while the ``"sorted"`` strategy sorts the terms first (see
+    /// :meth:`.sort_terms`) and then merges adjacent equal terms in a single pass. The latter can
+    /// be faster for large operators whose terms are already grouped, such as those constructed
+    /// from electronic integrals. Both strategies return the terms in the same canonical order.
+    ///
+    /// Since ``NaN`` never exceeds ``atol``, terms with ``NaN`` coefficients are silently
+    /// dropped. Use ``strict=True`` to detect such coefficients instead (see also
+    /// :meth:`has_invalid_coeffs`).
+    ///
     /// Args:
     ///     atol: the absolute tolerance for the cutoff. This value defaults to ``1e-8``.
+    ///     method: the strategy for merging equal terms, either ``"hashmap"`` (the default) or
+    ///         ``"sorted"``.
+    ///     strict: whether to raise an error when encountering ``NaN`` or infinite
+    ///         coefficients.
     ///
     /// Returns:
     ///     An equivalent but simplified operator.
-    #[pyo3(signature = (atol=1e-8))]
-    fn simplify(&mut self, atol: f64) -> Self {
+    ///
+    /// Raises:
+    ///     ValueError: if ``method`` is neither ``"hashmap"`` nor ``"sorted"``, or if ``strict``
+    ///         is set and a coefficient is ``NaN`` or infinite.
+    #[pyo3(signature = (atol=1e-8, method="hashmap", strict=false))]
+    fn simplify(&mut self, atol: f64, method: &str, strict: bool) -> PyResult<Self> {
+        if strict {
+            self.inner
+                .check_coeffs()
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        }
+        let inner = match method {
+            "hashmap" => self.inner.simplify(atol),
+            "sorted" => {
+                let mut inner = self.inner.clone();
+                inner.simplify_sorted(atol);
+                inner
+            }
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid simplification method '{method}', expected 'hashmap' or 'sorted'."
+                )));
+            }
+        };
+        Ok(Self { inner })
+    }
+
+    /// Reorders the terms of this operator into a canonical order, in-place.
+    ///
+    /// The terms are sorted by their actions (as yielded by :meth:`iter_terms`) and then by
+    /// their coefficients. Unlike :meth:`simplify`, duplicate terms are **not** merged. This makes
+    /// the internal data of operators, which only differ in the order of their terms, identical.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 1),): 1.0, ((True, 0),): 2.0})
+    ///     >>> op.sort_terms()
+    ///     >>> op
+    ///     FermionOperator.from_dict({((True, 0)): 2+0j, ((True, 1)): 1+0j})
+    fn sort_terms(&mut self) {
+        self.inner.sort_terms();
+    }
+
+    /// Returns a copy of this operator with its terms in a canonical order.
+    ///
+    /// See :meth:`sort_terms` for more details.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op1 = FermionOperator([1.0, 2.0], [True, True], [1, 0], [0, 1, 2])
+    ///     >>> op2 = FermionOperator([2.0, 1.0], [True, True], [0, 1], [0, 1, 2])
+    ///     >>> op1 == op2
+    ///     False
+    ///     >>> op1.sorted() == op2.sorted()
+    ///     True
+    ///
+    /// Returns:
+    ///     The sorted operator.
+    fn sorted(&self) -> Self {
+        let mut inner = self.inner.clone();
+        inner.sort_terms();
+        Self { inner }
+    }
+
+    /// Returns a simplified operator without terms whose coefficient magnitude lies below a
+    /// fraction of the largest one.
+    ///
+    /// Unlike :meth:`.simplify`, which uses an absolute cutoff, this method is agnostic to the
+    /// overall scale of the coefficients. Since terms are first simplified (see
+    /// :meth:`.simplify`), no information is lost due to duplicate terms.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0),): 1e-3, ((False, 0),): 1e-7})
+    ///     >>> print(op.chop_relative(1e-4))
+    ///       1.000000e0 +0.000000e0j * ()
+    ///      1.000000e-3 +0.000000e0j * (+_0)
+    ///
+    /// Args:
+    ///     rtol: the cutoff relative to the largest coefficient magnitude. A value of ``0`` keeps
+    ///         all terms.
+    ///
+    /// Returns:
+    ///     An equivalent but truncated and simplified operator.
+    fn chop_relative(&self, rtol: f64) -> Self {
         Self {
-            inner: self.inner.simplify(atol),
+            inner: self.inner.chop_relative(rtol),
         }
     }
 
+    /// Returns the number of terms surviving :meth:`.simplify` at each of the given tolerances.
+    ///
+    /// The operator is simplified only once, making this a cheap way of choosing a truncation
+    /// tolerance.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0),): 1e-3, ((False, 0),): 1e-7})
+    ///     >>> op.term_count_vs_threshold([1e-8, 1e-5, 1e-2])
+    ///     [3, 2, 1]
+    ///
+    /// Args:
+    ///     thresholds: the absolute tolerances to evaluate.
+    ///
+    /// Returns:
+    ///     The number of remaining terms for each tolerance, in the order of ``thresholds``.
+    fn term_count_vs_threshold(&self, thresholds: Vec<f64>) -> Vec<usize> {
+        self.inner.term_count_vs_threshold(&thresholds)
+    }
+
     /// Removes terms whose coefficient magnitude lies below the provided threshold.
     ///
     /// .. caution::
@@ -538,9 +1010,72 @@ impl PyFermionOperator {
     ///
     /// Args:
     ///     atol: the absolute tolerance for the cutoff. This value defaults to ``1e-8``.
-    #[pyo3(signature = (atol=1e-8))]
-    fn ichop(&mut self, atol: f64) {
+    ///     strict: whether to raise an error when encountering ``NaN`` or infinite
+    ///         coefficients, which would otherwise be dropped or kept silently. The operator is
+    ///         left unchanged in that case.
+    ///
+    /// Raises:
+    ///     ValueError: if ``strict`` is set and a coefficient is ``NaN`` or infinite.
+    #[pyo3(signature = (atol=1e-8, strict=false))]
+    fn ichop(&mut self, atol: f64, strict: bool) -> PyResult<()> {
+        if strict {
+            self.inner
+                .check_coeffs()
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        }
         self.inner.ichop(atol);
+        Ok(())
+    }
+
+    /// Returns whether any coefficient of this operator is ``NaN`` or infinite.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0),): float("nan")})
+    ///     >>> op.has_invalid_coeffs()
+    ///     True
+    ///     >>> op.purge_nan_inf().has_invalid_coeffs()
+    ///     False
+    ///
+    /// Returns:
+    ///     Whether any coefficient is invalid.
+    fn has_invalid_coeffs(&self) -> bool {
+        self.inner.has_invalid_coeffs()
+    }
+
+    /// Returns this operator without the terms whose coefficients are ``NaN`` or infinite.
+    ///
+    /// Returns:
+    ///     The operator with only finite coefficients.
+    fn purge_nan_inf(&self) -> Self {
+        Self {
+            inner: self.inner.purge_nan_inf(),
+        }
+    }
+
+    /// Returns this operator with the coefficients rounded to a given number of decimals.
+    ///
+    /// This is useful for cleaning up numerical noise like ``0.9999999999998`` accumulated
+    /// during arithmetic. The real and imaginary parts are rounded separately, with ties
+    /// rounded away from zero. Terms whose coefficients round to zero are kept; use
+    /// :meth:`simplify` to remove them.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0),): 0.9999999 + 0.1234567j})
+    ///     >>> print(op.round_coeffs(3))
+    ///       1.000000e0+1.230000e-1j * (+_0)
+    ///
+    /// Args:
+    ///     decimals: the number of decimal places to round to. Negative values round to the left
+    ///         of the decimal point.
+    ///
+    /// Returns:
+    ///     The operator with rounded coefficients.
+    fn round_coeffs(&self, decimals: i32) -> Self {
+        Self {
+            inner: self.inner.round_coeffs(decimals),
+        }
     }
 
     /// An iterator over the operator's terms.
@@ -554,14 +1089,87 @@ impl PyFermionOperator {
     ///     >>> list(sorted(op.iter_terms()))
     ///     [([], (2+0j)), ([(False, 1)], (-0-1j)), ([(True, 0)], (1+0j))]
     ///
+    /// Terms with negligible coefficients can be skipped without constructing a chopped copy of
+    /// the operator (see :meth:`ichop`):
+    ///
+    /// .. doctest::
+    ///     >>> op = FermionOperator.from_dict({(): 2.0, ((True, 0),): 1e-10})
+    ///     >>> list(op.iter_terms(atol=1e-8))
+    ///     [([], (2+0j))]
+    ///
+    /// The terms can also be yielded in a specific order, for example to report the dominant terms
+    /// first:
+    ///
+    /// .. doctest::
+    ///     >>> op = FermionOperator.from_dict({(): 2.0, ((True, 0),): 1.0, ((False, 1),): -3.0})
+    ///     >>> list(op.iter_terms(sort="magnitude"))
+    ///     [([(False, 1)], (-3+0j)), ([], (2+0j)), ([(True, 0)], (1+0j))]
+    ///
+    /// Args:
+    ///     atol: if provided, only the terms whose coefficients exceed this value in magnitude are
+    ///         yielded. By default, all terms are yielded.
+    ///     sort: the order in which to yield the terms. ``"magnitude"`` yields them by descending
+    ///         coefficient magnitude and ``"term"`` by ascending actions. Terms which compare
+    ///         equal retain their relative order. By default, the terms are yielded in the order
+    ///         in which they are stored.
+    ///
+    /// Raises:
+    ///     ValueError: if ``sort`` is not one of the values listed above.
+    #[pyo3(signature = (atol=None, sort=None))]
+    fn iter_terms(
+        slf: PyRef<'_, Self>,
+        atol: Option<f64>,
+        sort: Option<&str>,
+    ) -> PyResult<Py<FermionOperatorDataIter>> {
+        let mut vectorized: Vec<(Vec<PyFermionAction>, Complex64)> = match atol {
+            Some(atol) => slf
+                .inner
+                .iter_nonzero(atol)
+                .map(|term| (term.into_vec(), term.coeff))
+                .collect(),
+            None => slf
+                .inner
+                .iter()
+                .map(|term| (term.into_vec(), term.coeff))
+                .collect(),
+        };
+        super::sort_vectorized_terms(&mut vectorized, sort)?;
+        let iter = FermionOperatorDataIter {
+            inner: vectorized.into_iter(),
+        };
+        Py::new(slf.py(), iter)
+    }
+
+    /// An iterator over the operator's terms, annotated with their normal-ordering parity.
+    ///
+    /// In addition to the items yielded by :meth:`iter_terms`, each item contains the sign
+    /// (``+1`` or ``-1``) which arises from reordering the term's actions into normal order (see
+    /// :meth:`normal_ordered`). This only reflects the parity of the permutation and ignores any
+    /// additional terms arising from the anti-commutation relations.
+    ///
+    /// .. warning::
+    ///    Mutating the iteration items does **not** affect the underlying operator data.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre
+    ///     >>> op = FermionOperator.from_dict({(cre(1), cre(0)): 1.0, (cre(0), cre(1)): 2.0})
+    ///     >>> list(sorted(op.iter_terms_with_parity()))
+    ///     [([(True, 0), (True, 1)], (2+0j), -1), ([(True, 1), (True, 0)], (1+0j), 1)]
+    ///
     /// ..
-    fn iter_terms(slf: PyRef<'_, Self>) -> PyResult<Py<FermionOperatorDataIter>> {
-        let vectorized: Vec<(Vec<PyFermionAction>, Complex64)> = slf
+    fn iter_terms_with_parity(slf: PyRef<'_, Self>) -> PyResult<Py<FermionOperatorParityIter>> {
+        let vectorized: Vec<(Vec<PyFermionAction>, Complex64, i32)> = slf
             .inner
             .iter()
-            .map(|term| (term.into_vec(), term.coeff))
+            .map(|term| {
+                (
+                    term.into_vec(),
+                    term.coeff,
+                    term.permutation_parity_to_normal(),
+                )
+            })
             .collect();
-        let iter = FermionOperatorDataIter {
+        let iter = FermionOperatorParityIter {
             inner: vectorized.into_iter(),
         };
         Py::new(slf.py(), iter)
@@ -589,33 +1197,172 @@ impl PyFermionOperator {
         }
     }
 
-    /// Checks this operator for equivalence with another operator.
+    /// Returns the conjugation :math:`R^\dagger O R` of this operator :math:`O` by ``rotation``.
     ///
-    /// Equivalence in this context means approximate equality up to the specified absolute
-    /// tolerance. To be more precise, this method returns ``True``, when all the absolute values
-    /// of the coefficients in the difference ``other - self`` are below the specified threshold
-    /// ``atol``.
+    /// This is equivalent to ``rotation.adjoint().matmul(self).matmul(rotation)`` (see
+    /// :meth:`.matmul`), but constructs the result in a single pass without materializing the
+    /// adjoint or any intermediate products. The result is not simplified.
     ///
     /// .. doctest::
-    ///     >>> from qiskit_fermions.operators import FermionOperator
-    ///     >>> op = FermionOperator.from_dict({(): 1e-7})
-    ///     >>> zero = FermionOperator.zero()
-    ///     >>> op.equiv(zero)
-    ///     False
-    ///     >>> op.equiv(zero, 1e-6)
-    ///     True
-    ///     >>> op.equiv(zero, 1e-9)
-    ///     False
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> number = FermionOperator.from_dict({(cre(0), ann(0)): 1.0})
+    ///     >>> print(number.conjugate_by(FermionOperator.one()))
+    ///       1.000000e0 +0.000000e0j * (+_0 -_0)
     ///
     /// Args:
-    ///     other: the other operator to compare with.
-    ///     atol: the absolute tolerance for the comparison. This value defaults to ``1e-8``.
-    #[pyo3(signature = (other, atol=1e-8))]
-    fn equiv(&self, other: &Self, atol: f64) -> bool {
-        self.inner.equiv(&other.inner, atol)
-    }
-
-    /// Returns an equivalent operator with normal ordered terms.
+    ///     rotation: the operator :math:`R` by which to conjugate this operator.
+    ///
+    /// Returns:
+    ///     The conjugated operator.
+    fn conjugate_by(&self, rotation: &Self) -> Self {
+        Self {
+            inner: self.inner.conjugate_by(&rotation.inner),
+        }
+    }
+
+    /// Returns ``scale * self + shift * FermionOperator.one()``.
+    ///
+    /// This fuses the scaling and shifting into a single operation, avoiding the intermediate
+    /// operators of the equivalent arithmetic expression. The identity term is appended and not
+    /// merged with any existing identity terms.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0), (False, 0)): 1.0})
+    ///     >>> print(op.scale_and_shift(2.0, -1.0))
+    ///      -1.000000e0 +0.000000e0j * ()
+    ///       2.000000e0 +0.000000e0j * (+_0 -_0)
+    ///
+    /// Args:
+    ///     scale: the factor by which to multiply all coefficients.
+    ///     shift: the coefficient of the added identity term.
+    ///
+    /// Returns:
+    ///     The scaled and shifted operator.
+    fn scale_and_shift(&self, scale: Complex64, shift: Complex64) -> Self {
+        Self {
+            inner: self.inner.scale_and_shift(scale, shift),
+        }
+    }
+
+    /// Returns the complex conjugate of this operator.
+    ///
+    /// Unlike :meth:`.adjoint`, this only complex conjugates the coefficients and leaves the terms
+    /// unchanged.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0), (False, 1)): 1.0j})
+    ///     >>> print(op.conjugate())  # doctest: +FLOAT_CMP
+    ///       0.000000e0 -1.000000e0j * (+_0 -_1)
+    ///     >>> print(op.adjoint())  # doctest: +FLOAT_CMP
+    ///       0.000000e0 -1.000000e0j * (+_1 -_0)
+    ///
+    /// Returns:
+    ///     The complex conjugated operator.
+    fn conjugate(&self) -> Self {
+        Self {
+            inner: self.inner.conjugate(),
+        }
+    }
+
+    /// Adds a scaled operator to this one in-place.
+    ///
+    /// This is equivalent to ``self += scale * other`` but avoids the construction of the
+    /// intermediate scaled copy of ``other``, which makes it well suited for accumulating many
+    /// weighted operators.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0})
+    ///     >>> other = FermionOperator.from_dict({((True, 0), (False, 1)): 1.0})
+    ///     >>> op.scaled_iadd(other, 3.0)
+    ///     >>> print(op)
+    ///       1.000000e0 +0.000000e0j * ()
+    ///       3.000000e0 +0.000000e0j * (+_0 -_1)
+    ///
+    /// Args:
+    ///     other: the operator to add.
+    ///     scale: the factor by which to scale the coefficients of ``other``.
+    fn scaled_iadd(&mut self, other: &Self, scale: Complex64) {
+        self.inner.scaled_iadd(&other.inner, scale);
+    }
+
+    /// Checks this operator for equivalence with another operator.
+    ///
+    /// Equivalence in this context means approximate equality up to the specified absolute
+    /// tolerance. To be more precise, this method returns ``True``, when all the absolute values
+    /// of the coefficients in the difference ``other - self`` are below the specified threshold
+    /// ``atol``.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1e-7})
+    ///     >>> zero = FermionOperator.zero()
+    ///     >>> op.equiv(zero)
+    ///     False
+    ///     >>> op.equiv(zero, 1e-6)
+    ///     True
+    ///     >>> op.equiv(zero, 1e-9)
+    ///     False
+    ///
+    /// Args:
+    ///     other: the other operator to compare with.
+    ///     atol: the absolute tolerance for the comparison. This value defaults to ``1e-8``.
+    #[pyo3(signature = (other, atol=1e-8))]
+    fn equiv(&self, other: &Self, atol: f64) -> bool {
+        self.inner.equiv(&other.inner, atol)
+    }
+
+    /// Checks this operator for equivalence with another operator up to a global phase.
+    ///
+    /// This returns ``True`` when there exists a complex phase :math:`e^{i\theta}` for which
+    /// ``e^{i\theta} * self`` is equivalent (see :meth:`equiv`) to ``other``.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 2.0})
+    ///     >>> op.equiv(1j * op)
+    ///     False
+    ///     >>> op.equiv_up_to_phase(1j * op)
+    ///     True
+    ///     >>> op.equiv_up_to_phase(2 * op)
+    ///     False
+    ///
+    /// Args:
+    ///     other: the other operator to compare with.
+    ///     atol: the absolute tolerance for the comparison. This value defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     Whether both operators are equivalent up to a global phase.
+    #[pyo3(signature = (other, atol=1e-8))]
+    fn equiv_up_to_phase(&self, other: &Self, atol: f64) -> bool {
+        self.inner.equiv_up_to_phase(&other.inner, atol)
+    }
+
+    /// Returns the global phase relating this operator to another operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 2.0})
+    ///     >>> op.global_phase(1j * op)
+    ///     1j
+    ///     >>> op.global_phase(2 * op) is None
+    ///     True
+    ///
+    /// Args:
+    ///     other: the other operator to compare with.
+    ///     atol: the absolute tolerance for the comparison. This value defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     The phase :math:`e^{i\theta}` for which ``e^{i\theta} * self`` is equivalent to
+    ///     ``other``, or ``None`` if both operators are not equivalent up to a global phase.
+    #[pyo3(signature = (other, atol=1e-8))]
+    fn global_phase(&self, other: &Self, atol: f64) -> Option<Complex64> {
+        self.inner.global_phase(&other.inner, atol)
+    }
+
+    /// Returns an equivalent operator with normal ordered terms.
     ///
     /// The normal order of an operator term is defined such that all creation actions before all
     /// annihilation actions and the indices of actions within each group descend lexicographically
@@ -626,6 +1373,9 @@ impl PyFermionOperator {
     ///    account, :math:`a_i a^\dagger_j = \delta_{ij} - a^\dagger_j a^i`, implying that the
     ///    number of terms may change.
     ///
+    /// Since normal ordering may grow the number of terms exponentially, ``max_terms`` can be
+    /// used to abort early once the result would contain more terms than that.
+    ///
     /// .. doctest::
     ///     >>> from qiskit_fermions.operators import FermionOperator
     ///     >>> op = FermionOperator.from_dict({((False, 1), (True, 1), (False, 0), (True, 0)): 1})
@@ -635,14 +1385,106 @@ impl PyFermionOperator {
     ///      -1.000000e0 +0.000000e0j * (+_1 -_1)
     ///      -1.000000e0 +0.000000e0j * (+_1 +_0 -_1 -_0)
     ///
+    /// Args:
+    ///     max_terms: the maximum number of terms which may be generated. This value defaults to
+    ///         ``None``, meaning that no limit is applied.
+    ///
     /// Returns:
     ///     An equivalent but normal-ordered operator.
-    fn normal_ordered(&self) -> Self {
+    ///
+    /// Raises:
+    ///     ValueError: if more than ``max_terms`` terms would be generated.
+    #[pyo3(signature = (max_terms=None))]
+    fn normal_ordered(&self, max_terms: Option<usize>) -> PyResult<Self> {
+        let inner = match max_terms {
+            Some(max_terms) => self
+                .inner
+                .normal_ordered_bounded(max_terms)
+                .map_err(|err| PyValueError::new_err(err.to_string()))?,
+            None => self.inner.normal_ordered(),
+        };
+        Ok(Self { inner })
+    }
+
+    /// Returns this operator without the terms which vanish identically.
+    ///
+    /// A term vanishes identically by the Pauli exclusion principle, when it acts twice with the
+    /// same action on a mode without the opposite action on that mode in between.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({
+    ///     ...     (cre(0), cre(0), ann(1), ann(2)): 1.0,
+    ///     ...     (cre(0), ann(0), cre(0)): 2.0,
+    ///     ... })
+    ///     >>> print(op.drop_vanishing_terms())
+    ///       2.000000e0 +0.000000e0j * (+_0 -_0 +_0)
+    ///
+    /// Returns:
+    ///     The operator without its identically vanishing terms.
+    fn drop_vanishing_terms(&self) -> Self {
         Self {
-            inner: self.inner.normal_ordered(),
+            inner: self.inner.drop_vanishing_terms(),
         }
     }
 
+    /// Returns an equivalent operator with normal ordered terms, together with their provenance.
+    ///
+    /// This behaves like :meth:`.normal_ordered` (without a term limit) but additionally reports,
+    /// for each term of the result, the index of the term of ``self`` from which it originated.
+    /// This is useful for tracing where the terms of a normal-ordered operator come from.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> # +_0 -_1 and -_0 +_0, in this order
+    ///     >>> op = FermionOperator(
+    ///     ...     [2.0, 1.0], [True, False, False, True], [0, 1, 0, 0], [0, 2, 4]
+    ///     ... )
+    ///     >>> ordered, provenance = op.normal_ordered_with_provenance()
+    ///     >>> len(ordered)
+    ///     3
+    ///     >>> provenance
+    ///     [0, 1, 1]
+    ///
+    /// Returns:
+    ///     A tuple of the normal-ordered operator and the list of source term indices, such that
+    ///     the ``i``-th term of the result originated from the ``provenance[i]``-th term of
+    ///     ``self``.
+    fn normal_ordered_with_provenance(&self) -> (Self, Vec<usize>) {
+        let (inner, provenance) = self.inner.normal_ordered_with_provenance();
+        (Self { inner }, provenance)
+    }
+
+    /// Decomposes this operator into its Hermitian-conjugate generators.
+    ///
+    /// For a Hermitian operator :math:`H`, this returns single-term generators :math:`h_j` such
+    /// that :math:`H = \sum_j (h_j + h_j^\dagger)`. To this end, the operator is normal-ordered
+    /// and simplified and each pair of a term and its Hermitian conjugate is represented by the
+    /// lexicographically smaller one. Terms which are their own Hermitian conjugate (like number
+    /// operators) are represented by half of themselves.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 1.0})
+    ///     >>> generators = op.hermitian_generators()
+    ///     >>> print(generators[0])
+    ///       1.000000e0 +0.000000e0j * (+_0 -_1)
+    ///
+    /// Args:
+    ///     atol: the absolute tolerance below which simplified terms are discarded. This value
+    ///         defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     The list of generators.
+    #[pyo3(signature = (atol=1e-8))]
+    fn hermitian_generators(&self, atol: f64) -> Vec<Self> {
+        self.inner
+            .hermitian_generators(atol)
+            .into_iter()
+            .map(|inner| Self { inner })
+            .collect()
+    }
+
     /// Returns whether this operator is Hermitian.
     ///
     /// .. note::
@@ -671,6 +1513,84 @@ impl PyFermionOperator {
         self.inner.is_hermitian(atol)
     }
 
+    /// Returns whether all coefficients of this operator are real.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0), (False, 1)): -0.5 + 1e-6j})
+    ///     >>> op.is_real()
+    ///     False
+    ///     >>> op.is_real(1e-4)
+    ///     True
+    ///
+    /// Args:
+    ///     atol: the absolute tolerance up to which imaginary parts are considered zero. This
+    ///         value defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     Whether the imaginary parts of all coefficients lie within ``atol`` of zero.
+    #[pyo3(signature = (atol=1e-8))]
+    fn is_real(&self, atol: f64) -> bool {
+        self.inner.is_real(atol)
+    }
+
+    /// The kind of coefficients held by this operator, either ``"real"`` or ``"complex"``.
+    ///
+    /// An operator is considered ``"real"`` if the imaginary parts of all its coefficients are
+    /// exactly zero (i.e. if ``is_real(0.0)`` holds). This is evaluated on every access and thus
+    /// always reflects in-place modifications of the operator.
+    ///
+    /// .. note::
+    ///    This is unrelated to the coefficient precision, which is controlled by :meth:`.astype`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0), (False, 1)): 1.0})
+    ///     >>> op.coeff_dtype
+    ///     'real'
+    ///     >>> op += FermionOperator.from_dict({(): 1j})
+    ///     >>> op.coeff_dtype
+    ///     'complex'
+    #[getter]
+    fn coeff_dtype(&self) -> &'static str {
+        if self.inner.is_real(0.0) {
+            "real"
+        } else {
+            "complex"
+        }
+    }
+
+    /// Returns the total number of actions stored in this operator.
+    ///
+    /// Unlike the number of terms (see :func:`len`), this is the sum of all term lengths and
+    /// therefore reflects the storage size of the operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0), (False, 1)): 2.0})
+    ///     >>> op.num_actions()
+    ///     2
+    ///
+    /// Returns:
+    ///     The total number of actions.
+    fn num_actions(&self) -> usize {
+        self.inner.num_actions()
+    }
+
+    /// Returns the average number of actions per term of this operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0), (False, 1)): 2.0})
+    ///     >>> op.average_term_length()
+    ///     1.0
+    ///
+    /// Returns:
+    ///     The average term length, or ``0.0`` for an operator without terms.
+    fn average_term_length(&self) -> f64 {
+        self.inner.average_term_length()
+    }
+
     /// Returns the many-body order of this operator.
     ///
     /// .. note::
@@ -707,10 +1627,1147 @@ impl PyFermionOperator {
     fn conserves_particle_number(&self) -> bool {
         self.inner.conserves_particle_number()
     }
-}
 
-#[pymodule]
-pub mod fermion_operator {
-    #[pymodule_export]
-    use super::PyFermionOperator;
+    /// Returns whether this operator conserves the number of particles of each spin species.
+    ///
+    /// Unlike :meth:`conserves_particle_number`, this requires every term to separately conserve
+    /// the number of alpha- and beta-spin particles. The spin-orbitals are assumed to be blocked
+    /// by spin, i.e. the modes ``0, ..., norb - 1`` correspond to alpha-spin and the modes
+    /// ``norb, ..., 2 * norb - 1`` to beta-spin orbitals.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 1})
+    ///     >>> op.conserves_spin_numbers(2)
+    ///     True
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(2)): 1})
+    ///     >>> op.conserves_particle_number()
+    ///     True
+    ///     >>> op.conserves_spin_numbers(2)
+    ///     False
+    ///
+    /// Args:
+    ///     norb: the number of spatial orbitals.
+    ///
+    /// Returns:
+    ///     Whether this operator conserves the number of alpha- and beta-spin particles.
+    fn conserves_spin_numbers(&self, norb: u32) -> bool {
+        self.inner.conserves_spin_numbers(norb)
+    }
+
+    /// Returns whether this operator is quadratic.
+    ///
+    /// An operator is considered quadratic if every one of its terms, after normal ordering and
+    /// simplification, is either a constant or a product of exactly one creation and one
+    /// annihilation operator. Such free-fermion operators can be diagonalized efficiently.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> hopping = FermionOperator.from_dict({(cre(0), ann(1)): -1, (cre(1), ann(0)): -1})
+    ///     >>> hopping.is_quadratic()
+    ///     True
+    ///     >>> hubbard = FermionOperator.from_dict({(cre(0), ann(0), cre(1), ann(1)): 4})
+    ///     >>> hubbard.is_quadratic()
+    ///     False
+    ///
+    /// Returns:
+    ///     Whether this operator is quadratic.
+    fn is_quadratic(&self) -> bool {
+        self.inner.is_quadratic()
+    }
+
+    /// Returns whether this operator is a scalar multiple of the identity.
+    ///
+    /// The operator is :meth:`.normal_ordered` and :meth:`.simplify`-ed before checking that all
+    /// of its remaining terms are identity terms.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> (2 * FermionOperator.one()).is_constant()
+    ///     True
+    ///     >>> FermionOperator.from_dict({(ann(0), cre(0)): 1, (cre(0), ann(0)): 1}).is_constant()
+    ///     True
+    ///     >>> FermionOperator.from_dict({(cre(0), ann(0)): 1}).is_constant()
+    ///     False
+    ///
+    /// Args:
+    ///     atol: the absolute tolerance below which coefficients are considered zero. This value
+    ///         defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     Whether this operator is constant.
+    #[pyo3(signature = (atol=1e-8))]
+    fn is_constant(&self, atol: f64) -> bool {
+        self.inner.is_constant(atol)
+    }
+
+    /// Returns whether this operator equals the identity.
+    ///
+    /// .. note::
+    ///    This check is implemented using :meth:`.equiv` on the :meth:`.normal_ordered` operator
+    ///    and :meth:`.one`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> FermionOperator.one().is_identity()
+    ///     True
+    ///     >>> (2 * FermionOperator.one()).is_identity()
+    ///     False
+    ///
+    /// Args:
+    ///     atol: the absolute tolerance upto which coefficients are considered equal. This value
+    ///         defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     Whether this operator is the identity.
+    #[pyo3(signature = (atol=1e-8))]
+    fn is_identity(&self, atol: f64) -> bool {
+        self.inner.is_identity(atol)
+    }
+
+    /// Returns the product of this operator with ``other`` in the mathematical sense.
+    ///
+    /// Unlike the composition ``self & other``, which corresponds to :math:`B A`, this method
+    /// computes :math:`A B` (reading left to right). That is, ``a.matmul(b)`` equals ``b & a``.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> a = FermionOperator.from_dict({((True, 0),): 1.0})
+    ///     >>> b = FermionOperator.from_dict({((False, 0),): 1.0})
+    ///     >>> print(a.matmul(b))
+    ///       1.000000e0 +0.000000e0j * (+_0 -_0)
+    ///
+    /// Args:
+    ///     other: the right-hand operand of the product.
+    ///
+    /// Returns:
+    ///     The product operator.
+    fn matmul(&self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.matmul(&other.inner),
+        }
+    }
+
+    /// Returns the tensor product of this operator with ``other``.
+    ///
+    /// This operator is assumed to act on the first ``num_modes_self`` modes, while the indices of
+    /// ``other`` get shifted to act on the subsequent modes. The result is the product
+    /// (see :meth:`matmul`) of the two operators with disjoint supports.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> num = FermionOperator.from_dict({(cre(0), ann(0)): 1.0})
+    ///     >>> print(num.tensor(num, 1))
+    ///       1.000000e0 +0.000000e0j * (+_0 -_0 +_1 -_1)
+    ///
+    /// Args:
+    ///     other: the operator acting on the subsequent modes.
+    ///     num_modes_self: the number of modes acted upon by this operator.
+    ///
+    /// Returns:
+    ///     The tensor product operator.
+    ///
+    /// Raises:
+    ///     ValueError: if this operator acts on a mode outside of the first ``num_modes_self``.
+    fn tensor(&self, other: &Self, num_modes_self: u32) -> PyResult<Self> {
+        if self.inner.indices.iter().any(|&i| i >= num_modes_self) {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on a mode outside of its {num_modes_self} modes."
+            )));
+        }
+        Ok(Self {
+            inner: self.inner.tensor(&other.inner, num_modes_self),
+        })
+    }
+
+    /// Returns the sum of all coefficients of this operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.5, ((True, 0), (False, 1)): 2.0})
+    ///     >>> op.coefficient_sum()
+    ///     (3.5+0j)
+    ///
+    /// Returns:
+    ///     The sum of all coefficients.
+    fn coefficient_sum(&self) -> Complex64 {
+        self.inner.coefficient_sum()
+    }
+
+    /// Returns summary statistics of the coefficients of this operator.
+    ///
+    /// This is intended as a quick numerical health check. The magnitudes only take finite
+    /// coefficients into account and are ``0.0`` if there are none.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 3.0 + 4.0j, ((True, 0), (False, 1)): -1.0})
+    ///     >>> stats = op.coefficient_stats()
+    ///     >>> stats["min_abs"], stats["max_abs"], stats["mean_abs"]
+    ///     (1.0, 5.0, 3.0)
+    ///     >>> stats["has_nan"], stats["has_inf"]
+    ///     (False, False)
+    ///
+    /// Returns:
+    ///     A dictionary with the keys ``min_abs``, ``max_abs`` and ``mean_abs`` holding the
+    ///     smallest, largest and mean coefficient magnitude, and ``has_nan`` and ``has_inf``
+    ///     indicating whether any coefficient is NaN or infinite.
+    fn coefficient_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = self.inner.coefficient_stats();
+        let out = PyDict::new(py);
+        out.set_item("min_abs", stats.min_abs)?;
+        out.set_item("max_abs", stats.max_abs)?;
+        out.set_item("mean_abs", stats.mean_abs)?;
+        out.set_item("has_nan", stats.has_nan)?;
+        out.set_item("has_inf", stats.has_inf)?;
+        Ok(out)
+    }
+
+    /// Returns the constant (or identity) coefficient of this operator.
+    ///
+    /// Since an operator may contain multiple identity terms, their coefficients are summed.
+    /// Terms which reduce to a constant only after normal ordering are not considered.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.5, ((True, 0), (False, 1)): 2.0})
+    ///     >>> op.constant()
+    ///     (1.5+0j)
+    ///
+    /// Returns:
+    ///     The sum of the coefficients of all identity terms.
+    fn constant(&self) -> Complex64 {
+        self.inner.constant()
+    }
+
+    /// Appends many terms to this operator in a single call.
+    ///
+    /// This is considerably faster than adding the terms one at a time. The ``i``-th new term
+    /// has the coefficient ``coeffs[i]`` and consists of the next ``term_lengths[i]`` entries of
+    /// ``actions`` and ``indices``.
+    ///
+    /// .. doctest::
+    ///     >>> import numpy as np
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.one()
+    ///     >>> op.extend_terms(
+    ///     ...     np.array([2.0, -1.0j]),
+    ///     ...     np.array([1, 2], dtype=np.uint64),
+    ///     ...     np.array([True, True, False]),
+    ///     ...     np.array([0, 1, 0], dtype=np.uint32),
+    ///     ... )
+    ///     >>> print(op)
+    ///       1.000000e0 +0.000000e0j * ()
+    ///       2.000000e0 +0.000000e0j * (+_0)
+    ///      -0.000000e0 -1.000000e0j * (+_1 -_0)
+    ///
+    /// Args:
+    ///     coeffs: the coefficients of the new terms.
+    ///     term_lengths: the number of actions in each new term.
+    ///     actions: the concatenated actions of all new terms.
+    ///     indices: the concatenated indices of all new terms.
+    ///
+    /// Raises:
+    ///     ValueError: if the lengths of the arrays are inconsistent.
+    fn extend_terms(
+        &mut self,
+        coeffs: PyReadonlyArray1<Complex64>,
+        term_lengths: PyReadonlyArray1<usize>,
+        actions: PyReadonlyArray1<bool>,
+        indices: PyReadonlyArray1<u32>,
+    ) -> PyResult<()> {
+        let coeffs = coeffs.as_slice()?;
+        let term_lengths = term_lengths.as_slice()?;
+        let actions = actions.as_slice()?;
+        let indices = indices.as_slice()?;
+        if coeffs.len() != term_lengths.len() {
+            return Err(PyValueError::new_err(format!(
+                "Got {} coefficients but {} term lengths.",
+                coeffs.len(),
+                term_lengths.len()
+            )));
+        }
+        let num_actions: usize = term_lengths.iter().sum();
+        if actions.len() != num_actions || indices.len() != num_actions {
+            return Err(PyValueError::new_err(format!(
+                "The term lengths sum to {num_actions} but got {} actions and {} indices.",
+                actions.len(),
+                indices.len()
+            )));
+        }
+        self.inner
+            .extend_terms(coeffs, term_lengths, actions, indices);
+        Ok(())
+    }
+
+    /// Partitions the terms of this operator into contiguous chunks.
+    ///
+    /// The number of terms in the chunks differs by at most one and each chunk is a standalone
+    /// operator. This is useful for distributing the processing of a large operator across
+    /// multiple workers. The sum of all chunks equals the original operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0),): 2.0, ((False, 1),): 3.0})
+    ///     >>> chunks = op.chunks(2)
+    ///     >>> [len(chunk) for chunk in chunks]
+    ///     [2, 1]
+    ///     >>> sum(chunks, FermionOperator.zero()) == op
+    ///     True
+    ///
+    /// Args:
+    ///     num_chunks: the number of chunks. If this exceeds the number of terms, the trailing
+    ///         chunks are empty.
+    ///
+    /// Returns:
+    ///     The list of chunks.
+    ///
+    /// Raises:
+    ///     ValueError: if ``num_chunks`` is zero.
+    fn chunks(&self, num_chunks: usize) -> PyResult<Vec<Self>> {
+        if num_chunks == 0 {
+            return Err(PyValueError::new_err(
+                "The number of chunks must be positive.",
+            ));
+        }
+        Ok(self
+            .inner
+            .chunks(num_chunks)
+            .into_iter()
+            .map(|inner| Self { inner })
+            .collect())
+    }
+
+    /// Returns every term of this operator as a standalone single-term operator.
+    ///
+    /// Unlike :meth:`iter_terms`, which yields ``(list, coeff)`` tuples, this is useful for
+    /// processing every term independently with the methods of this class. The terms keep their
+    /// stored order and their sum equals the original operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.0, ((True, 0), (False, 1)): 2.0})
+    ///     >>> terms = op.term_operators()
+    ///     >>> [len(term) for term in terms]
+    ///     [1, 1]
+    ///     >>> sum(terms, FermionOperator.zero()) == op
+    ///     True
+    ///
+    /// Returns:
+    ///     The list of single-term operators.
+    fn term_operators(&self) -> Vec<Self> {
+        self.inner
+            .term_operators()
+            .into_iter()
+            .map(|inner| Self { inner })
+            .collect()
+    }
+
+    /// Returns the sub-operator of all terms for which ``callback`` returns ``True``.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0),): 1.0, ((True, 1),): 1e-3})
+    ///     >>> print(op.filter_terms(lambda actions, coeff: abs(coeff) > 1e-2))
+    ///       1.000000e0 +0.000000e0j * (+_0)
+    ///
+    /// Args:
+    ///     callback: a callable which receives the list of actions and the coefficient of a term
+    ///         (in the same format as yielded by :meth:`.iter_terms`) and returns whether to keep
+    ///         that term.
+    ///
+    /// Returns:
+    ///     A new operator containing only the selected terms.
+    ///
+    /// Raises:
+    ///     Exception: any exception raised by ``callback`` is propagated.
+    fn filter_terms(&self, callback: Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut err = None;
+        let inner = self.inner.filter_terms(|term| {
+            if err.is_some() {
+                return false;
+            }
+            match callback
+                .call1((term.into_vec(), term.coeff))
+                .and_then(|keep| keep.extract::<bool>())
+            {
+                Ok(keep) => keep,
+                Err(e) => {
+                    err = Some(e);
+                    false
+                }
+            }
+        });
+        match err {
+            Some(e) => Err(e),
+            None => Ok(Self { inner }),
+        }
+    }
+
+    /// Projects this operator onto the modes with indices below ``max_index_exclusive``.
+    ///
+    /// Only the terms which act exclusively on these modes are kept. Note that this is **not** a
+    /// partial trace: terms touching any other mode are discarded rather than evaluated on some
+    /// state of the remaining modes.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 1.0, (cre(2), ann(1)): 2.0})
+    ///     >>> print(op.restrict_to_modes(2))
+    ///       1.000000e0 +0.000000e0j * (+_0 -_1)
+    ///
+    /// Args:
+    ///     max_index_exclusive: the number of leading modes to restrict this operator to.
+    ///
+    /// Returns:
+    ///     A new operator containing only the terms acting on the leading modes.
+    fn restrict_to_modes(&self, max_index_exclusive: u32) -> Self {
+        Self {
+            inner: self.inner.restrict_to_modes(max_index_exclusive),
+        }
+    }
+
+    /// Applies a callback to every coefficient of this operator in place.
+    ///
+    /// The terms themselves are left untouched. In particular, terms whose coefficients get mapped
+    /// to zero are not removed (see :meth:`.simplify` for that).
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0), (False, 1)): 1.0 + 0.5j})
+    ///     >>> op.map_coeffs(lambda c: complex(c.real, 0))
+    ///     >>> print(op)
+    ///       1.000000e0 +0.000000e0j * (+_0 -_1)
+    ///
+    /// Args:
+    ///     callback: a callable which receives a coefficient and returns its replacement.
+    ///
+    /// Raises:
+    ///     Exception: any exception raised by ``callback`` is propagated. In that case, this
+    ///         operator is left unchanged.
+    fn map_coeffs(&mut self, callback: Bound<'_, PyAny>) -> PyResult<()> {
+        let mut err = None;
+        let mut inner = self.inner.clone();
+        inner.map_coeffs(|coeff| {
+            if err.is_some() {
+                return coeff;
+            }
+            match callback
+                .call1((coeff,))
+                .and_then(|new| new.extract::<Complex64>())
+            {
+                Ok(new) => new,
+                Err(e) => {
+                    err = Some(e);
+                    coeff
+                }
+            }
+        });
+        match err {
+            Some(e) => Err(e),
+            None => {
+                self.inner = inner;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns this operator without its identity terms, together with the removed constant.
+    ///
+    /// This is useful for shifting the energy origin of a Hamiltonian, since the original
+    /// operator can be reconstructed as ``traceless + constant * FermionOperator.one()``.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.5, ((True, 0), (False, 1)): 2.0})
+    ///     >>> traceless, constant = op.remove_constant()
+    ///     >>> print(traceless)
+    ///       2.000000e0 +0.000000e0j * (+_0 -_1)
+    ///     >>> constant
+    ///     (1.5+0j)
+    ///
+    /// Returns:
+    ///     A tuple of the operator without identity terms and the sum of their coefficients.
+    fn remove_constant(&self) -> (Self, Complex64) {
+        let (inner, constant) = self.inner.remove_constant();
+        (Self { inner }, constant)
+    }
+
+    /// Returns this operator with an additional identity term.
+    ///
+    /// This is a shorthand for ``self + c * FermionOperator.one()``, commonly used to add an
+    /// energy offset to a Hamiltonian. The new term is appended and not merged with any existing
+    /// identity terms.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({(): 1.5, ((True, 0), (False, 1)): 2.0})
+    ///     >>> op.add_constant(0.5).constant()
+    ///     (2+0j)
+    ///
+    /// Args:
+    ///     c: the coefficient of the added identity term.
+    ///
+    /// Returns:
+    ///     The shifted operator.
+    fn add_constant(&self, c: Complex64) -> Self {
+        Self {
+            inner: self.inner.add_constant(c),
+        }
+    }
+
+    /// Returns the smallest and largest mode index acted upon by this operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 4), (False, 1)): 1, ((True, 2),): 1})
+    ///     >>> op.index_range()
+    ///     (1, 4)
+    ///     >>> print(FermionOperator.one().index_range())
+    ///     None
+    ///
+    /// Returns:
+    ///     A tuple of the smallest and largest index or ``None`` if this operator contains no
+    ///     actions.
+    fn index_range(&self) -> Option<(u32, u32)> {
+        self.inner.index_range()
+    }
+
+    /// Splits this operator into its components of equal many-body order.
+    ///
+    /// The terms of this operator are bucketed by their number of actions, meaning that the
+    /// constant, 1-body and 2-body components of an electronic structure Hamiltonian end up under
+    /// the keys ``0``, ``2`` and ``4``, respectively. The sum over all buckets equals the original
+    /// operator. Terms are not normal-ordered or simplified beforehand.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({
+    ///     ...     (): 1.0,
+    ///     ...     (cre(0), ann(0)): 2.0,
+    ///     ...     (cre(0), cre(1), ann(1), ann(0)): 3.0,
+    ///     ... })
+    ///     >>> groups = op.group_by_order()
+    ///     >>> sorted(groups)
+    ///     [0, 2, 4]
+    ///     >>> print(groups[2])
+    ///       2.000000e0 +0.000000e0j * (+_0 -_0)
+    ///
+    /// Returns:
+    ///     A dictionary mapping the many-body order to the corresponding component of this
+    ///     operator.
+    fn group_by_order(&self) -> BTreeMap<u32, Self> {
+        self.inner
+            .group_by_order()
+            .into_iter()
+            .map(|(order, inner)| (order, Self { inner }))
+            .collect()
+    }
+
+    /// Counts the terms of this operator by the number of distinct modes they act upon.
+    ///
+    /// This can serve as a quick estimate for the cost of mapping this operator to qubits. Terms
+    /// are not normal-ordered or simplified beforehand.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({
+    ///     ...     (): 1.0,
+    ///     ...     (cre(0), ann(0)): 2.0,
+    ///     ...     (cre(0), ann(1)): 2.0,
+    ///     ...     (cre(0), cre(1), ann(1), ann(0)): 3.0,
+    ///     ... })
+    ///     >>> op.support_histogram()
+    ///     {0: 1, 1: 1, 2: 2}
+    ///
+    /// Returns:
+    ///     A dictionary mapping the number of distinct modes to the number of terms acting on that
+    ///     many modes.
+    fn support_histogram(&self) -> BTreeMap<u32, usize> {
+        self.inner.support_histogram()
+    }
+
+    /// Applies a single-particle basis rotation to this operator.
+    ///
+    /// Every creation action :math:`a^\dagger_p` gets substituted by
+    /// :math:`\sum_k U_{pk} a^\dagger_k` and every annihilation action :math:`a_p` by
+    /// :math:`\sum_k U^*_{pk} a_k`, before expanding the products. Since a term with :math:`m`
+    /// actions expands into up to :math:`n^m` terms, this is only supported for up to two-body
+    /// operators. The result is not simplified.
+    ///
+    /// .. doctest::
+    ///     >>> import numpy as np
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> num = FermionOperator.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 1.0})
+    ///     >>> unitary = np.array([[0.6, 0.8j], [0.8j, 0.6]])
+    ///     >>> num.rotate_orbitals(unitary).equiv(num)
+    ///     True
+    ///
+    /// Args:
+    ///     unitary: the square unitary matrix :math:`U` of the single-particle basis rotation.
+    ///
+    /// Returns:
+    ///     The rotated operator.
+    ///
+    /// Raises:
+    ///     ValueError: if ``unitary`` is not square, does not cover all modes acted upon, or if a
+    ///         term has more than 4 actions.
+    fn rotate_orbitals(&self, unitary: PyReadonlyArray2<Complex64>) -> PyResult<Self> {
+        let unitary = unitary.as_array();
+        if unitary.nrows() != unitary.ncols() {
+            return Err(PyValueError::new_err(format!(
+                "The unitary must be square but has shape ({}, {}).",
+                unitary.nrows(),
+                unitary.ncols()
+            )));
+        }
+        if let Some(index) = self
+            .inner
+            .indices
+            .iter()
+            .find(|&&i| i as usize >= unitary.nrows())
+        {
+            return Err(PyValueError::new_err(format!(
+                "Index {index} is out of bounds for a unitary of {} modes.",
+                unitary.nrows()
+            )));
+        }
+        if self.inner.max_term_length() > 4 {
+            return Err(PyValueError::new_err(
+                "Rotating terms of more than 4 actions is not supported.",
+            ));
+        }
+        Ok(Self {
+            inner: self.inner.rotate_orbitals(unitary),
+        })
+    }
+
+    /// Returns the partial trace of this operator over the provided modes.
+    ///
+    /// .. note::
+    ///    The traced modes are assumed to be in the vacuum state. Thus, actions on these modes get
+    ///    replaced by their vacuum expectation value. For example, :math:`a^\dagger_k a_k` yields
+    ///    0 while :math:`a_k a^\dagger_k` yields 1, and terms with an unpaired action on a traced
+    ///    mode vanish entirely.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 2.0})
+    ///     >>> print(op.trace_out_vacuum([1]))
+    ///       1.000000e0 +0.000000e0j * (+_0 -_0)
+    ///
+    /// Args:
+    ///     modes: the indices of the modes to trace out.
+    ///
+    /// Returns:
+    ///     The operator acting on the remaining modes.
+    fn trace_out_vacuum(&self, modes: Vec<u32>) -> Self {
+        Self {
+            inner: self.inner.trace_out_vacuum(&modes),
+        }
+    }
+
+    /// Evaluates the diagonal of this operator on all Fock states.
+    ///
+    /// This avoids constructing the dense matrix of the operator, but requires every term to act
+    /// diagonally on Fock states (for example, products of number operators). The ``k``-th entry
+    /// of the result corresponds to the Fock state whose ``i``-th mode is occupied if the ``i``-th
+    /// bit of ``k`` is set.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 1.0})
+    ///     >>> op.fock_diagonal(2).real
+    ///     array([0., 1., 1., 2.])
+    ///
+    /// Args:
+    ///     num_modes: the number of fermionic modes.
+    ///
+    /// Returns:
+    ///     The ``2 ** num_modes`` diagonal entries.
+    ///
+    /// Raises:
+    ///     ValueError: if the operator is not diagonal, acts on a mode outside of ``num_modes``, or
+    ///         if ``num_modes`` exceeds 30.
+    fn fock_diagonal<'py>(
+        &self,
+        py: Python<'py>,
+        num_modes: u32,
+    ) -> PyResult<Bound<'py, PyArray1<Complex64>>> {
+        crate::operators::check_fock_modes(num_modes)?;
+        if !self.inner.is_diagonal() {
+            return Err(PyValueError::new_err(
+                "The operator contains terms which do not act diagonally on Fock states.",
+            ));
+        }
+        if let Some(index) = self.inner.indices.iter().find(|i| **i >= num_modes) {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on mode {index} which exceeds the {num_modes} provided modes."
+            )));
+        }
+        Ok(self.inner.fock_diagonal(num_modes).into_pyarray(py))
+    }
+
+    /// Extracts the one-body matrix of this operator.
+    ///
+    /// This inverts the one-body electronic integral constructors, returning the matrix :math:`h`
+    /// for which this operator equals :math:`\sum_{ij} h_{ij} a^\dagger_i a_j`. Coefficients of
+    /// duplicate terms are summed.
+    ///
+    /// .. doctest::
+    ///     >>> import numpy as np
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_1body_tril_spin_sym(np.array([1.0, 2.0, 3.0]), norb=2)
+    ///     >>> op.to_1body_matrix(4).real
+    ///     array([[1., 2., 0., 0.],
+    ///            [2., 3., 0., 0.],
+    ///            [0., 0., 1., 2.],
+    ///            [0., 0., 2., 3.]])
+    ///
+    /// Args:
+    ///     norb: the number of rows and columns of the matrix. For operators constructed from
+    ///         spin-orbital integrals, this is the number of spin orbitals.
+    ///
+    /// Returns:
+    ///     The complex ``(norb, norb)`` one-body matrix.
+    ///
+    /// Raises:
+    ///     ValueError: if a term does not consist of exactly one creation followed by one
+    ///         annihilation action (see :meth:`.normal_ordered`), or acts on a mode outside of
+    ///         ``norb``.
+    fn to_1body_matrix<'py>(
+        &self,
+        py: Python<'py>,
+        norb: u32,
+    ) -> PyResult<Bound<'py, PyArray2<Complex64>>> {
+        self.inner
+            .to_1body_matrix(norb)
+            .map(|matrix| matrix.into_pyarray(py))
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Computes the single-particle energies of this quadratic operator.
+    ///
+    /// For a quadratic, particle-number-conserving (i.e. free-fermion) operator, these are the
+    /// eigenvalues of its one-body matrix (see :meth:`to_1body_matrix`). Only the Hermitian part
+    /// of that matrix is taken into account. This scales cubically in ``norb`` rather than
+    /// exponentially like :meth:`to_sparse_matrix`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict(
+    ///     ...     {
+    ///     ...         (cre(0), ann(0)): 1.0,
+    ///     ...         (cre(1), ann(1)): -1.0,
+    ///     ...         (cre(0), ann(1)): 1.0,
+    ///     ...         (cre(1), ann(0)): 1.0,
+    ///     ...     }
+    ///     ... )
+    ///     >>> op.quadratic_spectrum(2)  # doctest: +FLOAT_CMP
+    ///     array([-1.41421356,  1.41421356])
+    ///
+    /// Args:
+    ///     norb: the number of modes.
+    ///
+    /// Returns:
+    ///     The single-particle energies in ascending order.
+    ///
+    /// Raises:
+    ///     ValueError: if a term does not consist of exactly one creation followed by one
+    ///         annihilation action (see :meth:`.normal_ordered`), or acts on a mode outside of
+    ///         ``norb``.
+    fn quadratic_spectrum<'py>(
+        &self,
+        py: Python<'py>,
+        norb: u32,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        self.inner
+            .quadratic_spectrum(norb)
+            .map(|spectrum| spectrum.into_pyarray(py))
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Computes the sparse matrix of this operator on the Fock space.
+    ///
+    /// The basis state with index ``k`` is the one whose ``i``-th mode is occupied if the ``i``-th
+    /// bit of ``k`` is set (like in :meth:`.fock_diagonal`), and the signs arising from the
+    /// Jordan-Wigner convention are taken into account.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(1), ann(0)): 2.0})
+    ///     >>> op.to_sparse_matrix(2).toarray().real
+    ///     array([[0., 0., 0., 0.],
+    ///            [0., 0., 0., 0.],
+    ///            [0., 2., 0., 0.],
+    ///            [0., 0., 0., 0.]])
+    ///
+    /// Args:
+    ///     num_modes: the number of fermionic modes, :math:`n`.
+    ///
+    /// Returns:
+    ///     The ``scipy.sparse.coo_matrix`` of shape :math:`(2^n, 2^n)`.
+    ///
+    /// Raises:
+    ///     ValueError: if the operator acts on a mode outside of ``num_modes`` or if ``num_modes``
+    ///         exceeds 30.
+    fn to_sparse_matrix<'py>(
+        &self,
+        py: Python<'py>,
+        num_modes: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::operators::check_fock_modes(num_modes)?;
+        if let Some(index) = self.inner.indices.iter().find(|i| **i >= num_modes) {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on mode {index} which exceeds the {num_modes} provided modes."
+            )));
+        }
+        let (rows, cols, values) = self.inner.to_sparse_matrix(num_modes);
+        crate::operators::coo_matrix(py, rows, cols, values, 1 << num_modes)
+    }
+
+    /// Approximates the ground-state energy of this Hermitian operator.
+    ///
+    /// This runs the Lanczos iteration on the sparse matrix of this operator (see
+    /// :meth:`to_sparse_matrix`), starting from a seeded random vector. The iteration stops once
+    /// the residual norm of the estimated lowest eigenpair is at most ``tol``. Since no
+    /// particle-number sector is selected, the lowest eigenvalue across all sectors is returned.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///     >>> op = FermionOperator.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): -2.0})
+    ///     >>> round(op.ground_energy(2), 8)
+    ///     -2.0
+    ///
+    /// Args:
+    ///     num_modes: the number of fermionic modes.
+    ///     max_iter: the maximum number of Lanczos iterations. This value defaults to ``100``.
+    ///     tol: the bound on the residual norm, which is also used as the tolerance when checking
+    ///         that this operator is Hermitian. This value defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     The lowest eigenvalue of this operator.
+    ///
+    /// Raises:
+    ///     ValueError: if ``num_modes`` exceeds 30, the operator acts on a mode outside of
+    ///         ``num_modes``, is not Hermitian, or the iteration does not converge within
+    ///         ``max_iter`` iterations.
+    #[pyo3(signature = (num_modes, max_iter=100, tol=1e-8))]
+    fn ground_energy(&self, num_modes: u32, max_iter: usize, tol: f64) -> PyResult<f64> {
+        crate::operators::check_fock_modes(num_modes)?;
+        if let Some(index) = self.inner.indices.iter().find(|i| **i >= num_modes) {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on mode {index} which exceeds the {num_modes} provided modes."
+            )));
+        }
+        self.inner
+            .ground_energy(num_modes, max_iter, tol)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Computes the expectation value of this operator with respect to a Slater determinant.
+    ///
+    /// Each term is applied to the determinant (see :meth:`apply_to_state`), taking the signs
+    /// arising from the Jordan-Wigner convention into account, and only the contributions which
+    /// map the determinant back onto itself are summed.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict(
+    ///     ...     {(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 2.0, (cre(0), ann(1)): 3.0}
+    ///     ... )
+    ///     >>> op.expectation_determinant([1], 2)
+    ///     (2+0j)
+    ///
+    /// Args:
+    ///     occupied: the indices of the occupied modes.
+    ///     num_modes: the total number of fermionic modes.
+    ///
+    /// Returns:
+    ///     The expectation value.
+    ///
+    /// Raises:
+    ///     ValueError: if an occupied mode or a mode acted upon lies outside of ``num_modes``.
+    fn expectation_determinant(&self, occupied: Vec<u32>, num_modes: u32) -> PyResult<Complex64> {
+        if let Some(index) = occupied
+            .iter()
+            .chain(&self.inner.indices)
+            .find(|i| **i >= num_modes)
+        {
+            return Err(PyValueError::new_err(format!(
+                "Mode {index} is out of range for {num_modes} modes."
+            )));
+        }
+        Ok(self.inner.expectation_determinant(&occupied, num_modes))
+    }
+
+    /// Remaps the indices acted upon by this operator onto a contiguous range.
+    ///
+    /// After removing or freezing modes, the indices of an operator may contain gaps which would
+    /// waste qubits when mapping it. This method relabels the ``k`` distinct indices to
+    /// ``0, ..., k - 1`` while preserving their relative order.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 5), (False, 2)): 1.0})
+    ///     >>> compact, mapping = op.compactify_indices()
+    ///     >>> print(compact)
+    ///       1.000000e0 +0.000000e0j * (+_1 -_0)
+    ///     >>> mapping
+    ///     [2, 5]
+    ///
+    /// Returns:
+    ///     A tuple of the remapped operator and the list of original indices, such that the new
+    ///     index ``i`` corresponds to the original index ``mapping[i]``.
+    fn compactify_indices(&self) -> (Self, Vec<u32>) {
+        let (inner, mapping) = self.inner.compactify_indices();
+        (Self { inner }, mapping)
+    }
+
+    /// Applies this operator to a Fock state.
+    ///
+    /// Each term gets applied to the provided computational basis state individually, taking the
+    /// signs arising from the Jordan-Wigner convention into account. Terms which annihilate the
+    /// state are dropped and the remaining results are **not** merged.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(1), ann(0)): 2.0})
+    ///     >>> op.apply_to_state([True, False])
+    ///     [((2+0j), [False, True])]
+    ///     >>> op.apply_to_state([False, False])
+    ///     []
+    ///
+    /// Args:
+    ///     occupation: the occupation numbers of the fermionic modes making up the Fock state.
+    ///
+    /// Returns:
+    ///     A list of the resulting amplitudes and occupation numbers.
+    ///
+    /// Raises:
+    ///     ValueError: if the operator acts on a mode outside of the provided state.
+    fn apply_to_state(&self, occupation: Vec<bool>) -> PyResult<Vec<(Complex64, Vec<bool>)>> {
+        if let Some(index) = self
+            .inner
+            .indices
+            .iter()
+            .find(|i| **i as usize >= occupation.len())
+        {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on mode {index} which exceeds the {} provided modes.",
+                occupation.len()
+            )));
+        }
+        Ok(self.inner.apply_to_state(&occupation))
+    }
+
+    /// Computes the matrix element between two Fock states.
+    ///
+    /// The operator is applied to ``ket`` (see :meth:`.apply_to_state`) and the result is
+    /// projected onto ``bra``, yielding :math:`\langle \text{bra} | O | \text{ket} \rangle`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator, cre, ann
+    ///     >>> op = FermionOperator.from_dict({(cre(1), ann(0)): 2.0})
+    ///     >>> op.matrix_element([False, True], [True, False])
+    ///     (2+0j)
+    ///     >>> op.matrix_element([True, False], [False, True])
+    ///     0j
+    ///
+    /// Args:
+    ///     bra: the occupation numbers of the fermionic modes making up the bra state.
+    ///     ket: the occupation numbers of the fermionic modes making up the ket state.
+    ///
+    /// Returns:
+    ///     The matrix element.
+    ///
+    /// Raises:
+    ///     ValueError: if ``bra`` and ``ket`` differ in length or if the operator acts on a mode
+    ///         outside of them.
+    fn matrix_element(&self, bra: Vec<bool>, ket: Vec<bool>) -> PyResult<Complex64> {
+        if bra.len() != ket.len() {
+            return Err(PyValueError::new_err(format!(
+                "The bra and ket have different numbers of modes ({} and {}).",
+                bra.len(),
+                ket.len()
+            )));
+        }
+        if let Some(index) = self
+            .inner
+            .indices
+            .iter()
+            .find(|i| **i as usize >= ket.len())
+        {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on mode {index} which exceeds the {} provided modes.",
+                ket.len()
+            )));
+        }
+        Ok(self.inner.matrix_element(&bra, &ket))
+    }
+
+    /// Returns a copy of this operator with the requested coefficient precision.
+    ///
+    /// Converting to ``"float32"`` yields a :class:`.FermionOperatorF32` which stores its
+    /// coefficients in single precision, roughly halving the memory they occupy. This is intended
+    /// for storing extremely large operators where precision can be relaxed.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import FermionOperator
+    ///     >>> op = FermionOperator.from_dict({((True, 0), (False, 1)): 0.1})
+    ///     >>> op32 = op.astype("float32")
+    ///     >>> op32
+    ///     FermionOperatorF32(len=1)
+    ///     >>> op32.astype("float64").equiv(op, 1e-6)
+    ///     True
+    ///
+    /// Args:
+    ///     dtype: the coefficient precision, either ``"float64"`` or ``"float32"``.
+    ///
+    /// Returns:
+    ///     A :class:`.FermionOperator` for ``"float64"`` or a :class:`.FermionOperatorF32` for
+    ///     ``"float32"``.
+    ///
+    /// Raises:
+    ///     ValueError: if ``dtype`` is not supported.
+    fn astype(&self, py: Python<'_>, dtype: &str) -> PyResult<Py<PyAny>> {
+        match dtype {
+            "float64" => Ok(Py::new(py, self.clone())?.into_any()),
+            "float32" => Ok(Py::new(
+                py,
+                PyFermionOperatorF32 {
+                    inner: FermionOperatorF32::from(&self.inner),
+                },
+            )?
+            .into_any()),
+            _ => Err(unsupported_dtype(dtype)),
+        }
+    }
+
+    fn __sizeof__(&self) -> usize {
+        size_of::<Self>() + self.inner.nbytes()
+    }
+}
+
+fn unsupported_dtype(dtype: &str) -> PyErr {
+    PyValueError::new_err(format!(
+        "Unsupported dtype '{dtype}'. Expected 'float64' or 'float32'."
+    ))
+}
+
+/// A :class:`.FermionOperator` whose coefficients are stored in single precision.
+///
+/// This is purely a storage format which reduces the memory footprint of extremely large
+/// operators. It does not support any arithmetic and must be converted back using
+/// :meth:`astype` for further processing.
+///
+/// .. doctest::
+///     >>> import sys
+///     >>> from qiskit_fermions.operators import FermionOperator
+///     >>> op = FermionOperator.from_dict({((True, i), (False, i)): 1.0 for i in range(100)})
+///     >>> sys.getsizeof(op.astype("float32")) < sys.getsizeof(op)
+///     True
+#[gen_stub_pyclass]
+#[pyclass(
+    module = "qiskit_fermions.operators.fermion_operator",
+    name = "FermionOperatorF32"
+)]
+#[derive(Clone)]
+pub struct PyFermionOperatorF32 {
+    pub inner: FermionOperatorF32,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFermionOperatorF32 {
+    /// Returns a copy of this operator with the requested coefficient precision.
+    ///
+    /// Args:
+    ///     dtype: the coefficient precision, either ``"float64"`` or ``"float32"``.
+    ///
+    /// Returns:
+    ///     A :class:`.FermionOperator` for ``"float64"`` or a :class:`.FermionOperatorF32` for
+    ///     ``"float32"``.
+    ///
+    /// Raises:
+    ///     ValueError: if ``dtype`` is not supported.
+    fn astype(&self, py: Python<'_>, dtype: &str) -> PyResult<Py<PyAny>> {
+        match dtype {
+            "float64" => Ok(Py::new(
+                py,
+                PyFermionOperator {
+                    inner: FermionOperator::from(&self.inner),
+                },
+            )?
+            .into_any()),
+            "float32" => Ok(Py::new(py, self.clone())?.into_any()),
+            _ => Err(unsupported_dtype(dtype)),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.boundaries.len() - 1
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FermionOperatorF32(len={})", self.__len__())
+    }
+
+    fn __sizeof__(&self) -> usize {
+        size_of::<Self>() + self.inner.nbytes()
+    }
+}
+
+/// Asserts that two :class:`.FermionOperator` instances are equivalent.
+///
+/// Unlike ``assert a == b``, which compares the raw operator data, this uses
+/// :meth:`.FermionOperator.equiv` and thus ignores the order of the terms and duplicate terms.
+/// If the assertion fails, the error message lists all terms of ``a - b`` whose coefficient
+/// magnitude exceeds ``atol``.
+///
+/// .. doctest::
+///     >>> from qiskit_fermions.operators import FermionOperator, assert_operators_equiv
+///     >>> a = FermionOperator.from_dict({((True, 0),): 1.0, ((False, 1),): 2.0})
+///     >>> b = FermionOperator.from_dict({((False, 1),): 2.0, ((True, 0),): 1.0})
+///     >>> assert_operators_equiv(a, b)
+///     >>> assert_operators_equiv(a, FermionOperator.zero())
+///     Traceback (most recent call last):
+///     ...
+///     AssertionError: Operators are not equivalent within atol=1e-8. Differing terms of a - b:
+///       2.000000e0 +0.000000e0j * (-_1)
+///       1.000000e0 +0.000000e0j * (+_0)
+///
+/// Args:
+///     a: the first operator.
+///     b: the second operator.
+///     atol: the absolute tolerance for the comparison.
+///
+/// Raises:
+///     AssertionError: if the operators are not equivalent.
+#[gen_stub_pyfunction(module = "qiskit_fermions.operators.fermion_operator")]
+#[pyfunction(signature = (a, b, atol=1e-8))]
+pub fn assert_operators_equiv(
+    a: &PyFermionOperator,
+    b: &PyFermionOperator,
+    atol: f64,
+) -> PyResult<()> {
+    if a.inner.equiv(&b.inner, atol) {
+        return Ok(());
+    }
+    let diff = a.inner.__sub__(&b.inner).simplify(atol);
+    Err(PyAssertionError::new_err(format!(
+        "Operators are not equivalent within atol={atol:e}. Differing terms of a - b:\n{diff}"
+    )))
+}
+
+#[pymodule]
+pub mod fermion_operator {
+    #[pymodule_export]
+    use super::PyFermionOperator;
+
+    #[pymodule_export]
+    use super::PyFermionOperatorF32;
+
+    #[pymodule_export]
+    use super::assert_operators_equiv;
 }
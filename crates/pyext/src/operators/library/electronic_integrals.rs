@@ -11,12 +11,43 @@
 // that they have been altered from the originals.
 
 use crate::operators::fermion_operator::PyFermionOperator;
+use crate::operators::majorana_operator::PyMajoranaOperator;
+use ndarray::ArrayView1;
 use numpy::PyReadonlyArray1;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 use pyo3_stub_gen::derive::*;
 use qiskit_fermions_core::operators::fermion_operator::FermionOperator;
-use qiskit_fermions_core::operators::library::electronic_integrals::{From1Body, From2Body};
+use qiskit_fermions_core::operators::library::electronic_integrals::{
+    From1Body, From2Body, SpinOrdering, verify_s8_layout,
+};
+use qiskit_fermions_core::operators::majorana_operator::MajoranaOperator;
+
+fn _verify_s8_layout(two_body_aa: ArrayView1<f64>, norb: u32) -> PyResult<()> {
+    verify_s8_layout(two_body_aa, norb).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+fn _parse_spin_ordering(ordering: &str) -> PyResult<SpinOrdering> {
+    match ordering {
+        "block" => Ok(SpinOrdering::Block),
+        "interleaved" => Ok(SpinOrdering::Interleaved),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid spin ordering '{ordering}', expected 'block' or 'interleaved'."
+        ))),
+    }
+}
+
+fn _check_spatial_orbitals(op: &FermionOperator, norb: u32) -> PyResult<()> {
+    if let Some((_, max)) = op.index_range()
+        && max >= norb
+    {
+        return Err(PyValueError::new_err(format!(
+            "Index {max} is out of range for {norb} spatial orbitals."
+        )));
+    }
+    Ok(())
+}
 
 #[gen_stub_pymethods]
 #[pymethods]
@@ -167,19 +198,40 @@ impl PyFermionOperator {
     ///     two_body_aa: a 1-dimensional array of the S8-fold symmetric 2-body electronic integral
     ///         coefficients of the :math:`\alpha\alpha`-spin species, as a flattened array.
     ///     norb: the number of orbitals, :math:`n`.
+    ///     drop_vanishing: whether to skip the terms which vanish identically because they act
+    ///         twice with the same action on a mode (i.e. :math:`i = k` or :math:`j = l`). This
+    ///         value defaults to ``False``.
+    ///     validate: whether to check that the length of ``two_body_aa`` matches the S8-fold
+    ///         symmetric layout for ``norb`` orbitals. Since this layout stores every unique
+    ///         integral exactly once, integrals lacking this symmetry (e.g. S4-fold symmetric ones)
+    ///         are otherwise silently misinterpreted. This value defaults to ``False``.
+    ///
+    /// Raises:
+    ///     ValueError: if ``validate`` is set and the length of ``two_body_aa`` does not match the
+    ///         S8-fold symmetric layout.
     ///
     /// Returns:
     ///     The 2-body component of the electronic structure Hamiltonian as defined above.
     /// ..
     #[classmethod]
+    #[pyo3(signature = (two_body_aa, norb, drop_vanishing=false, validate=false))]
     fn from_2body_tril_spin_sym(
         _cls: &Bound<'_, PyType>,
         two_body_aa: PyReadonlyArray1<f64>,
         norb: u32,
-    ) -> Self {
-        Self {
-            inner: FermionOperator::from_2body_tril_spin_sym(two_body_aa.as_array(), norb),
+        drop_vanishing: bool,
+        validate: bool,
+    ) -> PyResult<Self> {
+        if validate {
+            _verify_s8_layout(two_body_aa.as_array(), norb)?;
         }
+        Ok(Self {
+            inner: FermionOperator::from_2body_tril_spin_sym(
+                two_body_aa.as_array(),
+                norb,
+                drop_vanishing,
+            ),
+        })
     }
 
     /// Constructs an operator from separate spin-species triangular 2-body integrals.
@@ -234,17 +286,22 @@ impl PyFermionOperator {
     ///     two_body_bb: a 1-dimensional array of the S8-fold symmetric 2-body electronic integral
     ///         coefficients of the :math:`\beta\beta`-spin species, as a flattened array.
     ///     norb: the number of orbitals, :math:`n`.
+    ///     drop_vanishing: whether to skip the terms which vanish identically because they act
+    ///         twice with the same action on a mode (i.e. :math:`i = k` or :math:`j = l`). This
+    ///         value defaults to ``False``.
     ///
     /// Returns:
     ///     The 2-body component of the electronic structure Hamiltonian as defined above.
     /// ..
     #[classmethod]
+    #[pyo3(signature = (two_body_aa, two_body_ab, two_body_bb, norb, drop_vanishing=false))]
     fn from_2body_tril_spin(
         _cls: &Bound<'_, PyType>,
         two_body_aa: PyReadonlyArray1<f64>,
         two_body_ab: PyReadonlyArray1<f64>,
         two_body_bb: PyReadonlyArray1<f64>,
         norb: u32,
+        drop_vanishing: bool,
     ) -> Self {
         Self {
             inner: FermionOperator::from_2body_tril_spin(
@@ -252,7 +309,202 @@ impl PyFermionOperator {
                 two_body_ab.as_array(),
                 two_body_bb.as_array(),
                 norb,
+                drop_vanishing,
             ),
         }
     }
+
+    /// Converts the spin-orbital indices of this operator between ordering conventions.
+    ///
+    /// Two conventions are supported for an operator acting on :math:`2n` spin orbitals:
+    ///
+    /// - ``"block"``: the :math:`\alpha`-spin orbitals are indexed by :math:`[0, n)` and the
+    ///   :math:`\beta`-spin orbitals by :math:`[n, 2n)`. This is the convention used by all
+    ///   electronic integral constructors.
+    /// - ``"interleaved"``: the :math:`\alpha`-spin (:math:`\beta`-spin) orbitals are indexed by
+    ///   the even (odd) integers.
+    ///
+    /// .. doctest::
+    ///    >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///    >>> op = FermionOperator.from_dict({(cre(1), ann(2)): 1.0})
+    ///    >>> print(op.reorder_spin(2, "block", "interleaved"))
+    ///      1.000000e0 +0.000000e0j * (+_2 -_1)
+    ///
+    /// Args:
+    ///     norb: the number of spatial orbitals, :math:`n`.
+    ///     from_ordering: the ordering convention of this operator.
+    ///     to_ordering: the ordering convention of the returned operator.
+    ///
+    /// Returns:
+    ///     The equivalent operator in the ``to_ordering`` convention.
+    ///
+    /// Raises:
+    ///     ValueError: if an ordering is not ``"block"`` or ``"interleaved"``, or if this operator
+    ///         acts on an index outside of :math:`[0, 2n)`.
+    fn reorder_spin(&self, norb: u32, from_ordering: &str, to_ordering: &str) -> PyResult<Self> {
+        let from = _parse_spin_ordering(from_ordering)?;
+        let to = _parse_spin_ordering(to_ordering)?;
+        if let Some((_, max)) = self.inner.index_range()
+            && max >= 2 * norb
+        {
+            return Err(PyValueError::new_err(format!(
+                "Index {max} is out of range for {norb} spatial orbitals."
+            )));
+        }
+        Ok(Self {
+            inner: self.inner.reorder_spin(norb, from, to),
+        })
+    }
+
+    /// Places this operator into the :math:`\beta`-spin block of the ``"block"`` spin ordering.
+    ///
+    /// This shifts all indices of this operator, which acts on the :math:`n` spatial orbitals of a
+    /// single spin species, by :math:`n` (see also :meth:`reorder_spin`).
+    ///
+    /// .. doctest::
+    ///    >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///    >>> op = FermionOperator.from_dict({(cre(0), ann(1)): 1.0})
+    ///    >>> print(op.to_beta_block(2))
+    ///      1.000000e0 +0.000000e0j * (+_2 -_3)
+    ///
+    /// Args:
+    ///     norb: the number of spatial orbitals, :math:`n`.
+    ///
+    /// Returns:
+    ///     The operator acting on the :math:`\beta`-spin orbitals.
+    ///
+    /// Raises:
+    ///     ValueError: if this operator acts on an index outside of :math:`[0, n)`.
+    fn to_beta_block(&self, norb: u32) -> PyResult<Self> {
+        _check_spatial_orbitals(&self.inner, norb)?;
+        Ok(Self {
+            inner: self.inner.to_beta_block(norb),
+        })
+    }
+
+    /// Combines separate :math:`\alpha`- and :math:`\beta`-spin operators into one.
+    ///
+    /// Both operators act on the :math:`n` spatial orbitals of their respective spin species. The
+    /// result is their sum acting on :math:`2n` spin orbitals in the ``"block"`` spin ordering,
+    /// i.e. ``alpha + beta.to_beta_block(norb)`` (see :meth:`to_beta_block`).
+    ///
+    /// .. doctest::
+    ///    >>> from qiskit_fermions.operators import FermionOperator, ann, cre
+    ///    >>> alpha = FermionOperator.from_dict({(cre(0), ann(0)): 1.0})
+    ///    >>> beta = FermionOperator.from_dict({(cre(0), ann(0)): 2.0})
+    ///    >>> print(FermionOperator.combine_spin_blocks(alpha, beta, 1))
+    ///      1.000000e0 +0.000000e0j * (+_0 -_0)
+    ///      2.000000e0 +0.000000e0j * (+_1 -_1)
+    ///
+    /// Args:
+    ///     alpha: the operator acting on the :math:`\alpha`-spin orbitals.
+    ///     beta: the operator acting on the :math:`\beta`-spin orbitals.
+    ///     norb: the number of spatial orbitals, :math:`n`.
+    ///
+    /// Returns:
+    ///     The combined operator.
+    ///
+    /// Raises:
+    ///     ValueError: if either operator acts on an index outside of :math:`[0, n)`.
+    #[staticmethod]
+    fn combine_spin_blocks(alpha: &Self, beta: &Self, norb: u32) -> PyResult<Self> {
+        _check_spatial_orbitals(&alpha.inner, norb)?;
+        _check_spatial_orbitals(&beta.inner, norb)?;
+        Ok(Self {
+            inner: FermionOperator::combine_spin_blocks(&alpha.inner, &beta.inner, norb),
+        })
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyMajoranaOperator {
+    /// Constructs an operator from spin-symmetric triangular 1-body integrals.
+    ///
+    /// This is the Majorana representation of the operator constructed by
+    /// :meth:`.FermionOperator.from_1body_tril_spin_sym`, as mapped by
+    /// :func:`.fermion_to_majorana` and then normal-ordered and simplified. Refer to the former
+    /// for the definition of the operator.
+    ///
+    /// .. doctest::
+    ///    >>> import numpy as np
+    ///    >>> from qiskit_fermions.operators import MajoranaOperator
+    ///    >>> one_body_a = np.array([1.0, 2.0, 3.0])
+    ///    >>> op = MajoranaOperator.from_1body_tril_spin_sym(one_body_a, norb=2)
+    ///    >>> len(op)
+    ///    9
+    ///
+    /// Args:
+    ///     one_body_a: a 1-dimensional array of length :math:`n * (n + 1) / 2` storing the 1-body
+    ///         electronic integral coefficients of the :math:`\alpha`-spin species, as a flattened
+    ///         triangular matrix.
+    ///     norb: the number of orbitals, :math:`n`.
+    ///
+    /// Returns:
+    ///     The 1-body component of the electronic structure Hamiltonian in Majorana form.
+    /// ..
+    #[classmethod]
+    fn from_1body_tril_spin_sym(
+        _cls: &Bound<'_, PyType>,
+        one_body_a: PyReadonlyArray1<f64>,
+        norb: u32,
+    ) -> Self {
+        Self {
+            inner: MajoranaOperator::from_1body_tril_spin_sym(one_body_a.as_array(), norb),
+        }
+    }
+
+    /// Constructs an operator from spin-symmetric triangular 2-body integrals.
+    ///
+    /// This is the Majorana representation of the operator constructed by
+    /// :meth:`.FermionOperator.from_2body_tril_spin_sym`, as mapped by
+    /// :func:`.fermion_to_majorana` and then normal-ordered and simplified. Refer to the former
+    /// for the definition of the operator.
+    ///
+    /// .. doctest::
+    ///    >>> import numpy as np
+    ///    >>> from qiskit_fermions.operators import MajoranaOperator
+    ///    >>> two_body_aa = np.arange(1, 7, dtype=float)
+    ///    >>> op = MajoranaOperator.from_2body_tril_spin_sym(two_body_aa, norb=2)
+    ///    >>> len(op)
+    ///    27
+    ///
+    /// Args:
+    ///     two_body_aa: a 1-dimensional array of the S8-fold symmetric 2-body electronic integral
+    ///         coefficients of the :math:`\alpha\alpha`-spin species, as a flattened array.
+    ///     norb: the number of orbitals, :math:`n`.
+    ///     drop_vanishing: whether to skip the terms which vanish identically before mapping them
+    ///         (see :meth:`.FermionOperator.from_2body_tril_spin_sym`). This value defaults to
+    ///         ``False``.
+    ///     validate: whether to check that the length of ``two_body_aa`` matches the S8-fold
+    ///         symmetric layout (see :meth:`.FermionOperator.from_2body_tril_spin_sym`). This value
+    ///         defaults to ``False``.
+    ///
+    /// Raises:
+    ///     ValueError: if ``validate`` is set and the length of ``two_body_aa`` does not match the
+    ///         S8-fold symmetric layout.
+    ///
+    /// Returns:
+    ///     The 2-body component of the electronic structure Hamiltonian in Majorana form.
+    /// ..
+    #[classmethod]
+    #[pyo3(signature = (two_body_aa, norb, drop_vanishing=false, validate=false))]
+    fn from_2body_tril_spin_sym(
+        _cls: &Bound<'_, PyType>,
+        two_body_aa: PyReadonlyArray1<f64>,
+        norb: u32,
+        drop_vanishing: bool,
+        validate: bool,
+    ) -> PyResult<Self> {
+        if validate {
+            _verify_s8_layout(two_body_aa.as_array(), norb)?;
+        }
+        Ok(Self {
+            inner: MajoranaOperator::from_2body_tril_spin_sym(
+                two_body_aa.as_array(),
+                norb,
+                drop_vanishing,
+            ),
+        })
+    }
 }
@@ -11,11 +11,14 @@
 // that they have been altered from the originals.
 
 use crate::operators::fermion_operator::PyFermionOperator;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 use pyo3_stub_gen::derive::*;
 use qiskit_fermions_core::operators::fermion_operator::FermionOperator;
 use qiskit_fermions_core::operators::library::fcidump::FCIDump;
+use std::fs::File;
+use std::path::PathBuf;
 
 /// An electronic structure Hamiltonian in FCIDump format.
 ///
@@ -125,6 +128,24 @@ impl PyFCIDump {
     fn ms2(&self) -> u32 {
         self.inner.ms2
     }
+
+    /// Returns the number of spatial orbitals.
+    ///
+    /// This is an alias of :attr:`norb`.
+    #[getter]
+    fn num_spatial_orbitals(&self) -> u32 {
+        self.inner.norb
+    }
+
+    /// Returns the number of spin orbitals.
+    ///
+    /// This is twice the number of spatial orbitals (see :attr:`num_spatial_orbitals`) and equals
+    /// the number of fermionic modes acted upon by the operator constructed via
+    /// :meth:`.FermionOperator.from_fcidump`.
+    #[getter]
+    fn num_spin_orbitals(&self) -> u32 {
+        2 * self.inner.norb
+    }
 }
 
 #[pymodule]
@@ -149,15 +170,42 @@ impl PyFermionOperator {
     ///    fcidump = FCIDump.from_file("molecule.fcidump")
     ///    operator = FermionOperator.from_fcidump(fcidump)
     ///
+    /// When the :class:`.FCIDump` itself is not needed, the path may also be passed directly:
+    ///
+    /// .. code-block:: python
+    ///
+    ///    operator = FermionOperator.from_fcidump("molecule.fcidump")
+    ///
+    /// .. note::
+    ///    The operator only stores the integrals. The header information needed for preparing
+    ///    states, like the number of electrons and the spin, remains available on the
+    ///    :class:`.FCIDump` via its :attr:`~.FCIDump.norb`, :attr:`~.FCIDump.nelec` and
+    ///    :attr:`~.FCIDump.ms2` properties.
+    ///
     /// Args:
-    ///     fcidump: the FCIDump data structure.
+    ///     fcidump: the FCIDump data structure or a path to an FCIDump file.
     ///
     /// Returns:
     ///     The constructed operator.
+    ///
+    /// Raises:
+    ///     TypeError: if ``fcidump`` is neither an :class:`.FCIDump` nor a path.
+    ///     OSError: if the FCIDump file cannot be opened.
     #[classmethod]
-    fn from_fcidump(_cls: &Bound<'_, PyType>, fcidump: PyFCIDump) -> Self {
-        Self {
-            inner: FermionOperator::from(&fcidump.inner),
+    fn from_fcidump(_cls: &Bound<'_, PyType>, fcidump: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(fcidump) = fcidump.extract::<PyRef<PyFCIDump>>() {
+            return Ok(Self {
+                inner: FermionOperator::from(&fcidump.inner),
+            });
         }
+        let path: PathBuf = fcidump.extract().map_err(|_| {
+            PyTypeError::new_err("Expected an FCIDump or a path to an FCIDump file.")
+        })?;
+        // open the file here, so that a bad path raises an OSError rather than panicking
+        File::open(&path)?;
+        let fcidump = FCIDump::from_file(path.to_string_lossy().into_owned());
+        Ok(Self {
+            inner: FermionOperator::from(&fcidump),
+        })
     }
 }
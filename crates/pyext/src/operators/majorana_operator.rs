@@ -11,14 +11,18 @@
 // that they have been altered from the originals.
 
 use num_complex::Complex64;
+use numpy::PyReadonlyArray2;
 use pyo3::prelude::*;
-use pyo3::types::PyType;
-use pyo3::{class::basic::CompareOp, exceptions::PyNotImplementedError};
+use pyo3::types::{PyBytes, PyType};
+use pyo3::{
+    class::basic::CompareOp,
+    exceptions::{PyNotImplementedError, PyValueError},
+};
 use pyo3_stub_gen::derive::*;
 use std::collections::HashMap;
 
 use qiskit_fermions_core::operators::majorana_operator::MajoranaOperator;
-use qiskit_fermions_core::operators::{OperatorMacro, OperatorTrait};
+use qiskit_fermions_core::operators::{MAX_POW_TERMS, OperatorMacro, OperatorTrait};
 
 pub type PyMajoranaAction = u32;
 
@@ -148,6 +152,10 @@ impl MajoranaOperatorDataIter {
 ///
 ///    zero
 ///    one
+///    parity_operator
+///    from_antisymmetric_matrix
+///    from_gamma_dict
+///    from_bytes
 ///
 /// Iteration
 /// ---------
@@ -252,9 +260,22 @@ impl MajoranaOperatorDataIter {
 /// .. autosummary::
 ///
 ///    adjoint
+///    conjugate
+///    commutator
+///    anticommutator
+///    scaled_iadd
+///    add_constant
 ///    ichop
 ///    simplify
+///    round_coeffs
+///    chop_relative
+///    restrict_to_modes
+///    split_by_parity
 ///    normal_ordered
+///    to_fermion
+///    to_sparse_matrix
+///    to_gamma_dict
+///    to_bytes
 ///
 /// Properties
 /// ^^^^^^^^^^
@@ -264,8 +285,14 @@ impl MajoranaOperatorDataIter {
 /// .. autosummary::
 ///
 ///    is_hermitian
+///    is_real
 ///    many_body_order
+///    reduced_many_body_order
 ///    is_even
+///    is_quadratic
+///    mode_range
+///    coefficient_sum
+///    constant
 ///
 /// ----
 ///
@@ -341,6 +368,81 @@ impl PyMajoranaOperator {
         }
     }
 
+    /// Constructs a new operator from a dictionary keyed by ``(mode, is_prime)`` pairs.
+    ///
+    /// This is the inverse of :meth:`to_gamma_dict`. Each pair is encoded into the Majorana index
+    /// ``2 * mode + int(is_prime)``, following the convention of :func:`.gamma`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_gamma_dict({(): 1.0, ((0, False), (1, True)): 2.0})
+    ///     >>> print(op)
+    ///       1.000000e0 +0.000000e0j * ()
+    ///       2.000000e0 +0.000000e0j * (0 3)
+    ///
+    /// Args:
+    ///     data: a dictionary mapping tuples of ``(mode, is_prime)`` pairs to complex
+    ///         coefficients.
+    ///
+    /// Returns:
+    ///     A new operator.
+    ///
+    /// Raises:
+    ///     ValueError: if a mode is too large to be encoded as a Majorana index.
+    #[classmethod]
+    fn from_gamma_dict(
+        _cls: &Bound<'_, PyType>,
+        data: HashMap<Vec<(u32, bool)>, Complex64>,
+    ) -> PyResult<Self> {
+        let mut coeffs = vec![];
+        let mut modes = vec![];
+        let mut boundaries = vec![0];
+
+        for (terms, coeff) in data.iter() {
+            coeffs.push(*coeff);
+            for (mode, is_prime) in terms {
+                let index = mode
+                    .checked_mul(2)
+                    .and_then(|index| index.checked_add(*is_prime as u32))
+                    .ok_or_else(|| {
+                        PyValueError::new_err(format!("Mode {mode} is too large to be encoded."))
+                    })?;
+                modes.push(index);
+            }
+            boundaries.push(modes.len());
+        }
+
+        Ok(Self {
+            inner: MajoranaOperator {
+                coeffs,
+                modes,
+                boundaries,
+            },
+        })
+    }
+
+    /// Returns a dictionary representation of this operator keyed by ``(mode, is_prime)`` pairs.
+    ///
+    /// Each Majorana index is decoded into ``(index // 2, index % 2 == 1)``, following the
+    /// convention of :func:`.gamma`. The coefficients of duplicate terms are summed.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 3): 2.0})
+    ///     >>> op.to_gamma_dict()
+    ///     {((0, False), (1, True)): (2+0j)}
+    ///
+    /// Returns:
+    ///     A dictionary mapping tuples of ``(mode, is_prime)`` pairs to complex coefficients.
+    fn to_gamma_dict(&self) -> HashMap<Vec<(u32, bool)>, Complex64> {
+        let mut data = HashMap::new();
+        for term in self.inner.iter() {
+            let key = term.modes.iter().map(|m| (m / 2, m % 2 == 1)).collect();
+            *data.entry(key).or_insert(Complex64::new(0.0, 0.0)) += term.coeff;
+        }
+        data
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, _py: Python<'_>) -> PyResult<bool> {
         match op {
             CompareOp::Eq => {
@@ -397,16 +499,7 @@ impl PyMajoranaOperator {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        let mut sorted: Vec<_> = self.inner.iter().collect();
-        sorted.sort_by_key(|&term| term.into_vec());
-        let mut items_str = Vec::new();
-        for term in sorted {
-            let key_parts: Vec<String> = term.iter().map(|mode| format!("{mode}")).collect();
-            let key_str = format!("({})", key_parts.join(" "));
-            let val_str = format!("{:12.6e}{:+12.6e}j", term.coeff.re, term.coeff.im);
-            items_str.push(format!("{val_str} * {key_str}"));
-        }
-        Ok(items_str.join("\n").to_string())
+        Ok(self.inner.to_string())
     }
 
     /// Constructs the additive identity operator.
@@ -447,6 +540,96 @@ impl PyMajoranaOperator {
         }
     }
 
+    /// Constructs the fermionic parity operator.
+    ///
+    /// In terms of Majorana fermions (under the default convention of
+    /// :func:`.fermion_to_majorana`), the parity operator is defined as
+    /// :math:`P = \prod_k (1 - 2 n_k) = \prod_k (-i \gamma_k \gamma'_k)`.
+    ///
+    /// Its single term stores the modes in ascending order. Since the modes of a term act in the
+    /// order in which they are stored, reversing them into the product above contributes a sign of
+    /// :math:`(-1)^n` for :math:`n` fermionic modes, which is why the stored coefficient is
+    /// :math:`i^n`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> parity = MajoranaOperator.parity_operator(2)
+    ///     >>> print(parity)
+    ///      -1.000000e0 +0.000000e0j * (0 1 2 3)
+    ///     >>> squared = (parity & parity).normal_ordered().simplify()
+    ///     >>> squared.equiv(MajoranaOperator.one())
+    ///     True
+    ///
+    /// Args:
+    ///     num_modes: the number of fermionic modes.
+    ///
+    /// Returns:
+    ///     The parity operator acting on ``num_modes`` fermionic modes.
+    #[classmethod]
+    fn parity_operator(_cls: &Bound<'_, PyType>, num_modes: u32) -> Self {
+        Self {
+            inner: MajoranaOperator::parity_operator(num_modes),
+        }
+    }
+
+    /// Constructs a quadratic operator from an antisymmetric coefficient matrix.
+    ///
+    /// Given a real antisymmetric matrix :math:`h` indexed by Majorana modes, this constructs the
+    /// Hermitian operator
+    ///
+    /// .. math::
+    ///
+    ///    \sum_{j \lt k} h_{jk} \, i \gamma_j \gamma_k \, .
+    ///
+    /// Such operators describe free-fermion (Gaussian) Hamiltonians.
+    ///
+    /// .. doctest::
+    ///     >>> import numpy as np
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> h = np.array([[0.0, 1.0], [-1.0, 0.0]])
+    ///     >>> op = MajoranaOperator.from_antisymmetric_matrix(h)
+    ///     >>> print(op)
+    ///       0.000000e0 +1.000000e0j * (0 1)
+    ///     >>> op.is_hermitian()
+    ///     True
+    ///
+    /// Args:
+    ///     h: the square, real and antisymmetric coefficient matrix.
+    ///     atol: the absolute tolerance used for checking the antisymmetry of ``h``.
+    ///
+    /// Returns:
+    ///     The quadratic Majorana operator.
+    ///
+    /// Raises:
+    ///     ValueError: if ``h`` is not square or not antisymmetric within ``atol``.
+    #[classmethod]
+    #[pyo3(signature = (h, atol=1e-8))]
+    fn from_antisymmetric_matrix(
+        _cls: &Bound<'_, PyType>,
+        h: PyReadonlyArray2<f64>,
+        atol: f64,
+    ) -> PyResult<Self> {
+        let h = h.as_array();
+        if h.nrows() != h.ncols() {
+            return Err(PyValueError::new_err(format!(
+                "The matrix must be square but has shape ({}, {}).",
+                h.nrows(),
+                h.ncols()
+            )));
+        }
+        if let Some(((j, k), _)) = h
+            .indexed_iter()
+            .find(|&((j, k), value)| (value + h[[k, j]]).abs() > atol)
+        {
+            return Err(PyValueError::new_err(format!(
+                "The matrix is not antisymmetric: h[{j}, {k}] + h[{k}, {j}] exceeds {atol}."
+            )));
+        }
+        Ok(Self {
+            inner: MajoranaOperator::from_antisymmetric_matrix(h),
+        })
+    }
+
     fn __len__(&self) -> usize {
         self.inner.boundaries.len() - 1
     }
@@ -454,12 +637,14 @@ impl PyMajoranaOperator {
     fn __pow__(&self, exponent: u32, modulo: Option<u32>) -> PyResult<Self> {
         match modulo {
             Some(_) => Err(PyNotImplementedError::new_err("mod argument not supported")),
-            None => {
-                let result = Self {
-                    inner: self.inner.__pow__(exponent as usize),
-                };
-                Ok(result)
-            }
+            None => match self.inner.checked_pow(exponent as usize, MAX_POW_TERMS) {
+                Some(inner) => Ok(Self { inner }),
+                None => Err(PyValueError::new_err(format!(
+                    "Raising an operator with {} terms to the power {exponent} exceeds the \
+                     maximum of {MAX_POW_TERMS} terms.",
+                    self.inner.coeffs.len()
+                ))),
+            },
         }
     }
 
@@ -497,6 +682,124 @@ impl PyMajoranaOperator {
         }
     }
 
+    /// Returns a simplified operator without terms whose coefficient magnitude lies below a
+    /// fraction of the largest one.
+    ///
+    /// Unlike :meth:`.simplify`, which uses an absolute cutoff, this method is agnostic to the
+    /// overall scale of the coefficients. Since terms are first simplified (see
+    /// :meth:`.simplify`), no information is lost due to duplicate terms.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.0, (0,): 1e-3, (1,): 1e-7})
+    ///     >>> print(op.chop_relative(1e-4))
+    ///       1.000000e0 +0.000000e0j * ()
+    ///      1.000000e-3 +0.000000e0j * (0)
+    ///
+    /// Args:
+    ///     rtol: the cutoff relative to the largest coefficient magnitude. A value of ``0`` keeps
+    ///         all terms.
+    ///
+    /// Returns:
+    ///     An equivalent but truncated and simplified operator.
+    fn chop_relative(&self, rtol: f64) -> Self {
+        Self {
+            inner: self.inner.chop_relative(rtol),
+        }
+    }
+
+    /// Projects this operator onto the modes below ``max_index_exclusive``.
+    ///
+    /// Only the terms which act exclusively on these modes are kept. Note that this is **not** a
+    /// partial trace: terms touching any other mode are discarded rather than evaluated on some
+    /// state of the remaining modes.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 1): 1.0, (1, 2): 2.0})
+    ///     >>> print(op.restrict_to_modes(2))
+    ///       1.000000e0 +0.000000e0j * (0 1)
+    ///
+    /// Args:
+    ///     max_index_exclusive: the number of leading modes to restrict this operator to.
+    ///
+    /// Returns:
+    ///     A new operator containing only the terms acting on the leading modes.
+    fn restrict_to_modes(&self, max_index_exclusive: u32) -> Self {
+        Self {
+            inner: self.inner.restrict_to_modes(max_index_exclusive),
+        }
+    }
+
+    /// Splits this operator into its even and odd fermion-parity sectors.
+    ///
+    /// The terms acting on an even (odd) number of modes make up the even (odd) part. Repeated
+    /// modes are not reduced beforehand.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 1): 1.0, (0, 1, 2): 2.0})
+    ///     >>> even, odd = op.split_by_parity()
+    ///     >>> print(even)
+    ///       1.000000e0 +0.000000e0j * (0 1)
+    ///     >>> print(odd)
+    ///       2.000000e0 +0.000000e0j * (0 1 2)
+    ///
+    /// Returns:
+    ///     A tuple of the even and the odd part of this operator.
+    fn split_by_parity(&self) -> (Self, Self) {
+        let (even, odd) = self.inner.split_by_parity();
+        (Self { inner: even }, Self { inner: odd })
+    }
+
+    /// Computes the sparse matrix of this operator on the Fock space.
+    ///
+    /// The Majorana modes :math:`2j` and :math:`2j + 1` act as
+    /// :math:`\gamma_j = a^\dagger_j + a_j` and :math:`\gamma'_j = i (a^\dagger_j - a_j)`,
+    /// respectively, with the fermionic operators represented by their Jordan-Wigner images. The
+    /// modes of a term act in the order in which they are stored, i.e. the first one acts first.
+    /// Thus, an operator obtained from :func:`.fermion_to_majorana` has the same matrix as the
+    /// original :class:`.FermionOperator` (see :meth:`.FermionOperator.to_sparse_matrix`).
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> maj_op = MajoranaOperator.from_dict({(): 0.5, (1, 0): 0.5j})
+    ///     >>> maj_op.to_sparse_matrix(1).toarray().real
+    ///     array([[0., 0.],
+    ///            [0., 1.]])
+    ///
+    /// Args:
+    ///     num_modes: the number of fermionic modes, :math:`n`, corresponding to :math:`2n`
+    ///         Majorana modes.
+    ///
+    /// Returns:
+    ///     The ``scipy.sparse.coo_matrix`` of shape :math:`(2^n, 2^n)`.
+    ///
+    /// Raises:
+    ///     ValueError: if the operator acts on a Majorana mode outside of ``2 * num_modes`` or if
+    ///         ``num_modes`` exceeds 30.
+    fn to_sparse_matrix<'py>(
+        &self,
+        py: Python<'py>,
+        num_modes: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::operators::check_fock_modes(num_modes)?;
+        if let Some(mode) = self
+            .inner
+            .modes
+            .iter()
+            .find(|mode| u64::from(**mode) >= 2 * u64::from(num_modes))
+        {
+            return Err(PyValueError::new_err(format!(
+                "The operator acts on Majorana mode {mode} which exceeds the {} Majorana modes of \
+                 {num_modes} fermionic modes.",
+                2 * u64::from(num_modes)
+            )));
+        }
+        let (rows, cols, values) = self.inner.to_sparse_matrix(num_modes);
+        crate::operators::coo_matrix(py, rows, cols, values, 1 << num_modes)
+    }
+
     /// Removes terms whose coefficient magnitude lies below the provided threshold.
     ///
     /// .. caution::
@@ -535,14 +838,29 @@ impl PyMajoranaOperator {
     ///     >>> op = MajoranaOperator.from_dict({(): 2.0, (0,): 1.0, (1,): -1.0j})
     ///     >>> list(sorted(op.iter_terms()))
     ///     [([], (2+0j)), ([0], (1+0j)), ([1], (-0-1j))]
+    ///     >>> op = MajoranaOperator.from_dict({(): 2.0, (0,): 1.0, (1,): -3.0j})
+    ///     >>> list(op.iter_terms(sort="magnitude"))
+    ///     [([1], (-0-3j)), ([], (2+0j)), ([0], (1+0j))]
     ///
-    /// ..
-    fn iter_terms(slf: PyRef<'_, Self>) -> PyResult<Py<MajoranaOperatorDataIter>> {
-        let vectorized: Vec<(Vec<PyMajoranaAction>, Complex64)> = slf
+    /// Args:
+    ///     sort: the order in which to yield the terms. ``"magnitude"`` yields them by descending
+    ///         coefficient magnitude and ``"term"`` by ascending modes. Terms which compare equal
+    ///         retain their relative order. By default, the terms are yielded in the order in
+    ///         which they are stored.
+    ///
+    /// Raises:
+    ///     ValueError: if ``sort`` is not one of the values listed above.
+    #[pyo3(signature = (sort=None))]
+    fn iter_terms(
+        slf: PyRef<'_, Self>,
+        sort: Option<&str>,
+    ) -> PyResult<Py<MajoranaOperatorDataIter>> {
+        let mut vectorized: Vec<(Vec<PyMajoranaAction>, Complex64)> = slf
             .inner
             .iter()
             .map(|term| (term.into_vec(), term.coeff))
             .collect();
+        super::sort_vectorized_terms(&mut vectorized, sort)?;
         let iter = MajoranaOperatorDataIter {
             inner: vectorized.into_iter(),
         };
@@ -571,6 +889,120 @@ impl PyMajoranaOperator {
         }
     }
 
+    /// Returns the complex conjugate of this operator.
+    ///
+    /// Unlike :meth:`.adjoint`, this only complex conjugates the coefficients and leaves the terms
+    /// unchanged.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 1): 1.0j})
+    ///     >>> print(op.conjugate())  # doctest: +FLOAT_CMP
+    ///       0.000000e0 -1.000000e0j * (0 1)
+    ///     >>> print(op.adjoint())  # doctest: +FLOAT_CMP
+    ///       0.000000e0 -1.000000e0j * (1 0)
+    ///
+    /// Returns:
+    ///     The complex conjugated operator.
+    fn conjugate(&self) -> Self {
+        Self {
+            inner: self.inner.conjugate(),
+        }
+    }
+
+    /// Returns this operator with the coefficients rounded to a given number of decimals.
+    ///
+    /// This is useful for cleaning up numerical noise like ``0.9999999999998`` accumulated
+    /// during arithmetic. The real and imaginary parts are rounded separately, with ties
+    /// rounded away from zero. Terms whose coefficients round to zero are kept; use
+    /// :meth:`simplify` to remove them.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 1): 0.9999999 + 0.1234567j})
+    ///     >>> print(op.round_coeffs(3))
+    ///       1.000000e0+1.230000e-1j * (0 1)
+    ///
+    /// Args:
+    ///     decimals: the number of decimal places to round to. Negative values round to the left
+    ///         of the decimal point.
+    ///
+    /// Returns:
+    ///     The operator with rounded coefficients.
+    fn round_coeffs(&self, decimals: i32) -> Self {
+        Self {
+            inner: self.inner.round_coeffs(decimals),
+        }
+    }
+
+    /// Returns the commutator of this operator with another one.
+    ///
+    /// This computes ``self & other - other & self`` and thus follows the left-multiplication
+    /// convention of the operator composition (see above). It is equivalent to
+    /// :func:`.commutator`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> gamma_0 = MajoranaOperator.from_dict({(0,): 1.0})
+    ///     >>> gamma_1 = MajoranaOperator.from_dict({(1,): 1.0})
+    ///     >>> print(gamma_0.commutator(gamma_1).normal_ordered().simplify())
+    ///       2.000000e0 +0.000000e0j * (1 0)
+    ///
+    /// Args:
+    ///     other: the other operator.
+    ///
+    /// Returns:
+    ///     The commutator.
+    fn commutator(&self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.commutator(&other.inner),
+        }
+    }
+
+    /// Returns the anti-commutator of this operator with another one.
+    ///
+    /// This computes ``self & other + other & self`` and is equivalent to
+    /// :func:`.anti_commutator`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> gamma_0 = MajoranaOperator.from_dict({(0,): 1.0})
+    ///     >>> print(gamma_0.anticommutator(gamma_0).normal_ordered().simplify())
+    ///       2.000000e0 +0.000000e0j * ()
+    ///
+    /// Args:
+    ///     other: the other operator.
+    ///
+    /// Returns:
+    ///     The anti-commutator.
+    fn anticommutator(&self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.anticommutator(&other.inner),
+        }
+    }
+
+    /// Adds a scaled operator to this one in-place.
+    ///
+    /// This is equivalent to ``self += scale * other`` but avoids the construction of the
+    /// intermediate scaled copy of ``other``, which makes it well suited for accumulating many
+    /// weighted operators.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.0})
+    ///     >>> other = MajoranaOperator.from_dict({(0, 1): 1.0})
+    ///     >>> op.scaled_iadd(other, 3.0)
+    ///     >>> print(op)
+    ///       1.000000e0 +0.000000e0j * ()
+    ///       3.000000e0 +0.000000e0j * (0 1)
+    ///
+    /// Args:
+    ///     other: the operator to add.
+    ///     scale: the factor by which to scale the coefficients of ``other``.
+    fn scaled_iadd(&mut self, other: &Self, scale: Complex64) {
+        self.inner.scaled_iadd(&other.inner, scale);
+    }
+
     /// Checks this operator for equivalence with another operator.
     ///
     /// Equivalence in this context means approximate equality up to the specified absolute
@@ -629,11 +1061,17 @@ impl PyMajoranaOperator {
     /// Returns whether this operator is Hermitian.
     ///
     /// .. note::
-    ///    This check is implemented using :meth:`.equiv` on the :meth:`.normal_ordered` difference
-    ///    of ``self`` and its :meth:`.adjoint` and :meth:`.zero`.
+    ///    Rather than constructing the :meth:`.adjoint`, this check reduces and merges the terms
+    ///    (see :meth:`.normal_ordered` and :meth:`.simplify`). Reversing a term of :math:`k`
+    ///    distinct modes yields the sign :math:`(-1)^{k(k-1)/2}`, such that, for example,
+    ///    :math:`\gamma_0 \gamma_1` is anti-Hermitian and :math:`i \gamma_0 \gamma_1` is Hermitian.
     ///
     /// .. doctest::
     ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> MajoranaOperator.from_dict({(0, 1): 1.0}).is_hermitian()
+    ///     False
+    ///     >>> MajoranaOperator.from_dict({(0, 1): 1.0j}).is_hermitian()
+    ///     True
     ///     >>> op = MajoranaOperator.from_dict({
     ///     ...     (0, 1, 2, 3): 1.00001j,
     ///     ...     (3, 2, 1, 0): -1j,
@@ -654,6 +1092,27 @@ impl PyMajoranaOperator {
         self.inner.is_hermitian(atol)
     }
 
+    /// Returns whether all coefficients of this operator are real.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.0, (0, 1): -0.5 + 1e-6j})
+    ///     >>> op.is_real()
+    ///     False
+    ///     >>> op.is_real(1e-4)
+    ///     True
+    ///
+    /// Args:
+    ///     atol: the absolute tolerance up to which imaginary parts are considered zero. This
+    ///         value defaults to ``1e-8``.
+    ///
+    /// Returns:
+    ///     Whether the imaginary parts of all coefficients lie within ``atol`` of zero.
+    #[pyo3(signature = (atol=1e-8))]
+    fn is_real(&self, atol: f64) -> bool {
+        self.inner.is_real(atol)
+    }
+
     /// Returns the many-body order of this operator.
     ///
     /// .. note::
@@ -672,6 +1131,27 @@ impl PyMajoranaOperator {
         self.inner.many_body_order()
     }
 
+    /// Returns the many-body order of this operator after reducing repeated modes.
+    ///
+    /// Since every Majorana mode squares to the identity, a term like :math:`\gamma_0 \gamma_0
+    /// \gamma_1` acts like :math:`\gamma_1`. Unlike :meth:`many_body_order`, which reports the raw
+    /// length of the longest term, this method reports the longest length after removing such
+    /// pairs. The operator itself is not modified.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 0, 1): 1})
+    ///     >>> op.many_body_order()
+    ///     3
+    ///     >>> op.reduced_many_body_order()
+    ///     1
+    ///
+    /// Returns:
+    ///     The many-body order of this operator after reduction.
+    fn reduced_many_body_order(&self) -> u32 {
+        self.inner.reduced_many_body_order()
+    }
+
     /// Returns whether this operator is even.
     ///
     /// .. note::
@@ -691,6 +1171,141 @@ impl PyMajoranaOperator {
     fn is_even(&self) -> bool {
         self.inner.is_even()
     }
+
+    /// Returns whether this operator is quadratic.
+    ///
+    /// An operator is considered quadratic if every one of its terms, after reducing repeated
+    /// modes (see :meth:`.normal_ordered`), acts on at most two Majorana modes.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(0, 3): 0.5j, (1, 2): -0.5j})
+    ///     >>> op.is_quadratic()
+    ///     True
+    ///     >>> op = MajoranaOperator.from_dict({(0, 1, 2, 3): -0.25})
+    ///     >>> op.is_quadratic()
+    ///     False
+    ///
+    /// Returns:
+    ///     Whether this operator is quadratic.
+    fn is_quadratic(&self) -> bool {
+        self.inner.is_quadratic()
+    }
+
+    /// Returns the sum of all coefficients of this operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.5, (0, 1): 2.0})
+    ///     >>> op.coefficient_sum()
+    ///     (3.5+0j)
+    ///
+    /// Returns:
+    ///     The sum of all coefficients.
+    fn coefficient_sum(&self) -> Complex64 {
+        self.inner.coefficient_sum()
+    }
+
+    /// Returns the constant (or identity) coefficient of this operator.
+    ///
+    /// Since an operator may contain multiple identity terms, their coefficients are summed.
+    /// Terms which reduce to a constant only after normal ordering are not considered.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.5, (0, 1): 2.0})
+    ///     >>> op.constant()
+    ///     (1.5+0j)
+    ///
+    /// Returns:
+    ///     The sum of the coefficients of all identity terms.
+    fn constant(&self) -> Complex64 {
+        self.inner.constant()
+    }
+
+    /// Returns this operator with an additional identity term.
+    ///
+    /// This is a shorthand for ``self + c * MajoranaOperator.one()``. The new term is appended and
+    /// not merged with any existing identity terms.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.5, (0, 1): 2.0})
+    ///     >>> op.add_constant(0.5).constant()
+    ///     (2+0j)
+    ///
+    /// Args:
+    ///     c: the coefficient of the added identity term.
+    ///
+    /// Returns:
+    ///     The shifted operator.
+    fn add_constant(&self, c: Complex64) -> Self {
+        Self {
+            inner: self.inner.add_constant(c),
+        }
+    }
+
+    /// Returns the smallest and largest mode acted upon by this operator.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(4, 1): 1, (2,): 1})
+    ///     >>> op.mode_range()
+    ///     (1, 4)
+    ///     >>> print(MajoranaOperator.one().mode_range())
+    ///     None
+    ///
+    /// Returns:
+    ///     A tuple of the smallest and largest mode or ``None`` if this operator contains no
+    ///     modes.
+    fn mode_range(&self) -> Option<(u32, u32)> {
+        self.inner.mode_range()
+    }
+
+    /// Serializes this operator into a versioned binary format.
+    ///
+    /// The result can be turned back into an operator using :meth:`from_bytes`. This is also
+    /// used to support :mod:`pickle`.
+    ///
+    /// .. doctest::
+    ///     >>> from qiskit_fermions.operators import MajoranaOperator
+    ///     >>> op = MajoranaOperator.from_dict({(): 1.0, (0, 1): 2.0j})
+    ///     >>> data = op.to_bytes()
+    ///     >>> data[:4]
+    ///     b'QFMO'
+    ///     >>> MajoranaOperator.from_bytes(data) == op
+    ///     True
+    ///
+    /// Returns:
+    ///     The serialized operator.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.inner.to_bytes())
+    }
+
+    /// Deserializes an operator previously serialized with :meth:`to_bytes`.
+    ///
+    /// Args:
+    ///     data: the serialized operator.
+    ///
+    /// Returns:
+    ///     A new operator.
+    ///
+    /// Raises:
+    ///     ValueError: if ``data`` is not a valid serialized operator, was written by an
+    ///         unsupported version, or contains a mode beyond the supported range.
+    #[classmethod]
+    fn from_bytes(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        MajoranaOperator::from_bytes(data)
+            .map(|inner| Self { inner })
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        let from_bytes = slf.get_type().getattr("from_bytes")?;
+        Ok((from_bytes, (slf.borrow().to_bytes(slf.py()),)))
+    }
 }
 
 #[pymodule]
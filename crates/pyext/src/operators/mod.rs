@@ -10,7 +10,12 @@
 // copyright notice, and modified files need to carry a notice indicating
 // that they have been altered from the originals.
 
+use num_complex::Complex64;
+use numpy::IntoPyArray;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use qiskit_fermions_core::operators::MAX_FOCK_MODES;
 
 #[macro_export]
 macro_rules! impl_operator_magic_methods {
@@ -79,10 +84,68 @@ macro_rules! impl_operator_magic_methods {
             fn __iand__(&mut self, other: &Self) {
                 self.inner.__iand__(&other.inner);
             }
+
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+
+            fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+                self.clone()
+            }
         }
     };
 }
 
+/// Raises a ``ValueError`` if the Fock space of `num_modes` modes exceeds [`MAX_FOCK_MODES`], which
+/// the core methods enumerating all of its basis states would panic on.
+pub(crate) fn check_fock_modes(num_modes: u32) -> PyResult<()> {
+    if num_modes > MAX_FOCK_MODES {
+        return Err(PyValueError::new_err(format!(
+            "The Fock space of {num_modes} modes exceeds the maximum of {MAX_FOCK_MODES} modes."
+        )));
+    }
+    Ok(())
+}
+
+/// Constructs a square ``scipy.sparse.coo_matrix`` of dimension `dim` from its COO triplets.
+pub(crate) fn coo_matrix<'py>(
+    py: Python<'py>,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<Complex64>,
+    dim: usize,
+) -> PyResult<Bound<'py, PyAny>> {
+    let data = (
+        values.into_pyarray(py),
+        (rows.into_pyarray(py), cols.into_pyarray(py)),
+    );
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("shape", (dim, dim))?;
+    py.import("scipy.sparse")?
+        .getattr("coo_matrix")?
+        .call((data,), Some(&kwargs))
+}
+
+/// Sorts vectorized terms for the ``iter_terms`` methods according to the `sort` argument, which
+/// may be ``"magnitude"`` (descending coefficient magnitude), ``"term"`` (ascending actions) or
+/// ``None`` (leaving the order unchanged). The sorting is stable.
+pub(crate) fn sort_vectorized_terms<T: Ord>(
+    terms: &mut [(T, Complex64)],
+    sort: Option<&str>,
+) -> PyResult<()> {
+    match sort {
+        None => {}
+        Some("magnitude") => terms.sort_by(|a, b| b.1.norm().total_cmp(&a.1.norm())),
+        Some("term") => terms.sort_by(|a, b| a.0.cmp(&b.0)),
+        Some(other) => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid sort order '{other}', expected 'magnitude', 'term' or None."
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub mod fermion_operator;
 pub mod library;
 pub mod majorana_operator;
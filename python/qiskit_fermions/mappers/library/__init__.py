@@ -25,11 +25,19 @@ routines.
    :toctree: ../stubs/
 
    jordan_wigner
+   jordan_wigner_to_json
+   inverse_jordan_wigner
+   inverse_jordan_wigner_checked
    fermion_to_majorana
    majorana_to_fermion
 """
 
-from qiskit_fermions._lib.mappers.mappers_library.jordan_wigner import jordan_wigner
+from qiskit_fermions._lib.mappers.mappers_library.jordan_wigner import (
+    inverse_jordan_wigner,
+    inverse_jordan_wigner_checked,
+    jordan_wigner,
+    jordan_wigner_to_json,
+)
 from qiskit_fermions._lib.mappers.mappers_library.majorana_fermion import (
     fermion_to_majorana,
     majorana_to_fermion,
@@ -37,6 +45,9 @@ from qiskit_fermions._lib.mappers.mappers_library.majorana_fermion import (
 
 __all__ = [
     "fermion_to_majorana",
+    "inverse_jordan_wigner",
+    "inverse_jordan_wigner_checked",
     "jordan_wigner",
+    "jordan_wigner_to_json",
     "majorana_to_fermion",
 ]
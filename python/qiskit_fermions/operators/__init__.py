@@ -32,8 +32,10 @@ annihilation operators.
 
    FermionAction
    FermionOperator
+   FermionOperatorF32
    cre
    ann
+   assert_operators_equiv
 
 Majorana Operator
 -----------------
@@ -48,7 +50,11 @@ This operator represents fermionic operators in terms of Majorana fermions.
    gamma
 """
 
-from qiskit_fermions._lib.operators.fermion_operator import FermionOperator
+from qiskit_fermions._lib.operators.fermion_operator import (
+    FermionOperator,
+    FermionOperatorF32,
+    assert_operators_equiv,
+)
 from qiskit_fermions._lib.operators.majorana_operator import MajoranaOperator
 
 from .fermion_action import FermionAction, ann, cre
@@ -57,9 +63,11 @@ from .majorana_action import MajoranaAction, gamma
 __all__ = [
     "FermionAction",
     "FermionOperator",
+    "FermionOperatorF32",
     "MajoranaAction",
     "MajoranaOperator",
     "ann",
+    "assert_operators_equiv",
     "cre",
     "gamma",
 ]
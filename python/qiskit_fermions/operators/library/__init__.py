@@ -57,19 +57,21 @@ Hamiltonians as an input. Different flavors exist:
 
 .. table::
 
-   ================================================= ===========================================================
-   :meth:`.FermionOperator.from_1body_tril_spin_sym` Constructs from spin-symmetric triangular 1-body integrals.
-   :meth:`.FermionOperator.from_1body_tril_spin`     Constructs from separate spin triangular 1-body integrals.
-   ================================================= ===========================================================
+   ================================================== ===========================================================
+   :meth:`.FermionOperator.from_1body_tril_spin_sym`  Constructs from spin-symmetric triangular 1-body integrals.
+   :meth:`.FermionOperator.from_1body_tril_spin`      Constructs from separate spin triangular 1-body integrals.
+   :meth:`.MajoranaOperator.from_1body_tril_spin_sym` Constructs from spin-symmetric triangular 1-body integrals.
+   ================================================== ===========================================================
 
 * 2-Body Terms
 
 .. table::
 
-   ================================================= ===========================================================
-   :meth:`.FermionOperator.from_2body_tril_spin_sym` Constructs from spin-symmetric triangular 2-body integrals.
-   :meth:`.FermionOperator.from_2body_tril_spin`     Constructs from separate spin triangular 2-body integrals.
-   ================================================= ===========================================================
+   ================================================== ===========================================================
+   :meth:`.FermionOperator.from_2body_tril_spin_sym`  Constructs from spin-symmetric triangular 2-body integrals.
+   :meth:`.FermionOperator.from_2body_tril_spin`      Constructs from separate spin triangular 2-body integrals.
+   :meth:`.MajoranaOperator.from_2body_tril_spin_sym` Constructs from spin-symmetric triangular 2-body integrals.
+   ================================================== ===========================================================
 
 Other Generators
 ----------------
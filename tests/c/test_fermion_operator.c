@@ -16,6 +16,7 @@
 #include <stdint.h>
 #include <stdio.h>
 #include <stdnoreturn.h>
+#include <string.h>
 
 static int test_new(void) {
     uint64_t num_terms = 3;
@@ -84,6 +85,31 @@ static int test_add_term(void) {
     return Ok;
 }
 
+static int test_add_constant(void) {
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool actions[2] = {true, false};
+    uint32_t indices[2] = {0, 1};
+    QkComplex64 coeff = {2.0, 0.0};
+    qf_ferm_op_add_term(op, 2, actions, indices, &coeff);
+
+    QfFermionOperator *expected = qf_ferm_op_zero();
+    qf_ferm_op_add_term(expected, 2, actions, indices, &coeff);
+    QkComplex64 offset = {1.5, 0.0};
+    qf_ferm_op_add_term(expected, 0, NULL, NULL, &offset);
+
+    QfExitCode exit = qf_ferm_op_add_constant(op, &offset);
+
+    bool is_equal = (exit == QfExitCode_Success) && qf_ferm_op_equal(op, expected);
+
+    qf_ferm_op_free(op);
+    qf_ferm_op_free(expected);
+
+    if (!is_equal) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 static int test_equiv_pos(void) {
     QfFermionOperator *op = qf_ferm_op_zero();
     QkComplex64 coeff = {1e-7, 0.0};
@@ -120,6 +146,35 @@ static int test_equiv_neg(void) {
     return Ok;
 }
 
+static int test_equiv_up_to_order(void) {
+    // two-body operator: a+_0 a+_1 a_1 a_0
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool actions[4] = {true, true, false, false};
+    uint32_t indices[4] = {0, 1, 1, 0};
+    QkComplex64 coeff = {1.0, 0.0};
+    qf_ferm_op_add_term(op, 4, actions, indices, &coeff);
+
+    // the same operator plus a small three-body perturbation: 0.01 a+_0 a+_1 a+_2 a_2 a_1 a_0
+    QfFermionOperator *perturbed = qf_ferm_op_zero();
+    qf_ferm_op_add_term(perturbed, 4, actions, indices, &coeff);
+    bool pert_actions[6] = {true, true, true, false, false, false};
+    uint32_t pert_indices[6] = {0, 1, 2, 2, 1, 0};
+    QkComplex64 pert_coeff = {0.01, 0.0};
+    qf_ferm_op_add_term(perturbed, 6, pert_actions, pert_indices, &pert_coeff);
+
+    // the many-body order counts the actions of a term, i.e. a two-body term has order 4
+    bool is_equiv = qf_ferm_op_equiv_up_to_order(op, perturbed, 4, 1e-8);
+    bool is_not_equiv = !qf_ferm_op_equiv_up_to_order(op, perturbed, 6, 1e-8);
+
+    qf_ferm_op_free(op);
+    qf_ferm_op_free(perturbed);
+
+    if (!is_equiv || !is_not_equiv) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 static int test_mul(void) {
     QfFermionOperator *one = qf_ferm_op_one();
 
@@ -179,6 +234,69 @@ static int test_compose(void) {
     return Ok;
 }
 
+static int test_pow(void) {
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool action[1] = {true};
+    uint32_t index[1] = {0};
+    QkComplex64 coeff = {2.0, 0.0};
+    qf_ferm_op_add_term(op, 1, action, index, &coeff);
+
+    // exponent=0
+    QfFermionOperator *result0 = qf_ferm_op_pow(op, 0);
+    QfFermionOperator *one = qf_ferm_op_one();
+    bool is_equal0 = qf_ferm_op_equal(result0, one);
+
+    // exponent=1
+    QfFermionOperator *result1 = qf_ferm_op_pow(op, 1);
+    bool is_equal1 = qf_ferm_op_equal(result1, op);
+
+    // exponent=2
+    QfFermionOperator *result2 = qf_ferm_op_pow(op, 2);
+    QfFermionOperator *expected = qf_ferm_op_zero();
+    bool actions_exp[2] = {true, true};
+    uint32_t indices_exp[2] = {0, 0};
+    QkComplex64 coeff_exp = {4.0, 0.0};
+    qf_ferm_op_add_term(expected, 2, actions_exp, indices_exp, &coeff_exp);
+    bool is_equal2 = qf_ferm_op_equal(result2, expected);
+
+    qf_ferm_op_free(op);
+    qf_ferm_op_free(result0);
+    qf_ferm_op_free(one);
+    qf_ferm_op_free(result1);
+    qf_ferm_op_free(result2);
+    qf_ferm_op_free(expected);
+
+    if (!is_equal0 || !is_equal1 || !is_equal2) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
+static int test_pow_too_large(void) {
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool action[1] = {true};
+    uint32_t index[1] = {0};
+    QkComplex64 coeff = {1.0, 0.0};
+    qf_ferm_op_add_term(op, 1, action, index, &coeff);
+    index[0] = 1;
+    qf_ferm_op_add_term(op, 1, action, index, &coeff);
+
+    // 2^10 terms are fine, but 2^100 terms are rejected
+    QfFermionOperator *result10 = qf_ferm_op_pow(op, 10);
+    QfFermionOperator *result100 = qf_ferm_op_pow(op, 100);
+    bool is_null10 = result10 == NULL;
+    bool is_null100 = result100 == NULL;
+
+    qf_ferm_op_free(op);
+    qf_ferm_op_free(result10);
+    qf_ferm_op_free(result100);
+
+    if (is_null10 || !is_null100) {
+        return NullptrError;
+    }
+    return Ok;
+}
+
 static int test_ichop(void) {
     QfFermionOperator *op = qf_ferm_op_zero();
     QkComplex64 coeff = {1e-8, 0.0};
@@ -288,6 +406,38 @@ static int test_adjoint(void) {
     return Ok;
 }
 
+static int test_conjugate(void) {
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool actions[2] = {true, false};
+    uint32_t indices[2] = {0, 1};
+    QkComplex64 coeff = {1.0, 2.0};
+    qf_ferm_op_add_term(op, 2, actions, indices, &coeff);
+
+    QfFermionOperator *conjugate = qf_ferm_op_conjugate(op);
+
+    QfFermionOperator *expected = qf_ferm_op_zero();
+    QkComplex64 coeff_conj = {1.0, -2.0};
+    qf_ferm_op_add_term(expected, 2, actions, indices, &coeff_conj);
+    bool is_equal = qf_ferm_op_equal(conjugate, expected);
+
+    QfFermionOperator *twice = qf_ferm_op_conjugate(conjugate);
+    bool is_involution = qf_ferm_op_equal(twice, op);
+
+    QfFermionOperator *adjoint = qf_ferm_op_adjoint(op);
+    bool differs = !qf_ferm_op_equal(adjoint, conjugate);
+
+    qf_ferm_op_free(op);
+    qf_ferm_op_free(conjugate);
+    qf_ferm_op_free(expected);
+    qf_ferm_op_free(twice);
+    qf_ferm_op_free(adjoint);
+
+    if (!is_equal || !is_involution || !differs) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 static int test_normal_ordered(void) {
     QfFermionOperator *op = qf_ferm_op_zero();
     bool action[4] = {false, true, false, true};
@@ -342,6 +492,55 @@ static int test_is_hermitian(void) {
     return Ok;
 }
 
+static int test_is_real(void) {
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool action[2] = {true, false};
+    uint32_t indices[2] = {0, 1};
+    QkComplex64 coeff1 = {1.0, 0.0};
+    qf_ferm_op_add_term(op, 2, action, indices, &coeff1);
+
+    bool is_real = qf_ferm_op_is_real(op, 0.0);
+
+    QkComplex64 coeff2 = {0.5, 1e-6};
+    qf_ferm_op_add_term(op, 2, action, indices, &coeff2);
+
+    bool is_approx_real = qf_ferm_op_is_real(op, 1e-4);
+    bool is_not_real = qf_ferm_op_is_real(op, 1e-8);
+
+    bool correct = is_real && is_approx_real && !is_not_real;
+
+    qf_ferm_op_free(op);
+
+    if (!correct) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
+static int test_is_constant_and_is_identity(void) {
+    QfFermionOperator *one = qf_ferm_op_one();
+    QkComplex64 coeff = {2.0, 0.0};
+    QfFermionOperator *two = qf_ferm_op_mul(one, &coeff);
+
+    QfFermionOperator *op = qf_ferm_op_zero();
+    bool actions[2] = {true, false};
+    uint32_t indices[2] = {0, 1};
+    qf_ferm_op_add_term(op, 2, actions, indices, &coeff);
+
+    bool correct = qf_ferm_op_is_constant(one, 1e-8) && qf_ferm_op_is_identity(one, 1e-8) &&
+                   qf_ferm_op_is_constant(two, 1e-8) && !qf_ferm_op_is_identity(two, 1e-8) &&
+                   !qf_ferm_op_is_constant(op, 1e-8) && !qf_ferm_op_is_identity(op, 1e-8);
+
+    qf_ferm_op_free(one);
+    qf_ferm_op_free(two);
+    qf_ferm_op_free(op);
+
+    if (!correct) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 static int test_many_body_order(void) {
     QfFermionOperator *op = qf_ferm_op_zero();
     bool action[4] = {true, false, true, false};
@@ -407,24 +606,100 @@ static int test_len(void) {
     return Ok;
 }
 
+static int test_data(void) {
+    QkComplex64 coeffs[3] = {{1.0, 0.0}, {-1.0, 0.0}, {0.0, -1.0}};
+    bool actions[4] = {true, false, true, false};
+    uint32_t indices[4] = {0, 1, 2, 3};
+    uint32_t boundaries[4] = {0, 0, 2, 4};
+    QfFermionOperator *op = qf_ferm_op_new(3, 4, coeffs, actions, indices, boundaries);
+
+    uint64_t num_terms = qf_ferm_op_num_terms(op);
+    uint64_t num_actions = qf_ferm_op_num_actions(op);
+    if (num_terms != 3 || num_actions != 4) {
+        qf_ferm_op_free(op);
+        return EqualityError;
+    }
+
+    QkComplex64 out_coeffs[3];
+    bool out_actions[4];
+    uint32_t out_indices[4];
+    uint32_t out_boundaries[4];
+    QfExitCode exit = qf_ferm_op_data(op, out_coeffs, out_actions, out_indices, out_boundaries);
+
+    qf_ferm_op_free(op);
+
+    if (exit != QfExitCode_Success) {
+        return EqualityError;
+    }
+    for (uint64_t i = 0; i < num_terms; i++) {
+        if (out_coeffs[i].re != coeffs[i].re || out_coeffs[i].im != coeffs[i].im) {
+            return EqualityError;
+        }
+    }
+    for (uint64_t i = 0; i < num_actions; i++) {
+        if (out_actions[i] != actions[i] || out_indices[i] != indices[i]) {
+            return EqualityError;
+        }
+    }
+    for (uint64_t i = 0; i < num_terms + 1; i++) {
+        if (out_boundaries[i] != boundaries[i]) {
+            return EqualityError;
+        }
+    }
+    return Ok;
+}
+
+static int test_str(void) {
+    QfFermionOperator *op = qf_ferm_op_one();
+
+    char *string = qf_ferm_op_str(op);
+    bool correct = strcmp(string, "  1.000000e0 +0.000000e0j * ()") == 0;
+    qf_ferm_op_str_free(string);
+
+    bool action[2] = {true, false};
+    uint32_t indices[2] = {0, 1};
+    QkComplex64 coeff = {0.0, -0.5};
+    qf_ferm_op_add_term(op, 2, action, indices, &coeff);
+    string = qf_ferm_op_str(op);
+    correct &= strcmp(string, "  1.000000e0 +0.000000e0j * ()\n"
+                              "  0.000000e0-5.000000e-1j * (+_0 -_1)") == 0;
+    qf_ferm_op_str_free(string);
+
+    qf_ferm_op_free(op);
+
+    if (!correct) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 int test_fermion_operator(void) {
     int num_failed = 0;
     num_failed += RUN_TEST(test_new);
     num_failed += RUN_TEST(test_add);
     num_failed += RUN_TEST(test_add_term);
+    num_failed += RUN_TEST(test_add_constant);
     num_failed += RUN_TEST(test_equiv_pos);
     num_failed += RUN_TEST(test_equiv_neg);
+    num_failed += RUN_TEST(test_equiv_up_to_order);
     num_failed += RUN_TEST(test_mul);
     num_failed += RUN_TEST(test_compose);
+    num_failed += RUN_TEST(test_pow);
+    num_failed += RUN_TEST(test_pow_too_large);
     num_failed += RUN_TEST(test_ichop);
     num_failed += RUN_TEST(test_simplify);
     num_failed += RUN_TEST(test_simplify_vs_ichop);
     num_failed += RUN_TEST(test_adjoint);
+    num_failed += RUN_TEST(test_conjugate);
     num_failed += RUN_TEST(test_normal_ordered);
     num_failed += RUN_TEST(test_is_hermitian);
+    num_failed += RUN_TEST(test_is_real);
+    num_failed += RUN_TEST(test_is_constant_and_is_identity);
     num_failed += RUN_TEST(test_many_body_order);
     num_failed += RUN_TEST(test_conserves_particle_number);
     num_failed += RUN_TEST(test_len);
+    num_failed += RUN_TEST(test_data);
+    num_failed += RUN_TEST(test_str);
 
     fflush(stderr);
     fprintf(stderr, "=== Number of failed subtests: %i\n", num_failed);
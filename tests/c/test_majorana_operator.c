@@ -15,6 +15,7 @@
 #include <stdint.h>
 #include <stdio.h>
 #include <stdnoreturn.h>
+#include <string.h>
 
 static int test_new(void) {
     uint64_t num_terms = 3;
@@ -178,6 +179,66 @@ static int test_compose(void) {
     return Ok;
 }
 
+static int test_pow(void) {
+    QfMajoranaOperator *op = qf_maj_op_zero();
+    uint32_t mode[1] = {0};
+    QkComplex64 coeff = {2.0, 0.0};
+    qf_maj_op_add_term(op, 1, mode, &coeff);
+
+    // exponent=0
+    QfMajoranaOperator *result0 = qf_maj_op_pow(op, 0);
+    QfMajoranaOperator *one = qf_maj_op_one();
+    bool is_equal0 = qf_maj_op_equal(result0, one);
+
+    // exponent=1
+    QfMajoranaOperator *result1 = qf_maj_op_pow(op, 1);
+    bool is_equal1 = qf_maj_op_equal(result1, op);
+
+    // exponent=2
+    QfMajoranaOperator *result2 = qf_maj_op_pow(op, 2);
+    QfMajoranaOperator *expected = qf_maj_op_zero();
+    uint32_t modes_exp[2] = {0, 0};
+    QkComplex64 coeff_exp = {4.0, 0.0};
+    qf_maj_op_add_term(expected, 2, modes_exp, &coeff_exp);
+    bool is_equal2 = qf_maj_op_equal(result2, expected);
+
+    qf_maj_op_free(op);
+    qf_maj_op_free(result0);
+    qf_maj_op_free(one);
+    qf_maj_op_free(result1);
+    qf_maj_op_free(result2);
+    qf_maj_op_free(expected);
+
+    if (!is_equal0 || !is_equal1 || !is_equal2) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
+static int test_pow_too_large(void) {
+    QfMajoranaOperator *op = qf_maj_op_zero();
+    uint32_t mode[1] = {0};
+    QkComplex64 coeff = {1.0, 0.0};
+    qf_maj_op_add_term(op, 1, mode, &coeff);
+    mode[0] = 1;
+    qf_maj_op_add_term(op, 1, mode, &coeff);
+
+    // 2^10 terms are fine, but 2^100 terms are rejected
+    QfMajoranaOperator *result10 = qf_maj_op_pow(op, 10);
+    QfMajoranaOperator *result100 = qf_maj_op_pow(op, 100);
+    bool is_null10 = result10 == NULL;
+    bool is_null100 = result100 == NULL;
+
+    qf_maj_op_free(op);
+    qf_maj_op_free(result10);
+    qf_maj_op_free(result100);
+
+    if (is_null10 || !is_null100) {
+        return NullptrError;
+    }
+    return Ok;
+}
+
 static int test_ichop(void) {
     QfMajoranaOperator *op = qf_maj_op_zero();
     QkComplex64 coeff = {1e-8, 0.0};
@@ -309,6 +370,60 @@ static int test_normal_ordered(void) {
     return Ok;
 }
 
+// Normal orders the single term ``modes`` with unit coefficient and compares the result to the
+// single term ``expected_modes`` with coefficient ``expected_sign``.
+static int check_normal_ordered(uint32_t num_modes, uint32_t *modes, bool reduce,
+                                uint32_t num_expected_modes, uint32_t *expected_modes,
+                                double expected_sign) {
+    QfMajoranaOperator *op = qf_maj_op_zero();
+    QkComplex64 coeff = {1.0, 0.0};
+    qf_maj_op_add_term(op, num_modes, modes, &coeff);
+
+    QfMajoranaOperator *normal_ordered = qf_maj_op_normal_ordered(op, reduce);
+
+    QfMajoranaOperator *expected = qf_maj_op_zero();
+    QkComplex64 expected_coeff = {expected_sign, 0.0};
+    qf_maj_op_add_term(expected, num_expected_modes, expected_modes, &expected_coeff);
+
+    bool is_equal = qf_maj_op_equal(normal_ordered, expected);
+
+    qf_maj_op_free(op);
+    qf_maj_op_free(normal_ordered);
+    qf_maj_op_free(expected);
+
+    if (!is_equal) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
+static int test_normal_ordered_reduce(void) {
+    // the sorting permutation of {0, 2, 1, 3} is odd and there are no pairs to reduce
+    uint32_t modes1[4] = {0, 2, 1, 3};
+    uint32_t sorted1[4] = {3, 2, 1, 0};
+    if (check_normal_ordered(4, modes1, false, 4, sorted1, -1.0) != Ok ||
+        check_normal_ordered(4, modes1, true, 4, sorted1, -1.0) != Ok) {
+        return EqualityError;
+    }
+
+    // the sorting permutation of {0, 0, 1} is even and the pair of 0 reduces to the identity
+    uint32_t modes2[3] = {0, 0, 1};
+    uint32_t sorted2[3] = {1, 0, 0};
+    uint32_t reduced2[1] = {1};
+    if (check_normal_ordered(3, modes2, false, 3, sorted2, 1.0) != Ok ||
+        check_normal_ordered(3, modes2, true, 1, reduced2, 1.0) != Ok) {
+        return EqualityError;
+    }
+
+    // the sorting permutation of {0, 1, 0} is odd, which also holds after the reduction
+    uint32_t modes3[3] = {0, 1, 0};
+    if (check_normal_ordered(3, modes3, false, 3, sorted2, -1.0) != Ok ||
+        check_normal_ordered(3, modes3, true, 1, reduced2, -1.0) != Ok) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 static int test_is_hermitian(void) {
     QfMajoranaOperator *op = qf_maj_op_zero();
     uint32_t modes1[4] = {0, 1, 2, 3};
@@ -393,6 +508,70 @@ static int test_len(void) {
     return Ok;
 }
 
+static int test_data(void) {
+    QkComplex64 coeffs[3] = {{1.0, 0.0}, {-1.0, 0.0}, {0.0, -1.0}};
+    uint32_t modes[4] = {0, 1, 2, 3};
+    uint32_t boundaries[4] = {0, 0, 2, 4};
+    QfMajoranaOperator *op = qf_maj_op_new(3, 4, coeffs, modes, boundaries);
+
+    uint64_t num_terms = qf_maj_op_num_terms(op);
+    uint64_t num_modes = qf_maj_op_num_modes(op);
+    if (num_terms != 3 || num_modes != 4) {
+        qf_maj_op_free(op);
+        return EqualityError;
+    }
+
+    QkComplex64 out_coeffs[3];
+    uint32_t out_modes[4];
+    uint32_t out_boundaries[4];
+    QfExitCode exit = qf_maj_op_data(op, out_coeffs, out_modes, out_boundaries);
+
+    qf_maj_op_free(op);
+
+    if (exit != QfExitCode_Success) {
+        return EqualityError;
+    }
+    for (uint64_t i = 0; i < num_terms; i++) {
+        if (out_coeffs[i].re != coeffs[i].re || out_coeffs[i].im != coeffs[i].im) {
+            return EqualityError;
+        }
+    }
+    for (uint64_t i = 0; i < num_modes; i++) {
+        if (out_modes[i] != modes[i]) {
+            return EqualityError;
+        }
+    }
+    for (uint64_t i = 0; i < num_terms + 1; i++) {
+        if (out_boundaries[i] != boundaries[i]) {
+            return EqualityError;
+        }
+    }
+    return Ok;
+}
+
+static int test_str(void) {
+    QfMajoranaOperator *op = qf_maj_op_one();
+
+    char *string = qf_maj_op_str(op);
+    bool correct = strcmp(string, "  1.000000e0 +0.000000e0j * ()") == 0;
+    qf_maj_op_str_free(string);
+
+    uint32_t modes[2] = {0, 1};
+    QkComplex64 coeff = {0.0, -0.5};
+    qf_maj_op_add_term(op, 2, modes, &coeff);
+    string = qf_maj_op_str(op);
+    correct &= strcmp(string, "  1.000000e0 +0.000000e0j * ()\n"
+                              "  0.000000e0-5.000000e-1j * (0 1)") == 0;
+    qf_maj_op_str_free(string);
+
+    qf_maj_op_free(op);
+
+    if (!correct) {
+        return EqualityError;
+    }
+    return Ok;
+}
+
 int test_majorana_operator(void) {
     int num_failed = 0;
     num_failed += RUN_TEST(test_new);
@@ -402,15 +581,20 @@ int test_majorana_operator(void) {
     num_failed += RUN_TEST(test_equiv_neg);
     num_failed += RUN_TEST(test_mul);
     num_failed += RUN_TEST(test_compose);
+    num_failed += RUN_TEST(test_pow);
+    num_failed += RUN_TEST(test_pow_too_large);
     num_failed += RUN_TEST(test_ichop);
     num_failed += RUN_TEST(test_simplify);
     num_failed += RUN_TEST(test_simplify_vs_ichop);
     num_failed += RUN_TEST(test_adjoint);
     num_failed += RUN_TEST(test_normal_ordered);
+    num_failed += RUN_TEST(test_normal_ordered_reduce);
     num_failed += RUN_TEST(test_is_hermitian);
     num_failed += RUN_TEST(test_many_body_order);
     num_failed += RUN_TEST(test_is_even);
     num_failed += RUN_TEST(test_len);
+    num_failed += RUN_TEST(test_data);
+    num_failed += RUN_TEST(test_str);
 
     fflush(stderr);
     fprintf(stderr, "=== Number of failed subtests: %i\n", num_failed);
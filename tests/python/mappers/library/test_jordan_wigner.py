@@ -10,9 +10,21 @@
 # copyright notice, and modified files need to carry a notice indicating
 # that they have been altered from the originals.
 
+import json
+import math
+from pathlib import Path
+
+import pytest
+
 from qiskit.quantum_info import SparseObservable
-from qiskit_fermions.mappers.library import jordan_wigner
+from qiskit_fermions.mappers.library import (
+    inverse_jordan_wigner,
+    inverse_jordan_wigner_checked,
+    jordan_wigner,
+    jordan_wigner_to_json,
+)
 from qiskit_fermions.operators import FermionOperator
+from qiskit_fermions.operators.library import FCIDump
 
 
 def test_jordan_wigner():
@@ -59,3 +71,87 @@ def test_jordan_wigner():
     )
     diff = (qop - expected).simplify()
     assert diff == SparseObservable.zero(num_qubits)
+
+
+def test_jordan_wigner_to_json():
+    file_path = Path(__file__).parent / "../../../h2.fcidump"
+    fcidump = FCIDump.from_file(str(file_path))
+    op = FermionOperator.from_fcidump(fcidump)
+    num_qubits = 2 * fcidump.norb
+
+    data = json.loads(jordan_wigner_to_json(op, num_qubits))
+
+    assert data["version"] == 1
+    assert data["num_qubits"] == num_qubits
+    assert len(data["terms"]) == 15
+    assert all(len(term["paulis"]) == num_qubits for term in data["terms"])
+
+    identity = [term["coeff"] for term in data["terms"] if term["paulis"] == "IIII"]
+    assert len(identity) == 1
+    re, im = identity[0]
+    assert math.isclose(re, 0.71996899444897966 - 0.8105479805373266, abs_tol=1e-10)
+    assert math.isclose(im, 0.0, abs_tol=1e-10)
+
+
+def test_jordan_wigner_too_few_qubits(subtests):
+    op = FermionOperator.from_dict({((True, 2), (False, 0)): 1.0})
+
+    with subtests.test("jordan_wigner"), pytest.raises(ValueError):
+        jordan_wigner(op, 2)
+
+    with subtests.test("jordan_wigner_to_json"), pytest.raises(ValueError):
+        jordan_wigner_to_json(op, 2)
+
+
+def test_jordan_wigner_to_json_invalid_coeffs(subtests):
+    for coeff in [math.nan, math.inf, complex(0.0, -math.inf)]:
+        with subtests.test(coeff=coeff):
+            op = FermionOperator.from_dict({(): 1.0, ((True, 0), (False, 0)): coeff})
+            with pytest.raises(ValueError):
+                jordan_wigner_to_json(op, 2)
+
+    with subtests.test("overflow"):
+        op = FermionOperator.from_dict({(): 1.5e308, ((True, 0), (False, 0)): 1.5e308})
+        with pytest.raises(ValueError):
+            jordan_wigner_to_json(op, 2)
+
+
+def test_jordan_wigner_progress(subtests):
+    file_path = Path(__file__).parent / "../../../h2.fcidump"
+    fcidump = FCIDump.from_file(str(file_path))
+    op = FermionOperator.from_fcidump(fcidump)
+    num_qubits = 2 * fcidump.norb
+
+    with subtests.test("invocations"):
+        calls = []
+        qop = jordan_wigner(
+            op, num_qubits, progress=lambda *args: calls.append(args), progress_interval=10
+        )
+        assert calls == [(10, 41), (20, 41), (30, 41), (40, 41), (41, 41)]
+        diff = (qop - jordan_wigner(op, num_qubits)).simplify()
+        assert diff == SparseObservable.zero(num_qubits)
+
+    with subtests.test("exception"):
+
+        def progress(done, total):
+            raise RuntimeError("abort")
+
+        with pytest.raises(RuntimeError):
+            jordan_wigner(op, num_qubits, progress=progress)
+
+
+def test_inverse_jordan_wigner():
+    file_path = Path(__file__).parent / "../../../h2.fcidump"
+    fcidump = FCIDump.from_file(str(file_path))
+    op = FermionOperator.from_fcidump(fcidump)
+    num_qubits = 2 * fcidump.norb
+
+    qop = jordan_wigner(op, num_qubits)
+    assert inverse_jordan_wigner(qop).equiv(op.normal_ordered())
+    assert inverse_jordan_wigner_checked(qop, num_qubits).equiv(op.normal_ordered())
+
+
+def test_inverse_jordan_wigner_checked_not_in_image():
+    qop = SparseObservable.from_sparse_list([("Z", [2], 1.0)], 3)
+    with pytest.raises(ValueError):
+        inverse_jordan_wigner_checked(qop, 2)
@@ -10,6 +10,8 @@
 # copyright notice, and modified files need to carry a notice indicating
 # that they have been altered from the originals.
 
+import numpy as np
+import pytest
 from qiskit_fermions.mappers.library import fermion_to_majorana, majorana_to_fermion
 from qiskit_fermions.operators import FermionOperator, MajoranaOperator
 
@@ -28,3 +30,61 @@ def test_majorana_to_fermion():
     canon = fer_op.normal_ordered()
     expected = FermionOperator.from_dict({(): -1j, ((True, 0), (False, 0)): 2j})
     assert canon.equiv(expected)
+
+
+def test_to_majorana_num_threads():
+    fer_op = FermionOperator.from_dict(
+        {
+            (): 0.5,
+            ((True, 0), (False, 1)): 1.0,
+            ((True, 1), (False, 0)): 1.0,
+            ((True, 0), (True, 2), (False, 2), (False, 0)): -0.25j,
+            ((True, 3), (False, 1), (True, 1), (False, 3)): 2.0,
+        }
+    )
+    single = fer_op.to_majorana(num_threads=1)
+    multi = fer_op.to_majorana(num_threads=4)
+    assert single == multi
+    assert single == fermion_to_majorana(fer_op)
+
+
+def test_to_majorana_convention(subtests):
+    fer_op = FermionOperator.from_dict({((True, 0),): 1})
+    norm = 1 / np.sqrt(2)
+    for convention, expected in [
+        ("default", {(0,): 0.5, (1,): -0.5j}),
+        ("flipped", {(0,): 0.5, (1,): 0.5j}),
+        ("normalized", {(0,): norm, (1,): -1j * norm}),
+    ]:
+        with subtests.test(convention):
+            maj_op = fer_op.to_majorana(convention=convention)
+            assert maj_op.equiv(MajoranaOperator.from_dict(expected))
+
+    with subtests.test("invalid"):
+        with pytest.raises(ValueError):
+            fer_op.to_majorana(convention="unknown")
+
+
+def test_to_fermion(subtests):
+    maj_op = MajoranaOperator.from_dict({(0, 3): 1.0, (2, 1): 1.0, (1,): 0.5, (): 0.25})
+    manual = majorana_to_fermion(maj_op).normal_ordered().simplify(1e-8)
+
+    with subtests.test("normal ordered"):
+        assert maj_op.to_fermion().equiv(manual)
+
+    with subtests.test("raw"):
+        assert maj_op.to_fermion(normal_order=False) == majorana_to_fermion(maj_op)
+
+
+def test_to_sparse_matrix():
+    fer_op = FermionOperator.from_dict(
+        {
+            ((True, 0), (False, 1)): 1.0,
+            ((True, 1), (False, 0)): 1.0,
+            ((True, 2), (True, 0), (False, 1)): 0.5j,
+            ((False, 2),): -0.25,
+        }
+    )
+    dense = fermion_to_majorana(fer_op).to_sparse_matrix(3).toarray()
+    expected = fer_op.to_sparse_matrix(3).toarray()
+    np.testing.assert_allclose(dense, expected, atol=1e-12)
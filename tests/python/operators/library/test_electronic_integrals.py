@@ -10,8 +10,13 @@
 # copyright notice, and modified files need to carry a notice indicating
 # that they have been altered from the originals.
 
+from pathlib import Path
+
 import numpy as np
-from qiskit_fermions.operators import FermionOperator
+import pytest
+from qiskit_fermions.mappers.library import fermion_to_majorana
+from qiskit_fermions.operators import FermionOperator, MajoranaOperator
+from qiskit_fermions.operators.library import FCIDump
 
 
 def test_from_1body_tril_spin_sym():
@@ -203,3 +208,82 @@ def test_from_2body_tril_spin():
         }
     )
     assert op.equiv(expected)
+
+
+def test_from_2body_tril_spin_drop_vanishing():
+    norb = 2
+    two_body_aa = np.arange(1, 7, dtype=float)
+    two_body_ab = np.arange(11, 20, dtype=float)
+    two_body_bb = np.arange(-1, -7, -1, dtype=float)
+    full = FermionOperator.from_2body_tril_spin(two_body_aa, two_body_ab, two_body_bb, norb)
+    op = FermionOperator.from_2body_tril_spin(
+        two_body_aa, two_body_ab, two_body_bb, norb, drop_vanishing=True
+    )
+    assert len(op) < len(full)
+    for actions, _ in op.iter_terms():
+        assert actions[0][1] != actions[1][1]
+        assert actions[2][1] != actions[3][1]
+    assert op == full.drop_vanishing_terms()
+    assert op.normal_ordered().equiv(full.normal_ordered())
+
+
+def test_reorder_spin():
+    file_path = Path(__file__).parent / "../../../h2.fcidump"
+    fcidump = FCIDump.from_file(str(file_path))
+    block = FermionOperator.from_fcidump(fcidump)
+    interleaved = block.reorder_spin(fcidump.norb, "block", "interleaved")
+    assert not interleaved.equiv(block)
+    assert interleaved.reorder_spin(fcidump.norb, "interleaved", "block") == block
+
+
+def test_reorder_spin_invalid():
+    op = FermionOperator.from_dict({((True, 4), (False, 0)): 1.0})
+    with pytest.raises(ValueError):
+        op.reorder_spin(2, "block", "interleaved")
+    with pytest.raises(ValueError):
+        op.reorder_spin(2, "block", "block")
+    with pytest.raises(ValueError):
+        op.reorder_spin(3, "block", "alternating")
+
+
+def test_combine_spin_blocks():
+    alpha = FermionOperator.from_dict({((True, 0), (False, 0)): 1.0})
+    beta = FermionOperator.from_dict({((True, 0), (False, 0)): 2.0})
+    assert beta.to_beta_block(1) == FermionOperator.from_dict({((True, 1), (False, 1)): 2.0})
+    combined = FermionOperator.combine_spin_blocks(alpha, beta, 1)
+    expected = FermionOperator.from_dict(
+        {((True, 0), (False, 0)): 1.0, ((True, 1), (False, 1)): 2.0}
+    )
+    assert combined.equiv(expected)
+    assert combined.conserves_spin_numbers(1)
+    with pytest.raises(ValueError):
+        FermionOperator.combine_spin_blocks(alpha, expected, 1)
+
+
+def test_from_2body_tril_spin_sym_validate():
+    norb = 2
+    two_body_aa = np.arange(1, 7, dtype=float)
+    op = FermionOperator.from_2body_tril_spin_sym(two_body_aa, norb, validate=True)
+    assert op == FermionOperator.from_2body_tril_spin_sym(two_body_aa, norb)
+
+    # S4-fold symmetric integrals, in which (ia|jb) and (jb|ia) differ
+    asymmetric = np.arange(11, 20, dtype=float)
+    with pytest.raises(ValueError, match="S8-fold symmetric"):
+        FermionOperator.from_2body_tril_spin_sym(asymmetric, norb, validate=True)
+    with pytest.raises(ValueError, match="S8-fold symmetric"):
+        MajoranaOperator.from_2body_tril_spin_sym(asymmetric, norb, validate=True)
+
+
+def test_majorana_from_tril_spin_sym():
+    norb = 2
+    one_body_a = np.arange(1, 4, dtype=float)
+    op = MajoranaOperator.from_1body_tril_spin_sym(one_body_a, norb)
+    expected = fermion_to_majorana(FermionOperator.from_1body_tril_spin_sym(one_body_a, norb))
+    assert op.equiv(expected.normal_ordered())
+    assert len(op) < len(expected)
+
+    two_body_aa = np.arange(1, 7, dtype=float)
+    op = MajoranaOperator.from_2body_tril_spin_sym(two_body_aa, norb)
+    expected = fermion_to_majorana(FermionOperator.from_2body_tril_spin_sym(two_body_aa, norb))
+    assert op.equiv(expected.normal_ordered())
+    assert len(op) < len(expected)
@@ -12,6 +12,7 @@
 
 from pathlib import Path
 
+import pytest
 from qiskit_fermions.operators import FermionOperator, ann, cre
 from qiskit_fermions.operators.library import FCIDump
 
@@ -23,6 +24,8 @@ class TestFCIDump:
         assert fcidump.norb == 2
         assert fcidump.nelec == 2
         assert fcidump.ms2 == 0
+        assert fcidump.num_spatial_orbitals == 2
+        assert fcidump.num_spin_orbitals == 4
         op = FermionOperator.from_fcidump(fcidump)
         expected = FermionOperator.from_dict(
             {
@@ -152,3 +155,19 @@ class TestFCIDump:
             }
         )
         assert op.equiv(expected)
+
+    def test_from_fcidump_path(self):
+        file_path = Path(__file__).parent / "../../../h2.fcidump"
+        op = FermionOperator.from_fcidump(str(file_path))
+        assert op.is_hermitian()
+        assert len(op) == 41
+        assert op.equiv(FermionOperator.from_fcidump(file_path))
+        assert op.equiv(FermionOperator.from_fcidump(FCIDump.from_file(str(file_path))))
+
+    def test_from_fcidump_bad_path(self):
+        with pytest.raises(FileNotFoundError):
+            FermionOperator.from_fcidump("does_not_exist.fcidump")
+
+    def test_from_fcidump_bad_type(self):
+        with pytest.raises(TypeError):
+            FermionOperator.from_fcidump(42)
@@ -10,10 +10,22 @@
 # copyright notice, and modified files need to carry a notice indicating
 # that they have been altered from the originals.
 
+import copy
+import sys
 from abc import ABC, abstractmethod
+from pathlib import Path
 
-from qiskit_fermions.operators import FermionOperator, ann, cre
-from qiskit_fermions.operators.library import anti_commutator, commutator
+import numpy as np
+import pytest
+
+from qiskit_fermions.operators import (
+    FermionOperator,
+    FermionOperatorF32,
+    ann,
+    assert_operators_equiv,
+    cre,
+)
+from qiskit_fermions.operators.library import FCIDump, anti_commutator, commutator
 
 
 class FermionOperatorTests(ABC):
@@ -21,6 +33,52 @@ class FermionOperatorTests(ABC):
     @abstractmethod
     def get_class() -> type[FermionOperator]: ...
 
+    def test_to_str(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 3, (cre(0), ann(1)): 0.5 - 2j})
+
+        with subtests.test("default"):
+            assert op.to_str() == str(op)
+            assert f"{op}" == str(op)
+
+        with subtests.test("fixed"):
+            expected = "    3.00   +0.00j * ()\n    0.50   -2.00j * (+_0 -_1)"
+            assert op.to_str(precision=2, style="fixed") == expected
+            assert f"{op:.2f}" == expected
+
+        with subtests.test("scientific"):
+            assert f"{op:.1e}" == op.to_str(precision=1, style="sci")
+
+        with subtests.test("invalid"):
+            with pytest.raises(ValueError):
+                op.to_str(style="engineering")
+            with pytest.raises(ValueError):
+                f"{op:>10}"
+
+    def test_to_latex(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("zero"):
+            assert cls.zero().to_latex() == "0"
+
+        with subtests.test("one"):
+            assert cls.one().to_latex() == "1"
+
+        with subtests.test("two-body"):
+            op = cls.from_dict({(cre(0), cre(1), ann(1), ann(0)): 0.5})
+            assert op.to_latex() == r"0.5\, a^\dagger_0 a^\dagger_1 a_1 a_0"
+
+        with subtests.test("max_terms"):
+            op = cls.from_dict({(): -1.0, (cre(0), ann(1)): 0.5, (ann(2),): 2.0j})
+            assert op.to_latex() == r"-1 + 2i\, a_2 + 0.5\, a^\dagger_0 a_1"
+            assert op.to_latex(max_terms=2) == r"-1 + 2i\, a_2 + \cdots"
+            assert op.to_latex(max_terms=3) == op.to_latex()
+            assert op.to_latex(max_terms=0) == r"\cdots"
+
+        with subtests.test("negative imaginary"):
+            op = cls.from_dict({(ann(0),): 1.0, (cre(1),): -3.0j})
+            assert op.to_latex() == r"1\, a_0 - 3i\, a^\dagger_1"
+
     def test_zero(self):
         cls = self.get_class()
         op = cls.zero()
@@ -31,6 +89,47 @@ class FermionOperatorTests(ABC):
         op = cls.one()
         assert op == cls.from_dict({(): 1})
 
+    def test_copy(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, (cre(0), ann(1)): 2.0})
+
+        for name, copier in [("copy", copy.copy), ("deepcopy", copy.deepcopy)]:
+            with subtests.test(name):
+                other = copier(op)
+                assert other == op
+                assert other is not op
+                other *= 3.0
+                other += cls.from_dict({(cre(1),): 1.0})
+                assert op.equiv(cls.from_dict({(): 1.0, (cre(0), ann(1)): 2.0}))
+
+    def test_from_dict_invalid_index(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("negative"), pytest.raises(ValueError) as exc:
+            cls.from_dict({((True, -1),): 1.0})
+        assert str(exc.value) == "mode index -1 out of range in key ((True, -1),)"
+
+        with subtests.test("overflow"), pytest.raises(ValueError) as exc:
+            cls.from_dict({(cre(0), (False, 2**32)): 1.0})
+        assert str(exc.value) == (
+            "mode index 4294967296 out of range in key ((True, 0), (False, 4294967296))"
+        )
+
+        with subtests.test("non-integer"), pytest.raises(TypeError):
+            cls.from_dict({((True, "a"),): 1.0})
+
+    def test_from_label_dict(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("valid"):
+            op = cls.from_label_dict({"": 2, "0^ 1": 1, "2 3^": -0.5j})
+            expected = cls.from_dict({(): 2, (cre(0), ann(1)): 1, (ann(2), cre(3)): -0.5j})
+            assert op.equiv(expected)
+
+        for label in ["0^^", "a", "-1", "^"]:
+            with subtests.test(f"invalid {label}"), pytest.raises(ValueError):
+                cls.from_label_dict({label: 1})
+
     def test_repr(self):
         cls = self.get_class()
         op = cls.from_dict(
@@ -57,11 +156,141 @@ class FermionOperatorTests(ABC):
             op = cls.from_dict({(): 1, (cre(0), ann(1)): 1})
             assert len(op) == 2
 
+    def test_num_actions(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1, (cre(0), ann(1)): 1, (cre(0), cre(1), ann(1), ann(0)): 1})
+        assert op.num_actions() == sum(len(term) for term, _ in op.iter_terms())
+        assert op.num_actions() == 6
+        assert op.average_term_length() == 2.0
+        assert cls.zero().num_actions() == 0
+        assert cls.zero().average_term_length() == 0.0
+
     def test_iter(self):
         cls = self.get_class()
         op = cls.one()
         assert list(op.iter_terms()) == [([], 1)]
 
+    def test_iter_nonzero(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, (cre(0),): 1e-10, (ann(1),): -2.0j, (cre(2),): 0.0})
+        assert sorted(op.iter_terms(atol=1e-8)) == [([], 1.0), ([(False, 1)], -2.0j)]
+        assert len(list(op.iter_terms(atol=0.0))) == 3
+        assert len(list(op.iter_terms())) == 4
+
+    def test_iter_sorted(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict(
+            {(): 0.5, (cre(0),): -3.0, (ann(1),): 2.0j, (cre(2), ann(0)): 1.0, (cre(1),): 0.1}
+        )
+
+        with subtests.test("magnitude"):
+            magnitudes = [abs(coeff) for _, coeff in op.iter_terms(sort="magnitude")]
+            assert magnitudes == sorted(magnitudes, reverse=True)
+            assert len(magnitudes) == 5
+
+        with subtests.test("magnitude with atol"):
+            terms = list(op.iter_terms(atol=0.75, sort="magnitude"))
+            assert [coeff for _, coeff in terms] == [-3.0, 2.0j, 1.0]
+
+        with subtests.test("term"):
+            terms = [term for term, _ in op.iter_terms(sort="term")]
+            assert terms == sorted(terms)
+
+        with subtests.test("invalid"):
+            with pytest.raises(ValueError):
+                op.iter_terms(sort="unknown")
+
+    def test_iter_with_parity(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("descending"):
+            op = cls.from_dict({(cre(1), cre(0)): 1})
+            assert list(op.iter_terms_with_parity()) == [([(True, 1), (True, 0)], 1, 1)]
+
+        with subtests.test("ascending"):
+            op = cls.from_dict({(cre(0), cre(1)): 1})
+            assert list(op.iter_terms_with_parity()) == [([(True, 0), (True, 1)], 1, -1)]
+
+        with subtests.test("mixed"):
+            op = cls.from_dict({(ann(0), cre(1)): 1, (cre(2), ann(0), ann(1)): 1})
+            assert [parity for _, _, parity in op.iter_terms_with_parity()] == [-1, -1]
+
+    def test_astype(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict(
+            {(cre(i), ann(j)): 0.1 * (i + 1) - 0.3j * j for i in range(10) for j in range(10)}
+        )
+
+        with subtests.test("round-trip"):
+            op32 = op.astype("float32")
+            assert isinstance(op32, FermionOperatorF32)
+            assert len(op32) == len(op)
+            round_trip = op32.astype("float64")
+            assert isinstance(round_trip, FermionOperator)
+            eps = np.finfo(np.float32).eps
+            for (term, coeff), (term_rt, coeff_rt) in zip(
+                op.iter_terms(), round_trip.iter_terms()
+            ):
+                assert term == term_rt
+                assert abs(coeff - coeff_rt) <= eps * abs(coeff)
+
+        with subtests.test("memory"):
+            assert sys.getsizeof(op.astype("float32")) < sys.getsizeof(op)
+
+        with subtests.test("float64"):
+            assert op.astype("float64").equiv(op)
+
+        with subtests.test("invalid"):
+            with pytest.raises(ValueError):
+                op.astype("float16")
+
+    def test_extend_terms(self, subtests):
+        cls = self.get_class()
+        rng = np.random.default_rng(0)
+        num_terms = 1000
+        coeffs = rng.random(num_terms) + 1j * rng.random(num_terms)
+        term_lengths = rng.integers(0, 5, num_terms, dtype=np.uint64)
+        num_actions = int(term_lengths.sum())
+        actions = rng.random(num_actions) < 0.5
+        indices = rng.integers(0, 8, num_actions, dtype=np.uint32)
+
+        bulk = cls.one()
+        bulk.extend_terms(coeffs, term_lengths, actions, indices)
+
+        single = cls.one()
+        offset = 0
+        for coeff, length in zip(coeffs, term_lengths):
+            end = offset + int(length)
+            term = tuple(zip(actions[offset:end].tolist(), indices[offset:end].tolist()))
+            single += cls.from_dict({term: coeff})
+            offset = end
+
+        with subtests.test("equal"):
+            assert len(bulk) == num_terms + 1
+            assert bulk == single
+
+        with subtests.test("inconsistent"):
+            with pytest.raises(ValueError):
+                bulk.extend_terms(coeffs, term_lengths, actions[:-1], indices)
+
+    def test_sorted(self, subtests):
+        cls = self.get_class()
+        op1 = cls.from_dict({(): 1.0, (cre(1), ann(0)): 2.0, (cre(0),): -1.0j})
+        op2 = cls.from_dict({(cre(0),): -1.0j, (): 1.0, (cre(1), ann(0)): 2.0})
+        assert not op1 == op2
+
+        with subtests.test("sorted"):
+            assert op1.sorted() == op2.sorted()
+            assert list(op1.sorted().iter_terms()) == list(op2.sorted().iter_terms())
+
+        with subtests.test("duplicates"):
+            op = cls.from_dict({(cre(0),): 2.0}) + cls.from_dict({(cre(0),): 1.0})
+            assert list(op.sorted().iter_terms()) == [([(True, 0)], 1.0), ([(True, 0)], 2.0)]
+
+        with subtests.test("in-place"):
+            op1.sort_terms()
+            assert op1 == op2.sorted()
+
     def test_ichop(self):
         cls = self.get_class()
         op = cls.from_dict({(): 1e-4, ((True, 0),): 1e-6, ((False, 0),): 1e-10})
@@ -80,6 +309,32 @@ class FermionOperatorTests(ABC):
         canon = op.simplify()
         assert canon.equiv(cls.from_dict({((True, 0),): 5}), 1e-12)
 
+    def test_simplify_sorted(self, subtests):
+        cls = self.get_class()
+        coeffs = [1e-10, 2, 3, 4, -4, 1j]
+        actions = [True, True, False, False, True]
+        indices = [0, 0, 1, 1, 0]
+        boundaries = [0, 0, 1, 2, 3, 4, 5]
+        op = cls(coeffs, actions, indices, boundaries)
+
+        with subtests.test("equivalent to hashmap"):
+            hashed = op.simplify(method="hashmap")
+            ordered = op.simplify(method="sorted")
+            assert ordered.equiv(hashed, 1e-12)
+            assert [term for term, _ in ordered.iter_terms()] == [
+                term for term, _ in hashed.iter_terms()
+            ]
+
+        with subtests.test("deterministic order"):
+            other = cls.from_dict({((False, 1),): 1.0, ((True, 2),): 1.0})
+            forward = (op + other).simplify(method="sorted")
+            backward = (other + op).simplify(method="sorted")
+            assert list(forward.iter_terms()) == list(backward.iter_terms())
+
+        with subtests.test("invalid method"):
+            with pytest.raises(ValueError):
+                op.simplify(method="unknown")
+
     def test_simplify_vs_ichop(self):
         cls = self.get_class()
         coeffs = [1e-5] * int(1e5)
@@ -92,6 +347,57 @@ class FermionOperatorTests(ABC):
         op.ichop(1e-4)
         assert op.equiv(op.zero(), 1e-6)
 
+    def test_invalid_coeffs(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, ((True, 0),): float("nan")})
+        assert op.has_invalid_coeffs()
+        assert not cls.one().has_invalid_coeffs()
+
+        with subtests.test("simplify"):
+            with pytest.raises(ValueError):
+                op.simplify(strict=True)
+            assert op.simplify().equiv(cls.one())
+
+        with subtests.test("ichop"):
+            copy = op.purge_nan_inf() + op
+            with pytest.raises(ValueError):
+                copy.ichop(strict=True)
+            assert len(copy) == 3
+            copy.ichop()
+            assert copy.equiv(2 * cls.one())
+
+        with subtests.test("purge_nan_inf"):
+            assert op.purge_nan_inf().equiv(cls.one())
+
+    def test_scaled_iadd(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, ((True, 0),): 2.0})
+        other = cls.from_dict({((True, 0),): 1.0, ((True, 1), (False, 2)): 1.0j})
+        expected = op + 3.0 * other
+        op.scaled_iadd(other, 3.0)
+        assert op == expected
+
+    def test_chop_relative(self, subtests):
+        cls = self.get_class()
+        op = cls([1.0, 1e-3, 1e-7], [True, False, True], [0, 1, 2], [0, 1, 2, 3])
+
+        with subtests.test("rtol=1e-4"):
+            chopped = op.chop_relative(1e-4)
+            assert len(chopped) == 2
+            assert chopped.equiv(cls.from_dict({((True, 0),): 1.0, ((False, 1),): 1e-3}), 0.0)
+
+        with subtests.test("rtol=0"):
+            assert len(op.chop_relative(0.0)) == 3
+
+    def test_term_count_vs_threshold(self):
+        cls = self.get_class()
+        op = cls([1.0, 1e-3, 1e-6, 1e-9], [True, False, True], [0, 1, 2], [0, 0, 1, 2, 3])
+        thresholds = [0.0, 1e-8, 1e-5, 1e-2, 10.0]
+        counts = op.term_count_vs_threshold(thresholds)
+        assert counts == [4, 3, 2, 1, 0]
+        assert all(a >= b for a, b in zip(counts, counts[1:]))
+        assert counts == [len(op.simplify(atol)) for atol in thresholds]
+
     def test_add(self):
         cls = self.get_class()
         one = cls.one()
@@ -171,6 +477,33 @@ class FermionOperatorTests(ABC):
             )
         )
 
+    def test_matmul(self):
+        cls = self.get_class()
+        op1 = cls.from_dict({(): 2, (cre(0),): 3})
+        op2 = cls.from_dict({(): 1.5, (ann(0),): 4})
+        product = op1.matmul(op2)
+        assert product.equiv(op2 & op1)
+        assert not product.equiv(op1 & op2)
+        assert product.equiv(
+            cls.from_dict({(): 3, (cre(0),): 4.5, (ann(0),): 8, (cre(0), ann(0)): 12})
+        )
+
+    def test_tensor(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("parity"):
+            parity = cls.parity_operator(1).tensor(cls.parity_operator(2), 1)
+            expected = cls.parity_operator(3)
+            assert parity.normal_ordered().equiv(expected.normal_ordered())
+
+        with subtests.test("number"):
+            num = cls.from_dict({(cre(0), ann(0)): 1.0})
+            expected = cls.from_dict({(cre(0), ann(0), cre(1), ann(1)): 1.0})
+            assert num.tensor(num, 1) == expected
+
+        with subtests.test("overlapping"), pytest.raises(ValueError):
+            cls.from_dict({(cre(2),): 1.0}).tensor(cls.one(), 2)
+
     def test_iand(self):
         cls = self.get_class()
         op1 = cls.from_dict({(): 2, (cre(0), ann(1)): 3})
@@ -200,6 +533,52 @@ class FermionOperatorTests(ABC):
         with subtests.test("pow==2"):
             assert (op**2).equiv(cls.from_dict({(cre(0), cre(0)): 4}))
 
+    def test_pow_large(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(cre(0),): 1, (ann(1),): 2})
+
+        with subtests.test("pow==10"):
+            naive = cls.one()
+            for _ in range(10):
+                naive = naive & op
+            result = op**10
+            assert len(result) == 2**10
+            assert result.simplify().equiv(naive.simplify())
+
+        with subtests.test("too many terms"), pytest.raises(ValueError):
+            op**1_000_000
+
+    def test_conjugate(self):
+        cls = self.get_class()
+        op = cls.from_dict({(cre(0), ann(1)): 1.0 + 2.0j})
+        conj = op.conjugate()
+        assert conj.equiv(cls.from_dict({(cre(0), ann(1)): 1.0 - 2.0j}))
+        assert conj.conjugate() == op
+        assert not conj.equiv(op.adjoint())
+
+    def test_round_coeffs(self):
+        cls = self.get_class()
+        op = cls.from_dict({(cre(0),): 1.0000001 + 0j})
+        rounded = op.round_coeffs(6)
+        assert rounded == cls.from_dict({(cre(0),): 1.0 + 0j})
+        assert op.round_coeffs(-1) == cls.from_dict({(cre(0),): 0j})
+
+    def test_scale_and_shift(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 0.5, (cre(0), ann(1)): 1.0 + 2.0j, (cre(1), ann(0)): 1.0 - 2.0j})
+        shifted = op.scale_and_shift(2, 3).simplify()
+        assert shifted.equiv((2 * op + 3 * cls.one()).simplify())
+
+    def test_conjugate_by(self):
+        cls = self.get_class()
+        number = cls.from_dict({(cre(0), ann(0)): 1.0})
+        assert number.conjugate_by(cls.one()) == number
+
+        op = cls.from_dict({(cre(0), ann(1)): 1.0 + 2.0j, (cre(1), ann(0)): 0.5})
+        rotation = cls.from_dict({(): 0.5j, (cre(0), ann(1)): 1.0, (cre(2), ann(0)): -2.0})
+        expected = rotation.adjoint().matmul(op).matmul(rotation)
+        assert op.conjugate_by(rotation).simplify().equiv(expected.simplify())
+
     def test_adjoint(self):
         cls = self.get_class()
         op = cls.from_dict({(): 2j, (cre(0), ann(1)): 3})
@@ -213,6 +592,28 @@ class FermionOperatorTests(ABC):
         assert op.equiv(zero, 1e-6)
         assert not op.equiv(zero, 1e-8)
 
+    def test_equiv_up_to_phase(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 2.0, (): 0.5j})
+
+        with subtests.test("imaginary unit"):
+            assert not op.equiv(1j * op)
+            assert op.equiv_up_to_phase(1j * op)
+            assert op.global_phase(1j * op) == pytest.approx(1j)
+
+        with subtests.test("arbitrary phase"):
+            phase = np.exp(0.3j)
+            assert op.equiv_up_to_phase(phase * op)
+            assert op.global_phase(phase * op) == pytest.approx(phase)
+
+        with subtests.test("different magnitude"):
+            assert not op.equiv_up_to_phase(2j * op)
+            assert op.global_phase(2j * op) is None
+
+        with subtests.test("different terms"):
+            other = cls.from_dict({(cre(0), ann(1)): 1.0})
+            assert not op.equiv_up_to_phase(other)
+
     def test_normal_ordered(self, subtests):
         cls = self.get_class()
 
@@ -230,6 +631,65 @@ class FermionOperatorTests(ABC):
             expected = cls.from_dict({(): 1, ((True, 0), (False, 0)): -1})
             assert op.normal_ordered().equiv(expected)
 
+        # a_0 a^+_0 a_1 a^+_1 ... normal orders into 2^10 terms
+        key = tuple(action for i in range(10) for action in (ann(i), cre(i)))
+        op = cls.from_dict({key: 1})
+
+        with subtests.test("within max_terms"):
+            ordered = op.normal_ordered(max_terms=2**10)
+            assert len(ordered) == 2**10
+            assert ordered.equiv(op.normal_ordered())
+
+        with subtests.test("exceeding max_terms"), pytest.raises(ValueError):
+            op.normal_ordered(max_terms=100)
+
+    def test_normal_ordered_with_provenance(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("single term"):
+            op = cls.from_dict({(ann(0), cre(0), ann(1), cre(1)): 1})
+            ordered, provenance = op.normal_ordered_with_provenance()
+            assert len(ordered) == 4
+            assert provenance == [0, 0, 0, 0]
+            assert ordered == op.normal_ordered()
+
+        with subtests.test("multiple terms"):
+            # +_0 -_1 and -_0 +_0, in this order
+            op = cls([2.0, 1.0], [True, False, False, True], [0, 1, 0, 0], [0, 2, 4])
+            ordered, provenance = op.normal_ordered_with_provenance()
+            assert provenance == [0, 1, 1]
+            assert ordered == op.normal_ordered()
+
+    def test_drop_vanishing_terms(self):
+        cls = self.get_class()
+        op = cls.from_dict(
+            {
+                (cre(0), cre(0), ann(1), ann(2)): 1.0,
+                (cre(0), ann(0), cre(0)): 2.0,
+                (cre(1), ann(2), ann(2)): 3.0,
+            }
+        )
+        expected = cls.from_dict({(cre(0), ann(0), cre(0)): 2.0})
+        assert op.drop_vanishing_terms() == expected
+
+    def test_hermitian_generators(self, subtests):
+        cls = self.get_class()
+        hopping = cls.from_dict({(cre(0), ann(1)): 1 + 2j, (cre(1), ann(0)): 1 - 2j})
+
+        with subtests.test("hopping"):
+            generators = hopping.hermitian_generators()
+            assert len(generators) == 1
+            assert generators[0] == cls.from_dict({(cre(0), ann(1)): 1 + 2j})
+
+        with subtests.test("reconstruction"):
+            op = hopping + cls.from_dict({(cre(0), ann(0)): 3})
+            generators = op.hermitian_generators()
+            assert len(generators) == 2
+            reconstructed = cls.zero()
+            for generator in generators:
+                reconstructed += generator + generator.adjoint()
+            assert reconstructed.normal_ordered().equiv(op.normal_ordered())
+
     def test_is_hermitian(self):
         cls = self.get_class()
 
@@ -243,6 +703,37 @@ class FermionOperatorTests(ABC):
         assert not op.is_hermitian()
         assert op.is_hermitian(1e-4)
 
+    def test_coeff_dtype(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({((True, 0), (False, 1)): 1.0, ((True, 1), (False, 0)): -0.5})
+
+        with subtests.test("real"):
+            assert op.coeff_dtype == "real"
+
+        with subtests.test("complex after in-place addition"):
+            op += cls.from_dict({(): 1e-12j})
+            assert op.coeff_dtype == "complex"
+            assert not op.is_real(0.0)
+
+        with subtests.test("real after in-place coefficient map"):
+            op.map_coeffs(lambda c: complex(c.real, 0))
+            assert op.coeff_dtype == "real"
+            assert op.is_real(0.0)
+
+    def test_is_real(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({((True, 0), (False, 1)): 1.0, ((True, 1), (False, 0)): -0.5})
+
+        with subtests.test("real"):
+            assert op.is_real()
+            assert op.is_real(0.0)
+
+        op += cls.from_dict({(): 1e-6j})
+
+        with subtests.test("perturbed"):
+            assert not op.is_real()
+            assert op.is_real(1e-4)
+
     def test_many_body_order(self, subtests):
         cls = self.get_class()
 
@@ -272,6 +763,527 @@ class FermionOperatorTests(ABC):
             op = cls.from_dict({((True, 0),): 1})
             assert not op.conserves_particle_number()
 
+    def test_conserves_spin_numbers(self, subtests):
+        cls = self.get_class()
+        norb = 2
+
+        with subtests.test("alpha hopping"):
+            op = cls.from_dict({(cre(0), ann(1)): 1})
+            assert op.conserves_spin_numbers(norb)
+
+        with subtests.test("spin flip"):
+            op = cls.from_dict({(cre(0), ann(norb)): 1})
+            assert op.conserves_particle_number()
+            assert not op.conserves_spin_numbers(norb)
+
+        with subtests.test("opposite-spin interaction"):
+            op = cls.from_dict({(cre(0), cre(norb), ann(norb), ann(0)): 1})
+            assert op.conserves_spin_numbers(norb)
+
+    def test_is_quadratic(self, subtests):
+        cls = self.get_class()
+        hopping = cls.from_dict({(cre(0), ann(1)): -1.0, (cre(1), ann(0)): -1.0, (): 0.5})
+        hubbard = cls.from_dict({(cre(0), ann(0), cre(1), ann(1)): 4.0})
+
+        with subtests.test("hopping"):
+            assert hopping.is_quadratic()
+
+        with subtests.test("Hubbard U"):
+            assert not hubbard.is_quadratic()
+            assert not (hopping + hubbard).is_quadratic()
+
+        with subtests.test("pairing"):
+            assert not cls.from_dict({(cre(0), cre(1)): 1.0}).is_quadratic()
+
+    def test_is_constant_and_is_identity(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("one"):
+            assert cls.one().is_constant()
+            assert cls.one().is_identity()
+
+        with subtests.test("2 * one"):
+            assert (2 * cls.one()).is_constant()
+            assert not (2 * cls.one()).is_identity()
+
+        with subtests.test("one-body"):
+            hopping = cls.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 1.0})
+            assert not hopping.is_constant()
+            assert not hopping.is_identity()
+
+        with subtests.test("anti-commutator"):
+            op = cls.from_dict({(ann(0), cre(0)): 1.0, (cre(0), ann(0)): 1.0})
+            assert op.is_constant()
+            assert op.is_identity()
+
+    def test_group_by_order(self, subtests):
+        cls = self.get_class()
+        fcidump = FCIDump.from_file(str(Path(__file__).parent / "../../h2.fcidump"))
+        op = cls.from_fcidump(fcidump)
+
+        groups = op.group_by_order()
+
+        with subtests.test("term counts"):
+            assert sorted(groups) == [0, 2, 4]
+            assert len(groups[0]) == 1
+            assert len(groups[2]) == 8
+            assert len(groups[4]) == 32
+
+        with subtests.test("sum"):
+            total = cls.zero()
+            for group in groups.values():
+                total += group
+            assert total.equiv(op)
+
+    def test_support_histogram(self):
+        cls = self.get_class()
+        file_path = Path(__file__).parent / "../../h2.fcidump"
+        op = cls.from_fcidump(FCIDump.from_file(str(file_path)))
+        # the constant, the 8 one-body terms of which 4 act on a single mode, and the 32 two-body
+        # terms of which 4 act on a single mode, 20 on a pair of modes and 8 on all four modes
+        assert op.support_histogram() == {0: 1, 1: 8, 2: 24, 4: 8}
+
+    def test_index_range(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("empty"):
+            assert cls.zero().index_range() is None
+            assert cls.one().index_range() is None
+
+        with subtests.test("single index"):
+            op = cls.from_dict({((True, 3), (False, 3)): 1})
+            assert op.index_range() == (3, 3)
+
+        with subtests.test("wide spread"):
+            op = cls.from_dict({((True, 7), (False, 2)): 1, ((True, 2**32 - 1), (False, 0)): 2})
+            assert op.index_range() == (0, 2**32 - 1)
+
+    def test_assert_operators_equiv(self, subtests):
+        cls = self.get_class()
+        a = cls.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 2.0})
+
+        with subtests.test("equivalent"):
+            b = cls.from_dict({(cre(1), ann(0)): 2.0, (cre(0), ann(1)): 1.0 + 1e-10})
+            assert_operators_equiv(a, b)
+
+        with subtests.test("not equivalent"):
+            b = cls.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 2.5})
+            message = r"-5\.000000e-1 \+0\.000000e0j \* \(\+_1 -_0\)"
+            with pytest.raises(AssertionError, match=message):
+                assert_operators_equiv(a, b)
+
+    def test_compactify_indices(self):
+        cls = self.get_class()
+        op = cls.from_dict({((True, 5), (False, 0)): 1.0, ((True, 2), (True, 5), (False, 0)): 2.0})
+        compact, mapping = op.compactify_indices()
+        assert mapping == [0, 2, 5]
+        assert {index for term, _ in compact.iter_terms() for _, index in term} == {0, 1, 2}
+        recovered = cls.from_dict(
+            {
+                tuple((action, mapping[index]) for action, index in term): coeff
+                for term, coeff in compact.iter_terms()
+            }
+        )
+        assert recovered.equiv(op)
+
+    def test_coefficient_sum_and_constant(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.5, ((True, 0), (False, 1)): 2.0 + 1.0j})
+
+        with subtests.test("coefficient_sum"):
+            assert op.coefficient_sum() == 3.5 + 1.0j
+
+        with subtests.test("constant"):
+            assert op.constant() == 1.5
+            assert cls.zero().constant() == 0
+
+    def test_coefficient_stats(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("finite"):
+            op = cls.from_dict({(): 3.0 + 4.0j, ((True, 0), (False, 1)): -1.0})
+            assert op.coefficient_stats() == {
+                "min_abs": 1.0,
+                "max_abs": 5.0,
+                "mean_abs": 3.0,
+                "has_nan": False,
+                "has_inf": False,
+            }
+
+        with subtests.test("nan"):
+            op = cls.from_dict({(): 3.0 + 4.0j, ((True, 0), (False, 1)): float("nan")})
+            stats = op.coefficient_stats()
+            assert stats["has_nan"]
+            assert not stats["has_inf"]
+            assert stats["min_abs"] == stats["max_abs"] == stats["mean_abs"] == 5.0
+
+    def test_chunks(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({((True, i), (False, (i + 1) % 10)): float(i + 1) for i in range(10)})
+
+        with subtests.test("sizes"):
+            chunks = op.chunks(3)
+            assert [len(chunk) for chunk in chunks] == [4, 3, 3]
+
+        with subtests.test("concatenation"):
+            concatenated = cls.zero()
+            for chunk in chunks:
+                concatenated += chunk
+            assert concatenated == op
+
+        with subtests.test("zero chunks"):
+            with pytest.raises(ValueError):
+                op.chunks(0)
+
+    def test_term_operators(self):
+        cls = self.get_class()
+        op = cls.from_dict({((True, i), (False, (i + 1) % 10)): float(i + 1) for i in range(10)})
+        terms = op.term_operators()
+        assert len(terms) == 10
+        assert all(len(term) == 1 for term in terms)
+        assert sum(terms, cls.zero()) == op
+        assert cls.zero().term_operators() == []
+
+    def test_fock_diagonal(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("number operator"):
+            number_op = cls.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 1.0})
+            np.testing.assert_allclose(number_op.fock_diagonal(2), [0, 1, 1, 2])
+
+        with subtests.test("hopping"):
+            hopping = cls.from_dict({(cre(0), ann(1)): 1.0})
+            with pytest.raises(ValueError):
+                hopping.fock_diagonal(2)
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                number_op.fock_diagonal(1)
+
+        with subtests.test("too many modes"):
+            with pytest.raises(ValueError):
+                number_op.fock_diagonal(31)
+
+    def test_to_1body_matrix(self, subtests):
+        cls = self.get_class()
+        norb = 2
+        one_body_a = np.array([1.0, 2.0, 3.0])
+        op = cls.from_1body_tril_spin_sym(one_body_a, norb)
+
+        with subtests.test("round trip"):
+            block = np.array([[1.0, 2.0], [2.0, 3.0]])
+            expected = np.block([[block, np.zeros((2, 2))], [np.zeros((2, 2)), block]])
+            np.testing.assert_allclose(op.to_1body_matrix(2 * norb), expected)
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                op.to_1body_matrix(norb)
+
+        with subtests.test("two-body term"):
+            with pytest.raises(ValueError):
+                cls.from_dict({(cre(0), cre(1), ann(1), ann(0)): 1.0}).to_1body_matrix(2)
+
+    def test_quadratic_spectrum(self, subtests):
+        cls = self.get_class()
+
+        for t in [1.0, 1.0j]:
+            with subtests.test(t=t):
+                op = cls.from_dict(
+                    {
+                        (cre(0), ann(0)): 1.0,
+                        (cre(1), ann(1)): -1.0,
+                        (cre(0), ann(1)): t,
+                        (cre(1), ann(0)): np.conj(t),
+                    }
+                )
+                # the eigenvalues of [[1, t], [t*, -1]] are ±sqrt(1 + |t|^2)
+                np.testing.assert_allclose(op.quadratic_spectrum(2), [-np.sqrt(2), np.sqrt(2)])
+                dense = op.to_sparse_matrix(2).toarray()
+                single_particle = np.linalg.eigvalsh(dense[[1, 2]][:, [1, 2]])
+                np.testing.assert_allclose(op.quadratic_spectrum(2), single_particle)
+
+        with subtests.test("non-quadratic"):
+            with pytest.raises(ValueError):
+                cls.from_dict({(cre(0), cre(1), ann(1), ann(0)): 1.0}).quadratic_spectrum(2)
+
+    def test_to_sparse_matrix(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("number operator"):
+            number_op = cls.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 1.0})
+            dense = number_op.to_sparse_matrix(2).toarray()
+            np.testing.assert_allclose(dense, np.diag(number_op.fock_diagonal(2)))
+
+        with subtests.test("sign"):
+            hopping = cls.from_dict({(cre(2), ann(0)): 2.0})
+            dense = hopping.to_sparse_matrix(3).toarray()
+            expected = np.zeros((8, 8))
+            expected[4, 1] = 2.0
+            expected[6, 3] = -2.0
+            np.testing.assert_allclose(dense, expected)
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                number_op.to_sparse_matrix(1)
+
+        with subtests.test("too many modes"):
+            with pytest.raises(ValueError):
+                number_op.to_sparse_matrix(31)
+
+    def test_ground_energy(self, subtests):
+        cls = self.get_class()
+        fcidump = FCIDump.from_file(str(Path(__file__).parent / "../../h2.fcidump"))
+        op = cls.from_fcidump(fcidump)
+
+        with subtests.test("H2 FCI energy"):
+            assert np.isclose(op.ground_energy(4, tol=1e-10), -1.137306035753)
+
+        with subtests.test("dense"):
+            dense = op.to_sparse_matrix(4).toarray()
+            assert np.isclose(op.ground_energy(4), np.linalg.eigvalsh(dense)[0])
+
+        with subtests.test("non-Hermitian"):
+            with pytest.raises(ValueError):
+                cls.from_dict({(cre(0), ann(1)): 1.0}).ground_energy(2)
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                op.ground_energy(3)
+
+    def test_expectation_determinant(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("H2 Hartree-Fock energy"):
+            fcidump = FCIDump.from_file(str(Path(__file__).parent / "../../h2.fcidump"))
+            op = cls.from_fcidump(fcidump)
+            # the alpha and beta electrons occupy the modes 0 and 2, respectively
+            energy = op.expectation_determinant([0, 2], 4)
+            assert np.isclose(energy, -1.116998996754)
+
+        with subtests.test("off-diagonal terms"):
+            op = cls.from_dict({(cre(0), ann(1)): 1.0, (cre(1), ann(0)): 1.0})
+            assert op.expectation_determinant([0], 2) == 0
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                cls.one().expectation_determinant([2], 2)
+
+    def test_filter_terms(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict(
+            {
+                (): 1.0,
+                ((True, 0), (False, 0)): 0.5,
+                ((True, 0), (False, 1)): 1e-3,
+                ((True, 1), (False, 1)): 2.0,
+            }
+        )
+
+        with subtests.test("hermitian diagonal"):
+            diagonal = op.filter_terms(
+                lambda actions, _: [action for action, _ in actions] == [True, False]
+                and actions[0][1] == actions[1][1]
+            )
+            expected = cls.from_dict({((True, 0), (False, 0)): 0.5, ((True, 1), (False, 1)): 2.0})
+            assert diagonal.equiv(expected)
+
+        with subtests.test("threshold"):
+            large = op.filter_terms(lambda _, coeff: abs(coeff) > 0.75)
+            expected = cls.from_dict({(): 1.0, ((True, 1), (False, 1)): 2.0})
+            assert large.equiv(expected)
+
+        with subtests.test("exception"):
+
+            def callback(actions, coeff):
+                raise RuntimeError("abort")
+
+            with pytest.raises(RuntimeError):
+                op.filter_terms(callback)
+
+    def test_restrict_to_modes(self):
+        cls = self.get_class()
+        op = cls.from_dict(
+            {
+                (): 1.0,
+                (cre(0), ann(1)): 2.0,
+                (cre(2), ann(1)): 3.0,
+                (cre(3), cre(0), ann(3), ann(0)): 4.0,
+            }
+        )
+        restricted = op.restrict_to_modes(2)
+        assert restricted.equiv(cls.from_dict({(): 1.0, (cre(0), ann(1)): 2.0}))
+        assert op.restrict_to_modes(4) == op
+
+    def test_map_coeffs(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("drop imaginary parts"):
+            op = cls.from_dict({(): 1.0 + 2.0j, ((True, 0), (False, 1)): -0.5 - 1.0j})
+            assert not op.is_real()
+            op.map_coeffs(lambda c: complex(c.real, 0))
+            assert op.is_real()
+            assert op.equiv(cls.from_dict({(): 1.0, ((True, 0), (False, 1)): -0.5}))
+
+        with subtests.test("exception"):
+            op = cls.from_dict({(): 1.0 + 2.0j})
+
+            def callback(coeff):
+                raise RuntimeError("abort")
+
+            with pytest.raises(RuntimeError):
+                op.map_coeffs(callback)
+            assert op == cls.from_dict({(): 1.0 + 2.0j})
+
+    def test_remove_constant(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, ((True, 0), (False, 1)): 2.0 + 1.0j})
+        traceless, constant = op.remove_constant()
+        assert constant == 1.0
+        assert traceless.constant() == 0
+        reconstructed = traceless + constant * cls.one()
+        assert reconstructed.simplify().equiv(op.simplify())
+
+    def test_add_constant(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, ((True, 0), (False, 1)): 2.0 + 1.0j})
+        shifted = op.add_constant(1.5)
+        assert shifted.constant() == 2.5
+        assert len(shifted) == 3
+        assert shifted.simplify().equiv((op + 1.5 * cls.one()).simplify())
+
+    def test_parity_operator(self, subtests):
+        cls = self.get_class()
+        parity = cls.parity_operator(2)
+
+        with subtests.test("squared"):
+            squared = (parity & parity).normal_ordered().simplify()
+            assert squared.equiv(cls.one())
+
+        with subtests.test("commutes with number operator"):
+            number = cls.from_dict({(cre(0), ann(0)): 1, (cre(1), ann(1)): 1})
+            comm = commutator(parity, number).normal_ordered().simplify()
+            assert comm.equiv(cls.zero())
+
+    def test_rotate_orbitals(self, subtests):
+        cls = self.get_class()
+        unitary = np.array([[0.6, 0.8j], [0.8j, 0.6]])
+
+        with subtests.test("single number operator"):
+            rotated = cls.from_dict({(cre(0), ann(0)): 1.0}).rotate_orbitals(unitary)
+            trace = sum(
+                coeff for key, coeff in rotated.simplify().iter_terms() if key[0][1] == key[1][1]
+            )
+            assert trace == pytest.approx(1.0)
+
+        with subtests.test("total particle number"):
+            num = cls.from_dict({(cre(0), ann(0)): 1.0, (cre(1), ann(1)): 1.0})
+            assert num.rotate_orbitals(unitary).equiv(num)
+
+        with subtests.test("not square"), pytest.raises(ValueError):
+            cls.one().rotate_orbitals(np.ones((2, 3), dtype=complex))
+
+        with subtests.test("out of bounds"), pytest.raises(ValueError):
+            cls.from_dict({(cre(2),): 1.0}).rotate_orbitals(unitary)
+
+        with subtests.test("too many actions"), pytest.raises(ValueError):
+            cls.from_dict({(cre(0), cre(1), ann(1), ann(0), cre(0)): 1.0}).rotate_orbitals(unitary)
+
+    def test_trace_out_vacuum(self):
+        cls = self.get_class()
+        op = cls.from_dict({(cre(0), ann(0)): 1, (cre(1), ann(1)): 2, (ann(1), cre(1)): 0.5})
+        traced = op.trace_out_vacuum([1])
+        assert traced.equiv(cls.from_dict({(cre(0), ann(0)): 1, (): 0.5}))
+
+    def test_apply_to_state(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("creation"):
+            op = cls.from_dict({(cre(0),): 1})
+            assert op.apply_to_state([False]) == [(1, [True])]
+
+        with subtests.test("annihilation"):
+            op = cls.from_dict({(ann(0),): 1})
+            assert op.apply_to_state([False]) == []
+
+        with subtests.test("sign"):
+            op = cls.from_dict({(ann(1),): 1})
+            assert op.apply_to_state([True, True]) == [(-1, [True, False])]
+
+        with subtests.test("out of range"):
+            op = cls.from_dict({(cre(2),): 1})
+            with pytest.raises(ValueError):
+                op.apply_to_state([False, False])
+
+    def test_matrix_element(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("creation"):
+            op = cls.from_dict({(cre(0),): 1})
+            assert op.matrix_element([True], [False]) == 1
+            assert op.matrix_element([False], [False]) == 0
+
+        with subtests.test("hermitian"):
+            op = cls.from_dict(
+                {
+                    (cre(0), ann(2)): 1 + 2j,
+                    (cre(2), ann(0)): 1 - 2j,
+                    (cre(1), ann(1)): 0.5,
+                }
+            )
+            states = [[bool(k >> i & 1) for i in range(3)] for k in range(8)]
+            for bra in states:
+                for ket in states:
+                    expected = op.matrix_element(ket, bra).conjugate()
+                    assert op.matrix_element(bra, ket) == expected
+
+        with subtests.test("invalid"):
+            op = cls.from_dict({(cre(2),): 1})
+            with pytest.raises(ValueError):
+                op.matrix_element([False, False], [False, False])
+            with pytest.raises(ValueError):
+                op.matrix_element([False, False, False], [False, False])
+
+    def test_determinant_projector(self, subtests):
+        cls = self.get_class()
+        projector = cls.determinant_projector(3, [0, 2])
+
+        with subtests.test("idempotent"):
+            squared = (projector & projector).normal_ordered().simplify()
+            assert squared.equiv(projector)
+
+        with subtests.test("Fock expectation"):
+            for state in range(8):
+                occupation = [bool(state & (1 << i)) for i in range(3)]
+                expectation = sum(
+                    coeff
+                    for coeff, out in projector.apply_to_state(occupation)
+                    if out == occupation
+                )
+                expected = 1.0 if occupation == [True, False, True] else 0.0
+                assert abs(expectation - expected) < 1e-10
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                cls.determinant_projector(2, [2])
+
+    def test_from_hopping(self, subtests):
+        cls = self.get_class()
+        t = -1.0 + 0.5j
+        ring = cls.from_hopping([(0, 1, t), (1, 2, t), (2, 0, t)], [(i, 2.0) for i in range(3)])
+
+        with subtests.test("term count"):
+            assert len(ring) == 9
+
+        with subtests.test("hermitian"):
+            assert ring.is_hermitian()
+
+        with subtests.test("one-body matrix"):
+            expected = np.array([[2.0, t, np.conj(t)], [np.conj(t), 2.0, t], [t, np.conj(t), 2.0]])
+            np.testing.assert_allclose(ring.to_1body_matrix(3), expected)
+
+        with subtests.test("no onsite"):
+            assert len(cls.from_hopping([(0, 1, 1.0)])) == 2
+
     def test_commutator(self):
         cls = self.get_class()
 
@@ -10,9 +10,15 @@
 # copyright notice, and modified files need to carry a notice indicating
 # that they have been altered from the originals.
 
+import copy
+import pickle
 from abc import ABC, abstractmethod
 
-from qiskit_fermions.operators import MajoranaOperator, gamma
+import numpy as np
+import pytest
+
+from qiskit_fermions.mappers.library import fermion_to_majorana
+from qiskit_fermions.operators import FermionOperator, MajoranaOperator, gamma
 from qiskit_fermions.operators.library import anti_commutator, commutator
 
 
@@ -31,6 +37,39 @@ class MajoranaOperatorTests(ABC):
         op = cls.one()
         assert op.equiv(cls.from_dict({(): 1}))
 
+    def test_copy(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, (0, 1): 2.0})
+
+        for name, copier in [("copy", copy.copy), ("deepcopy", copy.deepcopy)]:
+            with subtests.test(name):
+                other = copier(op)
+                assert other == op
+                assert other is not op
+                other *= 3.0
+                other += cls.from_dict({(2,): 1.0})
+                assert op.equiv(cls.from_dict({(): 1.0, (0, 1): 2.0}))
+
+    def test_gamma_dict(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("from_gamma_dict"):
+            op = cls.from_gamma_dict({(): 1.0, ((0, False), (1, True)): 2.0j})
+            expected = cls.from_dict({(): 1.0, (gamma(0, False), gamma(1, True)): 2.0j})
+            assert op.equiv(expected)
+
+        with subtests.test("to_gamma_dict sums duplicates"):
+            op = cls.from_dict({(0, 3): 2.0}) + cls.from_dict({(0, 3): -0.5})
+            assert op.to_gamma_dict() == {((0, False), (1, True)): 1.5}
+
+        with subtests.test("round-trip"):
+            op = cls.from_dict({(): 0.5, (0,): 1.0, (3, 2, 1): -2.0j, (5, 0): 1.5})
+            op += op
+            assert cls.from_gamma_dict(op.to_gamma_dict()).simplify() == op.simplify()
+
+        with subtests.test("too large mode"), pytest.raises(ValueError):
+            cls.from_gamma_dict({((2**31, False),): 1.0})
+
     def test_repr(self):
         cls = self.get_class()
         op = cls.from_dict(
@@ -62,6 +101,23 @@ class MajoranaOperatorTests(ABC):
         op = cls.one()
         assert list(op.iter_terms()) == [([], 1)]
 
+    def test_iter_sorted(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 0.5, (0,): -3.0, (1,): 2.0j, (2, 0): 1.0, (1, 3): 0.1})
+
+        with subtests.test("magnitude"):
+            magnitudes = [abs(coeff) for _, coeff in op.iter_terms(sort="magnitude")]
+            assert magnitudes == sorted(magnitudes, reverse=True)
+            assert len(magnitudes) == 5
+
+        with subtests.test("term"):
+            terms = [term for term, _ in op.iter_terms(sort="term")]
+            assert terms == sorted(terms)
+
+        with subtests.test("invalid"):
+            with pytest.raises(ValueError):
+                op.iter_terms(sort="unknown")
+
     def test_ichop(self):
         cls = self.get_class()
         op = cls.from_dict({(): 1e-4, (0,): 1e-6, (1,): 1e-10})
@@ -90,6 +146,67 @@ class MajoranaOperatorTests(ABC):
         op.ichop(1e-4)
         assert op.equiv(op.zero(), 1e-6)
 
+    def test_scaled_iadd(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, (0,): 2.0})
+        other = cls.from_dict({(0,): 1.0, (1, 2): 1.0j})
+        expected = op + 3.0 * other
+        op.scaled_iadd(other, 3.0)
+        assert op == expected
+
+    def test_chop_relative(self, subtests):
+        cls = self.get_class()
+        op = cls([1.0, 1e-3, 1e-7], [0, 1, 2], [0, 1, 2, 3])
+
+        with subtests.test("rtol=1e-4"):
+            chopped = op.chop_relative(1e-4)
+            assert len(chopped) == 2
+            assert chopped.equiv(cls.from_dict({(0,): 1.0, (1,): 1e-3}), 0.0)
+
+        with subtests.test("rtol=0"):
+            assert len(op.chop_relative(0.0)) == 3
+
+    def test_restrict_to_modes(self):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.0, (0, 1): 2.0, (1, 2, 3): 3.0, (3,): 4.0})
+        restricted = op.restrict_to_modes(2)
+        assert restricted.equiv(cls.from_dict({(): 1.0, (0, 1): 2.0}))
+        assert op.restrict_to_modes(4) == op
+
+    def test_split_by_parity(self):
+        cls = self.get_class()
+        op = cls.from_dict({(0, 1): 1.0, (1, 2, 3): 2.0j})
+        even, odd = op.split_by_parity()
+        assert even.is_even()
+        assert not odd.is_even()
+        assert even == cls.from_dict({(0, 1): 1.0})
+        assert odd == cls.from_dict({(1, 2, 3): 2.0j})
+        assert (even + odd).equiv(op)
+
+    def test_to_sparse_matrix(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("number operator"):
+            number_op = cls.from_dict({(): 0.5, (gamma(0, True), gamma(0, False)): 0.5j})
+            dense = number_op.to_sparse_matrix(2).toarray()
+            np.testing.assert_allclose(dense, np.diag([0, 1, 0, 1]))
+
+        with subtests.test("sign"):
+            op = cls.from_dict({(gamma(1, False),): 1.0})
+            dense = op.to_sparse_matrix(2).toarray()
+            expected = np.zeros((4, 4))
+            expected[2, 0] = expected[0, 2] = 1.0
+            expected[3, 1] = expected[1, 3] = -1.0
+            np.testing.assert_allclose(dense, expected)
+
+        with subtests.test("out of range"):
+            with pytest.raises(ValueError):
+                number_op.to_sparse_matrix(0)
+
+        with subtests.test("too many modes"):
+            with pytest.raises(ValueError):
+                number_op.to_sparse_matrix(31)
+
     def test_add(self):
         cls = self.get_class()
         one = cls.one()
@@ -208,6 +325,50 @@ class MajoranaOperatorTests(ABC):
         with subtests.test("pow==2"):
             assert (op**2).equiv(cls.from_dict({(gamma(0, False), gamma(0, False)): 4}))
 
+    def test_pow_large(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(gamma(0, False),): 1, (gamma(1, True),): 2})
+
+        with subtests.test("pow==10"):
+            naive = cls.one()
+            for _ in range(10):
+                naive = naive & op
+            result = op**10
+            assert len(result) == 2**10
+            assert result.simplify().equiv(naive.simplify())
+
+        with subtests.test("too many terms"), pytest.raises(ValueError):
+            op**1_000_000
+
+    def test_conjugate(self):
+        cls = self.get_class()
+        op = cls.from_dict({(0, 1): 1.0 + 2.0j})
+        conj = op.conjugate()
+        assert conj.equiv(cls.from_dict({(0, 1): 1.0 - 2.0j}))
+        assert conj.conjugate() == op
+        assert not conj.equiv(op.adjoint())
+
+    def test_round_coeffs(self):
+        cls = self.get_class()
+        op = cls.from_dict({(0, 1): 1.0000001 + 0j})
+        rounded = op.round_coeffs(6)
+        assert rounded == cls.from_dict({(0, 1): 1.0 + 0j})
+        assert op.round_coeffs(-1) == cls.from_dict({(0, 1): 0j})
+
+    def test_commutator_methods(self, subtests):
+        cls = self.get_class()
+        gamma_0 = cls.from_dict({(0,): 1.0})
+        gamma_1 = cls.from_dict({(1,): 1.0})
+
+        with subtests.test("anti-commutator"):
+            anti = gamma_0.anticommutator(gamma_0).normal_ordered().simplify()
+            assert anti.equiv(2 * cls.one())
+
+        with subtests.test("commutator"):
+            comm = gamma_0.commutator(gamma_1).normal_ordered()
+            assert not comm.equiv(cls.zero())
+            assert comm.equiv(commutator(gamma_0, gamma_1).normal_ordered())
+
     def test_adjoint(self):
         cls = self.get_class()
         op = cls.from_dict({(): 2j, (gamma(0, False), gamma(0, True)): 3})
@@ -261,6 +422,31 @@ class MajoranaOperatorTests(ABC):
         assert not op.is_hermitian()
         assert op.is_hermitian(1e-4)
 
+    def test_is_hermitian_reversal_sign(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("anti-Hermitian"):
+            op = cls.from_dict({(0, 1): 1.0})
+            assert (op.adjoint() + op).normal_ordered(True).simplify().equiv(cls.zero())
+            assert not op.is_hermitian()
+
+        with subtests.test("Hermitian"):
+            assert cls.from_dict({(0, 1): 1.0j}).is_hermitian()
+
+    def test_is_real(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(0, 1): 1.0, (2, 3): -0.5})
+
+        with subtests.test("real"):
+            assert op.is_real()
+            assert op.is_real(0.0)
+
+        op += cls.from_dict({(): 1e-6j})
+
+        with subtests.test("perturbed"):
+            assert not op.is_real()
+            assert op.is_real(1e-4)
+
     def test_many_body_order(self, subtests):
         cls = self.get_class()
 
@@ -279,6 +465,22 @@ class MajoranaOperatorTests(ABC):
         with subtests.test("4"):
             assert op.many_body_order() == 4
 
+    def test_reduced_many_body_order(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("repeated pair"):
+            op = cls.from_dict({(0, 0, 1): 1})
+            assert op.many_body_order() == 3
+            assert op.reduced_many_body_order() == 1
+
+        with subtests.test("non-adjacent pair"):
+            op = cls.from_dict({(0, 1, 0): 1, (2, 3): 1})
+            assert op.many_body_order() == 3
+            assert op.reduced_many_body_order() == 2
+
+        with subtests.test("empty"):
+            assert cls.zero().reduced_many_body_order() == 0
+
     def test_is_even(self, subtests):
         cls = self.get_class()
 
@@ -290,6 +492,98 @@ class MajoranaOperatorTests(ABC):
             op = cls.from_dict({(gamma(0, True),): 1})
             assert not op.is_even()
 
+    def test_is_quadratic(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("hopping"):
+            op = cls.from_dict({(0, 3): 0.5j, (1, 2): -0.5j})
+            assert op.is_quadratic()
+
+        with subtests.test("Hubbard U"):
+            op = cls.from_dict({(0, 1, 2, 3): -0.25})
+            assert not op.is_quadratic()
+
+    def test_mode_range(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("empty"):
+            assert cls.zero().mode_range() is None
+            assert cls.one().mode_range() is None
+
+        with subtests.test("single mode"):
+            op = cls.from_dict({(5,): 1})
+            assert op.mode_range() == (5, 5)
+
+        with subtests.test("wide spread"):
+            op = cls.from_dict({(7, 2): 1, (2**32 - 1, 0): 2})
+            assert op.mode_range() == (0, 2**32 - 1)
+
+    def test_coefficient_sum_and_constant(self, subtests):
+        cls = self.get_class()
+        op = cls.from_dict({(): 1.5, (0, 1): 2.0 + 1.0j})
+
+        with subtests.test("coefficient_sum"):
+            assert op.coefficient_sum() == 3.5 + 1.0j
+
+        with subtests.test("constant"):
+            assert op.constant() == 1.5
+            assert cls.zero().constant() == 0
+
+        with subtests.test("add_constant"):
+            assert op.add_constant(1.5).constant() == 3.0
+            assert cls.zero().add_constant(1.5).equiv(1.5 * cls.one())
+
+    def test_from_antisymmetric_matrix(self, subtests):
+        cls = self.get_class()
+
+        with subtests.test("quadratic Hamiltonian"):
+            h = np.array(
+                [
+                    [0.0, 1.0, 0.0, -0.5],
+                    [-1.0, 0.0, 2.0, 0.0],
+                    [0.0, -2.0, 0.0, 0.25],
+                    [0.5, 0.0, -0.25, 0.0],
+                ]
+            )
+            op = cls.from_antisymmetric_matrix(h)
+            expected = cls.from_dict({(0, 1): 1.0j, (0, 3): -0.5j, (1, 2): 2.0j, (2, 3): 0.25j})
+            assert op.equiv(expected)
+            assert op.is_hermitian()
+            assert op.is_quadratic()
+
+        with subtests.test("not antisymmetric"):
+            with pytest.raises(ValueError):
+                cls.from_antisymmetric_matrix(np.array([[0.0, 1.0], [1.0, 0.0]]))
+
+        with subtests.test("not square"):
+            with pytest.raises(ValueError):
+                cls.from_antisymmetric_matrix(np.zeros((2, 3)))
+
+    def test_parity_operator(self, subtests):
+        cls = self.get_class()
+        parity = cls.parity_operator(2)
+
+        with subtests.test("squared"):
+            squared = (parity & parity).normal_ordered().simplify()
+            assert squared.equiv(cls.one())
+
+        with subtests.test("commutes with number operator"):
+            number = cls.from_dict(
+                {
+                    (): 1,
+                    (gamma(0, False), gamma(0, True)): 0.5j,
+                    (gamma(1, False), gamma(1, True)): 0.5j,
+                }
+            )
+            comm = commutator(parity, number).normal_ordered().simplify()
+            assert comm.equiv(cls.zero())
+
+        for num_modes in [1, 3]:
+            with subtests.test("matches the mapped fermionic parity", num_modes=num_modes):
+                mapped = fermion_to_majorana(FermionOperator.parity_operator(num_modes))
+                parity = cls.parity_operator(num_modes).normal_ordered()
+                assert parity.equiv(mapped.normal_ordered().simplify())
+
     def test_commutator(self):
         cls = self.get_class()
 
@@ -307,6 +601,30 @@ class MajoranaOperatorTests(ABC):
         comm = anti_commutator(op1, op2)
         assert comm.equiv(cls.from_dict({(gamma(0, True), gamma(0, True)): 2}))
 
+    def test_bytes(self, subtests):
+        cls = self.get_class()
+
+        ops = {
+            "zero": cls.zero(),
+            "one": cls.one(),
+            "many": cls(
+                [1.0, -2.5 + 0.5j, 3j, 1e-12],
+                [0, 1, 2, 3, 7, 0, 2],
+                [0, 0, 2, 4, 7],
+            ),
+        }
+        for name, op in ops.items():
+            with subtests.test(name):
+                assert cls.from_bytes(op.to_bytes()) == op
+                assert pickle.loads(pickle.dumps(op)) == op
+
+        with subtests.test("invalid"):
+            data = cls.one().to_bytes()
+            with pytest.raises(ValueError):
+                cls.from_bytes(b"XXXX" + data[4:])
+            with pytest.raises(ValueError):
+                cls.from_bytes(data[:-1])
+
 
 class TestMajoranaOperator(MajoranaOperatorTests):
     @staticmethod